@@ -16,7 +16,7 @@
 
 `+this.textContent(e))]},r.prototype.fontSize=function(e){return 0},r.prototype.fontFamily=function(e){return""},r.prototype.nodeSize=function(e,Q,T){return Q===void 0&&(Q=1),T===void 0&&(T=null),[0,0]},r.prototype.nodeBBox=function(e){return{left:0,right:0,top:0,bottom:0}},r})(jV.AbstractDOMAdaptor);q1.LiteBase=Ns;var ks=(function(t){js(r,t);function r(){return t!==null&&t.apply(this,arguments)||this}return r})((0,NV.NodeMixin)(Ns));q1.LiteAdaptor=ks;function RV(t){return t===void 0&&(t=null),new ks(null,t)}q1.liteAdaptor=RV});var Rs=p(g1=>{"use strict";var Fs=g1&&g1.__generator||function(t,r){var e={label:0,sent:function(){if(n[0]&1)throw n[1];return n[1]},trys:[],ops:[]},Q,T,n,i;return i={next:a(0),throw:a(1),return:a(2)},typeof Symbol=="function"&&(i[Symbol.iterator]=function(){return this}),i;function a(u){return function(l){return o([u,l])}}function o(u){if(Q)throw new TypeError("Generator is already executing.");for(;e;)try{if(Q=1,T&&(n=u[0]&2?T.return:u[0]?T.throw||((n=T.return)&&n.call(T),0):T.next)&&!(n=n.call(T,u[1])).done)return n;switch(T=0,n&&(u=[u[0]&2,n.value]),u[0]){case 0:case 1:n=u;break;case 4:return e.label++,{value:u[1],done:!1};case 5:e.label++,T=u[1],u=[0];continue;case 7:u=e.ops.pop(),e.trys.pop();continue;default:if(n=e.trys,!(n=n.length>0&&n[n.length-1])&&(u[0]===6||u[0]===2)){e=0;continue}if(u[0]===3&&(!n||u[1]>n[0]&&u[1]<n[3])){e.label=u[1];break}if(u[0]===6&&e.label<n[1]){e.label=n[1],n=u;break}if(n&&e.label<n[2]){e.label=n[2],e.ops.push(u);break}n[2]&&e.ops.pop(),e.trys.pop();continue}u=r.call(t,e)}catch(l){u=[6,l],T=0}finally{Q=n=0}if(u[0]&5)throw u[1];return{value:u[0]?u[1]:void 0,done:!0}}},Y5=g1&&g1.__read||function(t,r){var e=typeof Symbol=="function"&&t[Symbol.iterator];if(!e)return t;var Q=e.call(t),T,n=[],i;try{for(;(r===void 0||r-- >0)&&!(T=Q.next()).done;)n.push(T.value)}catch(a){i={error:a}}finally{try{T&&!T.done&&(e=Q.return)&&e.call(Q)}finally{if(i)throw i.error}}return n},qV=g1&&g1.__spreadArray||function(t,r,e){if(e||arguments.length===2)for(var Q=0,T=r.length,n;Q<T;Q++)(n||!(Q in r))&&(n||(n=Array.prototype.slice.call(r,0,Q)),n[Q]=r[Q]);return t.concat(n||Array.prototype.slice.call(r))},b9=g1&&g1.__values||function(t){var r=typeof Symbol=="function"&&Symbol.iterator,e=r&&t[r],Q=0;if(e)return e.call(t);if(t&&typeof t.length=="number")return{next:function(){return t&&Q>=t.length&&(t=void 0),{value:t&&t[Q++],done:!t}}};throw new TypeError(r?"Object is not iterable.":"Symbol.iterator is not defined.")};Object.defineProperty(g1,"__esModule",{value:!0});g1.LinkedList=g1.ListItem=g1.END=void 0;g1.END=Symbol();var $0=(function(){function t(r){r===void 0&&(r=null),this.next=null,this.prev=null,this.data=r}return t})();g1.ListItem=$0;var ZV=(function(){function t(){for(var r=[],e=0;e<arguments.length;e++)r[e]=arguments[e];this.list=new $0(g1.END),this.list.next=this.list.prev=this.list,this.push.apply(this,qV([],Y5(r),!1))}return t.prototype.isBefore=function(r,e){return r<e},t.prototype.push=function(){for(var r,e,Q=[],T=0;T<arguments.length;T++)Q[T]=arguments[T];try{for(var n=b9(Q),i=n.next();!i.done;i=n.next()){var a=i.value,o=new $0(a);o.next=this.list,o.prev=this.list.prev,this.list.prev=o,o.prev.next=o}}catch(u){r={error:u}}finally{try{i&&!i.done&&(e=n.return)&&e.call(n)}finally{if(r)throw r.error}}return this},t.prototype.pop=function(){var r=this.list.prev;return r.data===g1.END?null:(this.list.prev=r.prev,r.prev.next=this.list,r.next=r.prev=null,r.data)},t.prototype.unshift=function(){for(var r,e,Q=[],T=0;T<arguments.length;T++)Q[T]=arguments[T];try{for(var n=b9(Q.slice(0).reverse()),i=n.next();!i.done;i=n.next()){var a=i.value,o=new $0(a);o.next=this.list.next,o.prev=this.list,this.list.next=o,o.next.prev=o}}catch(u){r={error:u}}finally{try{i&&!i.done&&(e=n.return)&&e.call(n)}finally{if(r)throw r.error}}return this},t.prototype.shift=function(){var r=this.list.next;return r.data===g1.END?null:(this.list.next=r.next,r.next.prev=this.list,r.next=r.prev=null,r.data)},t.prototype.remove=function(){for(var r,e,Q=[],T=0;T<arguments.length;T++)Q[T]=arguments[T];var n=new Map;try{for(var i=b9(Q),a=i.next();!a.done;a=i.next()){var o=a.value;n.set(o,!0)}}catch(c){r={error:c}}finally{try{a&&!a.done&&(e=i.return)&&e.call(i)}finally{if(r)throw r.error}}for(var u=this.list.next;u.data!==g1.END;){var l=u.next;n.has(u.data)&&(u.prev.next=u.next,u.next.prev=u.prev,u.next=u.prev=null),u=l}},t.prototype.clear=function(){return this.list.next.prev=this.list.prev.next=null,this.list.next=this.list.prev=this.list,this},t.prototype[Symbol.iterator]=function(){var r;return Fs(this,function(e){switch(e.label){case 0:r=this.list.next,e.label=1;case 1:return r.data===g1.END?[3,3]:[4,r.data];case 2:return e.sent(),r=r.next,[3,1];case 3:return[2]}})},t.prototype.reversed=function(){var r;return Fs(this,function(e){switch(e.label){case 0:r=this.list.prev,e.label=1;case 1:return r.data===g1.END?[3,3]:[4,r.data];case 2:return e.sent(),r=r.prev,[3,1];case 3:return[2]}})},t.prototype.insert=function(r,e){e===void 0&&(e=null),e===null&&(e=this.isBefore.bind(this));for(var Q=new $0(r),T=this.list.next;T.data!==g1.END&&e(T.data,Q.data);)T=T.next;return Q.prev=T.prev,Q.next=T,T.prev.next=T.prev=Q,this},t.prototype.sort=function(r){var e,Q;r===void 0&&(r=null),r===null&&(r=this.isBefore.bind(this));var T=[];try{for(var n=b9(this),i=n.next();!i.done;i=n.next()){var a=i.value;T.push(new t(a))}}catch(l){e={error:l}}finally{try{i&&!i.done&&(Q=n.return)&&Q.call(n)}finally{if(e)throw e.error}}for(this.list.next=this.list.prev=this.list;T.length>1;){var o=T.shift(),u=T.shift();o.merge(u,r),T.push(o)}return T.length&&(this.list=T[0].list),this},t.prototype.merge=function(r,e){var Q,T,n,i,a;e===void 0&&(e=null),e===null&&(e=this.isBefore.bind(this));for(var o=this.list.next,u=r.list.next;o.data!==g1.END&&u.data!==g1.END;)e(u.data,o.data)?(Q=Y5([o,u],2),u.prev.next=Q[0],o.prev.next=Q[1],T=Y5([o.prev,u.prev],2),u.prev=T[0],o.prev=T[1],n=Y5([r.list,this.list],2),this.list.prev.next=n[0],r.list.prev.next=n[1],i=Y5([r.list.prev,this.list.prev],2),this.list.prev=i[0],r.list.prev=i[1],a=Y5([u.next,o],2),o=a[0],u=a[1]):o=o.next;return u.data!==g1.END&&(this.list.prev.next=r.list.next,r.list.next.prev=this.list.prev,r.list.prev.next=this.list,this.list.prev=r.list.prev,r.list.next=r.list.prev=r.list),this},t})();g1.LinkedList=ZV});var aQ=p(t0=>{"use strict";var GV=t0&&t0.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})();Object.defineProperty(t0,"__esModule",{value:!0});t0.AbstractMathList=void 0;var WV=Rs(),XV=(function(t){GV(r,t);function r(){return t!==null&&t.apply(this,arguments)||this}return r.prototype.isBefore=function(e,Q){return e.start.i<Q.start.i||e.start.i===Q.start.i&&e.start.n<Q.start.n},r})(WV.LinkedList);t0.AbstractMathList=XV});var qs=p(e0=>{"use strict";var zV=e0&&e0.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})();Object.defineProperty(e0,"__esModule",{value:!0});e0.AbstractNodeFactory=void 0;var UV=C7(),$V=(function(t){zV(r,t);function r(){return t!==null&&t.apply(this,arguments)||this}return r.prototype.create=function(e,Q,T){return Q===void 0&&(Q={}),T===void 0&&(T=[]),this.node[e](Q,T)},r})(UV.AbstractFactory);e0.AbstractNodeFactory=$V});var Gs=p(s6=>{"use strict";var KV=s6&&s6.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})(),S9=s6&&s6.__assign||function(){return S9=Object.assign||function(t){for(var r,e=1,Q=arguments.length;e<Q;e++){r=arguments[e];for(var T in r)Object.prototype.hasOwnProperty.call(r,T)&&(t[T]=r[T])}return t},S9.apply(this,arguments)};Object.defineProperty(s6,"__esModule",{value:!0});s6.MmlMstyle=void 0;var Zs=R(),K0=t5(),JV=(function(t){KV(r,t);function r(){return t!==null&&t.apply(this,arguments)||this}return Object.defineProperty(r.prototype,"kind",{get:function(){return"mstyle"},enumerable:!1,configurable:!0}),Object.defineProperty(r.prototype,"notParent",{get:function(){return this.childNodes[0]&&this.childNodes[0].childNodes.length===1},enumerable:!1,configurable:!0}),r.prototype.setChildInheritedAttributes=function(e,Q,T,n){var i=this.attributes.getExplicit("scriptlevel");i!=null&&(i=i.toString(),i.match(/^\s*[-+]/)?T+=parseInt(i):T=parseInt(i),n=!1);var a=this.attributes.getExplicit("displaystyle");a!=null&&(Q=a===!0,n=!1);var o=this.attributes.getExplicit("data-cramped");o!=null&&(n=o),e=this.addInheritedAttributes(e,this.attributes.getAllAttributes()),this.childNodes[0].setInheritedAttributes(e,Q,T,n)},r.defaults=S9(S9({},Zs.AbstractMmlLayoutNode.defaults),{scriptlevel:K0.INHERIT,displaystyle:K0.INHERIT,scriptsizemultiplier:1/Math.sqrt(2),scriptminsize:"8px",mathbackground:K0.INHERIT,mathcolor:K0.INHERIT,dir:K0.INHERIT,infixlinebreakstyle:"before"}),r})(Zs.AbstractMmlLayoutNode);s6.MmlMstyle=JV});var Xs=p(u6=>{"use strict";var YV=u6&&u6.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})(),w9=u6&&u6.__assign||function(){return w9=Object.assign||function(t){for(var r,e=1,Q=arguments.length;e<Q;e++){r=arguments[e];for(var T in r)Object.prototype.hasOwnProperty.call(r,T)&&(t[T]=r[T])}return t},w9.apply(this,arguments)};Object.defineProperty(u6,"__esModule",{value:!0});u6.MmlMaligngroup=void 0;var Ws=R(),t_=t5(),e_=(function(t){YV(r,t);function r(){return t!==null&&t.apply(this,arguments)||this}return Object.defineProperty(r.prototype,"kind",{get:function(){return"maligngroup"},enumerable:!1,configurable:!0}),Object.defineProperty(r.prototype,"isSpacelike",{get:function(){return!0},enumerable:!1,configurable:!0}),r.prototype.setChildInheritedAttributes=function(e,Q,T,n){e=this.addInheritedAttributes(e,this.attributes.getAllAttributes()),t.prototype.setChildInheritedAttributes.call(this,e,Q,T,n)},r.defaults=w9(w9({},Ws.AbstractMmlLayoutNode.defaults),{groupalign:t_.INHERIT}),r})(Ws.AbstractMmlLayoutNode);u6.MmlMaligngroup=e_});var Us=p(l6=>{"use strict";var r_=l6&&l6.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})(),B9=l6&&l6.__assign||function(){return B9=Object.assign||function(t){for(var r,e=1,Q=arguments.length;e<Q;e++){r=arguments[e];for(var T in r)Object.prototype.hasOwnProperty.call(r,T)&&(t[T]=r[T])}return t},B9.apply(this,arguments)};Object.defineProperty(l6,"__esModule",{value:!0});l6.MmlMalignmark=void 0;var zs=R(),Q_=(function(t){r_(r,t);function r(){return t!==null&&t.apply(this,arguments)||this}return Object.defineProperty(r.prototype,"kind",{get:function(){return"malignmark"},enumerable:!1,configurable:!0}),Object.defineProperty(r.prototype,"arity",{get:function(){return 0},enumerable:!1,configurable:!0}),Object.defineProperty(r.prototype,"isSpacelike",{get:function(){return!0},enumerable:!1,configurable:!0}),r.defaults=B9(B9({},zs.AbstractMmlNode.defaults),{edge:"left"}),r})(zs.AbstractMmlNode);l6.MmlMalignmark=Q_});var Ks=p(c6=>{"use strict";var T_=c6&&c6.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})(),oQ=c6&&c6.__assign||function(){return oQ=Object.assign||function(t){for(var r,e=1,Q=arguments.length;e<Q;e++){r=arguments[e];for(var T in r)Object.prototype.hasOwnProperty.call(r,T)&&(t[T]=r[T])}return t},oQ.apply(this,arguments)};Object.defineProperty(c6,"__esModule",{value:!0});c6.MathChoice=void 0;var $s=R(),n_=(function(t){T_(r,t);function r(){return t!==null&&t.apply(this,arguments)||this}return Object.defineProperty(r.prototype,"kind",{get:function(){return"MathChoice"},enumerable:!1,configurable:!0}),Object.defineProperty(r.prototype,"arity",{get:function(){return 4},enumerable:!1,configurable:!0}),Object.defineProperty(r.prototype,"notParent",{get:function(){return!0},enumerable:!1,configurable:!0}),r.prototype.setInheritedAttributes=function(e,Q,T,n){var i=Q?0:Math.max(0,Math.min(T,2))+1,a=this.childNodes[i]||this.factory.create("mrow");this.parent.replaceChild(a,this),a.setInheritedAttributes(e,Q,T,n)},r.defaults=oQ({},$s.AbstractMmlBaseNode.defaults),r})($s.AbstractMmlBaseNode);c6.MathChoice=n_});var mu=p(k9=>{"use strict";var $;Object.defineProperty(k9,"__esModule",{value:!0});k9.MML=void 0;var P9=R(),Js=_e(),Ys=Se(),tu=Pe(),eu=S0(),ru=Fe(),Qu=We(),Tu=Ne(),j9=Ee(),nu=Ke(),iu=er(),au=nr(),ou=Gs(),su=Ze(),uu=Xe(),lu=$e(),cu=ir(),fu=wr(),hu=br(),r0=o8(),Q0=pr(),T0=mr(),du=gr(),N9=Ar(),Lu=Cr(),pu=Xs(),vu=Us(),xu=Ir(),n0=jr(),Hu=qr(),yu=Ks();k9.MML=($={},$[Js.MmlMath.prototype.kind]=Js.MmlMath,$[Ys.MmlMi.prototype.kind]=Ys.MmlMi,$[tu.MmlMn.prototype.kind]=tu.MmlMn,$[eu.MmlMo.prototype.kind]=eu.MmlMo,$[ru.MmlMtext.prototype.kind]=ru.MmlMtext,$[Qu.MmlMspace.prototype.kind]=Qu.MmlMspace,$[Tu.MmlMs.prototype.kind]=Tu.MmlMs,$[j9.MmlMrow.prototype.kind]=j9.MmlMrow,$[j9.MmlInferredMrow.prototype.kind]=j9.MmlInferredMrow,$[nu.MmlMfrac.prototype.kind]=nu.MmlMfrac,$[iu.MmlMsqrt.prototype.kind]=iu.MmlMsqrt,$[au.MmlMroot.prototype.kind]=au.MmlMroot,$[ou.MmlMstyle.prototype.kind]=ou.MmlMstyle,$[su.MmlMerror.prototype.kind]=su.MmlMerror,$[uu.MmlMpadded.prototype.kind]=uu.MmlMpadded,$[lu.MmlMphantom.prototype.kind]=lu.MmlMphantom,$[cu.MmlMfenced.prototype.kind]=cu.MmlMfenced,$[fu.MmlMenclose.prototype.kind]=fu.MmlMenclose,$[hu.MmlMaction.prototype.kind]=hu.MmlMaction,$[r0.MmlMsub.prototype.kind]=r0.MmlMsub,$[r0.MmlMsup.prototype.kind]=r0.MmlMsup,$[r0.MmlMsubsup.prototype.kind]=r0.MmlMsubsup,$[Q0.MmlMunder.prototype.kind]=Q0.MmlMunder,$[Q0.MmlMover.prototype.kind]=Q0.MmlMover,$[Q0.MmlMunderover.prototype.kind]=Q0.MmlMunderover,$[T0.MmlMmultiscripts.prototype.kind]=T0.MmlMmultiscripts,$[T0.MmlMprescripts.prototype.kind]=T0.MmlMprescripts,$[T0.MmlNone.prototype.kind]=T0.MmlNone,$[du.MmlMtable.prototype.kind]=du.MmlMtable,$[N9.MmlMlabeledtr.prototype.kind]=N9.MmlMlabeledtr,$[N9.MmlMtr.prototype.kind]=N9.MmlMtr,$[Lu.MmlMtd.prototype.kind]=Lu.MmlMtd,$[pu.MmlMaligngroup.prototype.kind]=pu.MmlMaligngroup,$[vu.MmlMalignmark.prototype.kind]=vu.MmlMalignmark,$[xu.MmlMglyph.prototype.kind]=xu.MmlMglyph,$[n0.MmlSemantics.prototype.kind]=n0.MmlSemantics,$[n0.MmlAnnotation.prototype.kind]=n0.MmlAnnotation,$[n0.MmlAnnotationXML.prototype.kind]=n0.MmlAnnotationXML,$[Hu.TeXAtom.prototype.kind]=Hu.TeXAtom,$[yu.MathChoice.prototype.kind]=yu.MathChoice,$[P9.TextNode.prototype.kind]=P9.TextNode,$[P9.XMLNode.prototype.kind]=P9.XMLNode,$)});var gu=p(i0=>{"use strict";var i_=i0&&i0.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})();Object.defineProperty(i0,"__esModule",{value:!0});i0.MmlFactory=void 0;var a_=qs(),o_=mu(),s_=(function(t){i_(r,t);function r(){return t!==null&&t.apply(this,arguments)||this}return Object.defineProperty(r.prototype,"MML",{get:function(){return this.node},enumerable:!1,configurable:!0}),r.defaultNodes=o_.MML,r})(a_.AbstractNodeFactory);i0.MmlFactory=s_});var Vu=p(h2=>{"use strict";var u_=h2&&h2.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})(),l_=h2&&h2.__values||function(t){var r=typeof Symbol=="function"&&Symbol.iterator,e=r&&t[r],Q=0;if(e)return e.call(t);if(t&&typeof t.length=="number")return{next:function(){return t&&Q>=t.length&&(t=void 0),{value:t&&t[Q++],done:!t}}};throw new TypeError(r?"Object is not iterable.":"Symbol.iterator is not defined.")},c_=h2&&h2.__read||function(t,r){var e=typeof Symbol=="function"&&t[Symbol.iterator];if(!e)return t;var Q=e.call(t),T,n=[],i;try{for(;(r===void 0||r-- >0)&&!(T=Q.next()).done;)n.push(T.value)}catch(a){i={error:a}}finally{try{T&&!T.done&&(e=Q.return)&&e.call(Q)}finally{if(i)throw i.error}}return n},f_=h2&&h2.__spreadArray||function(t,r,e){if(e||arguments.length===2)for(var Q=0,T=r.length,n;Q<T;Q++)(n||!(Q in r))&&(n||(n=Array.prototype.slice.call(r,0,Q)),n[Q]=r[Q]);return t.concat(n||Array.prototype.slice.call(r))};Object.defineProperty(h2,"__esModule",{value:!0});h2.BitFieldClass=h2.BitField=void 0;var Mu=(function(){function t(){this.bits=0}return t.allocate=function(){for(var r,e,Q=[],T=0;T<arguments.length;T++)Q[T]=arguments[T];try{for(var n=l_(Q),i=n.next();!i.done;i=n.next()){var a=i.value;if(this.has(a))throw new Error("Bit already allocated for "+a);if(this.next===t.MAXBIT)throw new Error("Maximum number of bits already allocated");this.names.set(a,this.next),this.next<<=1}}catch(o){r={error:o}}finally{try{i&&!i.done&&(e=n.return)&&e.call(n)}finally{if(r)throw r.error}}},t.has=function(r){return this.names.has(r)},t.prototype.set=function(r){this.bits|=this.getBit(r)},t.prototype.clear=function(r){this.bits&=~this.getBit(r)},t.prototype.isSet=function(r){return!!(this.bits&this.getBit(r))},t.prototype.reset=function(){this.bits=0},t.prototype.getBit=function(r){var e=this.constructor.names.get(r);if(!e)throw new Error("Unknown bit-field name: "+r);return e},t.MAXBIT=1<<31,t.next=1,t.names=new Map,t})();h2.BitField=Mu;function h_(){for(var t=[],r=0;r<arguments.length;r++)t[r]=arguments[r];var e=(function(Q){u_(T,Q);function T(){return Q!==null&&Q.apply(this,arguments)||this}return T})(Mu);return e.allocate.apply(e,f_([],c_(t),!1)),e}h2.BitFieldClass=h_});var lQ=p(S1=>{"use strict";var Y0=S1&&S1.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})(),J2=S1&&S1.__values||function(t){var r=typeof Symbol=="function"&&Symbol.iterator,e=r&&t[r],Q=0;if(e)return e.call(t);if(t&&typeof t.length=="number")return{next:function(){return t&&Q>=t.length&&(t=void 0),{value:t&&t[Q++],done:!t}}};throw new TypeError(r?"Object is not iterable.":"Symbol.iterator is not defined.")},z3=S1&&S1.__read||function(t,r){var e=typeof Symbol=="function"&&t[Symbol.iterator];if(!e)return t;var Q=e.call(t),T,n=[],i;try{for(;(r===void 0||r-- >0)&&!(T=Q.next()).done;)n.push(T.value)}catch(a){i={error:a}}finally{try{T&&!T.done&&(e=Q.return)&&e.call(Q)}finally{if(i)throw i.error}}return n},sQ=S1&&S1.__spreadArray||function(t,r,e){if(e||arguments.length===2)for(var Q=0,T=r.length,n;Q<T;Q++)(n||!(Q in r))&&(n||(n=Array.prototype.slice.call(r,0,Q)),n[Q]=r[Q]);return t.concat(n||Array.prototype.slice.call(r))};Object.defineProperty(S1,"__esModule",{value:!0});S1.AbstractMathDocument=S1.resetAllOptions=S1.resetOptions=S1.RenderList=void 0;var J0=B1(),d_=Bt(),L_=xe(),p_=aQ(),d2=Y6(),v_=gu(),x_=Vu(),H_=$6(),uQ=(function(t){Y0(r,t);function r(){return t!==null&&t.apply(this,arguments)||this}return r.create=function(e){var Q,T,n=new this;try{for(var i=J2(Object.keys(e)),a=i.next();!a.done;a=i.next()){var o=a.value,u=z3(this.action(o,e[o]),2),l=u[0],c=u[1];c&&n.add(l,c)}}catch(f){Q={error:f}}finally{try{a&&!a.done&&(T=i.return)&&T.call(i)}finally{if(Q)throw Q.error}}return n},r.action=function(e,Q){var T,n,i,a,o,u,l=!0,c=Q[0];if(Q.length===1||typeof Q[1]=="boolean")Q.length===2&&(l=Q[1]),T=z3(this.methodActions(e),2),o=T[0],u=T[1];else if(typeof Q[1]=="string")if(typeof Q[2]=="string"){Q.length===4&&(l=Q[3]);var f=z3(Q.slice(1),2),h=f[0],d=f[1];n=z3(this.methodActions(h,d),2),o=n[0],u=n[1]}else Q.length===3&&(l=Q[2]),i=z3(this.methodActions(Q[1]),2),o=i[0],u=i[1];else Q.length===4&&(l=Q[3]),a=z3(Q.slice(1),2),o=a[0],u=a[1];return[{id:e,renderDoc:o,renderMath:u,convert:l},c]},r.methodActions=function(e,Q){return Q===void 0&&(Q=e),[function(T){return e&&T[e](),!1},function(T,n){return Q&&T[Q](n),!1}]},r.prototype.renderDoc=function(e,Q){var T,n;Q===void 0&&(Q=d2.STATE.UNPROCESSED);try{for(var i=J2(this.items),a=i.next();!a.done;a=i.next()){var o=a.value;if(o.priority>=Q&&o.item.renderDoc(e))return}}catch(u){T={error:u}}finally{try{a&&!a.done&&(n=i.return)&&n.call(i)}finally{if(T)throw T.error}}},r.prototype.renderMath=function(e,Q,T){var n,i;T===void 0&&(T=d2.STATE.UNPROCESSED);try{for(var a=J2(this.items),o=a.next();!o.done;o=a.next()){var u=o.value;if(u.priority>=T&&u.item.renderMath(e,Q))return}}catch(l){n={error:l}}finally{try{o&&!o.done&&(i=a.return)&&i.call(a)}finally{if(n)throw n.error}}},r.prototype.renderConvert=function(e,Q,T){var n,i;T===void 0&&(T=d2.STATE.LAST);try{for(var a=J2(this.items),o=a.next();!o.done;o=a.next()){var u=o.value;if(u.priority>T||u.item.convert&&u.item.renderMath(e,Q))return}}catch(l){n={error:l}}finally{try{o&&!o.done&&(i=a.return)&&i.call(a)}finally{if(n)throw n.error}}},r.prototype.findID=function(e){var Q,T;try{for(var n=J2(this.items),i=n.next();!i.done;i=n.next()){var a=i.value;if(a.item.id===e)return a.item}}catch(o){Q={error:o}}finally{try{i&&!i.done&&(T=n.return)&&T.call(n)}finally{if(Q)throw Q.error}}return null},r})(H_.PrioritizedList);S1.RenderList=uQ;S1.resetOptions={all:!1,processed:!1,inputJax:null,outputJax:null};S1.resetAllOptions={all:!0,processed:!0,inputJax:[],outputJax:[]};var y_=(function(t){Y0(r,t);function r(){return t!==null&&t.apply(this,arguments)||this}return r.prototype.compile=function(e){return null},r})(d_.AbstractInputJax),m_=(function(t){Y0(r,t);function r(){return t!==null&&t.apply(this,arguments)||this}return r.prototype.typeset=function(e,Q){return Q===void 0&&(Q=null),null},r.prototype.escaped=function(e,Q){return null},r})(L_.AbstractOutputJax),_u=(function(t){Y0(r,t);function r(){return t!==null&&t.apply(this,arguments)||this}return r})(p_.AbstractMathList),g_=(function(t){Y0(r,t);function r(){return t!==null&&t.apply(this,arguments)||this}return r})(d2.AbstractMathItem),M_=(function(){function t(r,e,Q){var T=this,n=this.constructor;this.document=r,this.options=(0,J0.userOptions)((0,J0.defaultOptions)({},n.OPTIONS),Q),this.math=new(this.options.MathList||_u),this.renderActions=uQ.create(this.options.renderActions),this.processed=new t.ProcessBits,this.outputJax=this.options.OutputJax||new m_;var i=this.options.InputJax||[new y_];Array.isArray(i)||(i=[i]),this.inputJax=i,this.adaptor=e,this.outputJax.setAdaptor(e),this.inputJax.map(function(a){return a.setAdaptor(e)}),this.mmlFactory=this.options.MmlFactory||new v_.MmlFactory,this.inputJax.map(function(a){return a.setMmlFactory(T.mmlFactory)}),this.outputJax.initialize(),this.inputJax.map(function(a){return a.initialize()})}return Object.defineProperty(t.prototype,"kind",{get:function(){return this.constructor.KIND},enumerable:!1,configurable:!0}),t.prototype.addRenderAction=function(r){for(var e=[],Q=1;Q<arguments.length;Q++)e[Q-1]=arguments[Q];var T=z3(uQ.action(r,e),2),n=T[0],i=T[1];this.renderActions.add(n,i)},t.prototype.removeRenderAction=function(r){var e=this.renderActions.findID(r);e&&this.renderActions.remove(e)},t.prototype.render=function(){return this.renderActions.renderDoc(this),this},t.prototype.rerender=function(r){return r===void 0&&(r=d2.STATE.RERENDER),this.state(r-1),this.render(),this},t.prototype.convert=function(r,e){e===void 0&&(e={});var Q=(0,J0.userOptions)({format:this.inputJax[0].name,display:!0,end:d2.STATE.LAST,em:16,ex:8,containerWidth:null,lineWidth:1e6,scale:1,family:""},e),T=Q.format,n=Q.display,i=Q.end,a=Q.ex,o=Q.em,u=Q.containerWidth,l=Q.lineWidth,c=Q.scale,f=Q.family;u===null&&(u=80*a);var h=this.inputJax.reduce(function(L,v){return v.name===T?v:L},null),d=new this.options.MathItem(r,h,n);return d.start.node=this.adaptor.body(this.document),d.setMetrics(o,a,u,l,c),this.outputJax.options.mtextInheritFont&&(d.outputData.mtextFamily=f),this.outputJax.options.merrorInheritFont&&(d.outputData.merrorFamily=f),d.convert(this,i),d.typesetRoot||d.root},t.prototype.findMath=function(r){return r===void 0&&(r=null),this.processed.set("findMath"),this},t.prototype.compile=function(){var r,e,Q,T;if(!this.processed.isSet("compile")){var n=[];try{for(var i=J2(this.math),a=i.next();!a.done;a=i.next()){var o=a.value;this.compileMath(o),o.inputData.recompile!==void 0&&n.push(o)}}catch(f){r={error:f}}finally{try{a&&!a.done&&(e=i.return)&&e.call(i)}finally{if(r)throw r.error}}try{for(var u=J2(n),l=u.next();!l.done;l=u.next()){var o=l.value,c=o.inputData.recompile;o.state(c.state),o.inputData.recompile=c,this.compileMath(o)}}catch(f){Q={error:f}}finally{try{l&&!l.done&&(T=u.return)&&T.call(u)}finally{if(Q)throw Q.error}}this.processed.set("compile")}return this},t.prototype.compileMath=function(r){try{r.compile(this)}catch(e){if(e.retry||e.restart)throw e;this.options.compileError(this,r,e),r.inputData.error=e}},t.prototype.compileError=function(r,e){r.root=this.mmlFactory.create("math",null,[this.mmlFactory.create("merror",{"data-mjx-error":e.message,title:e.message},[this.mmlFactory.create("mtext",null,[this.mmlFactory.create("text").setText("Math input error")])])]),r.display&&r.root.attributes.set("display","block"),r.inputData.error=e.message},t.prototype.typeset=function(){var r,e;if(!this.processed.isSet("typeset")){try{for(var Q=J2(this.math),T=Q.next();!T.done;T=Q.next()){var n=T.value;try{n.typeset(this)}catch(i){if(i.retry||i.restart)throw i;this.options.typesetError(this,n,i),n.outputData.error=i}}}catch(i){r={error:i}}finally{try{T&&!T.done&&(e=Q.return)&&e.call(Q)}finally{if(r)throw r.error}}this.processed.set("typeset")}return this},t.prototype.typesetError=function(r,e){r.typesetRoot=this.adaptor.node("mjx-container",{class:"MathJax mjx-output-error",jax:this.outputJax.name},[this.adaptor.node("span",{"data-mjx-error":e.message,title:e.message,style:{color:"red","background-color":"yellow","line-height":"normal"}},[this.adaptor.text("Math output error")])]),r.display&&this.adaptor.setAttributes(r.typesetRoot,{style:{display:"block",margin:"1em 0","text-align":"center"}}),r.outputData.error=e.message},t.prototype.getMetrics=function(){return this.processed.isSet("getMetrics")||(this.outputJax.getMetrics(this),this.processed.set("getMetrics")),this},t.prototype.updateDocument=function(){var r,e;if(!this.processed.isSet("updateDocument")){try{for(var Q=J2(this.math.reversed()),T=Q.next();!T.done;T=Q.next()){var n=T.value;n.updateDocument(this)}}catch(i){r={error:i}}finally{try{T&&!T.done&&(e=Q.return)&&e.call(Q)}finally{if(r)throw r.error}}this.processed.set("updateDocument")}return this},t.prototype.removeFromDocument=function(r){return r===void 0&&(r=!1),this},t.prototype.state=function(r,e){var Q,T;e===void 0&&(e=!1);try{for(var n=J2(this.math),i=n.next();!i.done;i=n.next()){var a=i.value;a.state(r,e)}}catch(o){Q={error:o}}finally{try{i&&!i.done&&(T=n.return)&&T.call(n)}finally{if(Q)throw Q.error}}return r<d2.STATE.INSERTED&&this.processed.clear("updateDocument"),r<d2.STATE.TYPESET&&(this.processed.clear("typeset"),this.processed.clear("getMetrics")),r<d2.STATE.COMPILED&&this.processed.clear("compile"),this},t.prototype.reset=function(r){var e;return r===void 0&&(r={processed:!0}),r=(0,J0.userOptions)(Object.assign({},S1.resetOptions),r),r.all&&Object.assign(r,S1.resetAllOptions),r.processed&&this.processed.reset(),r.inputJax&&this.inputJax.forEach(function(Q){return Q.reset.apply(Q,sQ([],z3(r.inputJax),!1))}),r.outputJax&&(e=this.outputJax).reset.apply(e,sQ([],z3(r.outputJax),!1)),this},t.prototype.clear=function(){return this.reset(),this.math.clear(),this},t.prototype.concat=function(r){return this.math.merge(r),this},t.prototype.clearMathItemsWithin=function(r){var e,Q=this.getMathItemsWithin(r);return(e=this.math).remove.apply(e,sQ([],z3(Q),!1)),Q},t.prototype.getMathItemsWithin=function(r){var e,Q,T,n;Array.isArray(r)||(r=[r]);var i=this.adaptor,a=[],o=i.getElements(r,this.document);try{t:for(var u=J2(this.math),l=u.next();!l.done;l=u.next()){var c=l.value;try{for(var f=(T=void 0,J2(o)),h=f.next();!h.done;h=f.next()){var d=h.value;if(c.start.node&&i.contains(d,c.start.node)){a.push(c);continue t}}}catch(L){T={error:L}}finally{try{h&&!h.done&&(n=f.return)&&n.call(f)}finally{if(T)throw T.error}}}}catch(L){e={error:L}}finally{try{l&&!l.done&&(Q=u.return)&&Q.call(u)}finally{if(e)throw e.error}}return a},t.KIND="MathDocument",t.OPTIONS={OutputJax:null,InputJax:null,MmlFactory:null,MathList:_u,MathItem:g_,compileError:function(r,e,Q){r.compileError(e,Q)},typesetError:function(r,e,Q){r.typesetError(e,Q)},renderActions:(0,J0.expandable)({find:[d2.STATE.FINDMATH,"findMath","",!1],compile:[d2.STATE.COMPILED],metrics:[d2.STATE.METRICS,"getMetrics","",!1],typeset:[d2.STATE.TYPESET],update:[d2.STATE.INSERTED,"updateDocument",!1]})},t.ProcessBits=(0,x_.BitFieldClass)("findMath","compile","getMetrics","typeset","updateDocument"),t})();S1.AbstractMathDocument=M_});var Au=p(a0=>{"use strict";var V_=a0&&a0.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})();Object.defineProperty(a0,"__esModule",{value:!0});a0.AbstractHandler=void 0;var __=lQ(),A_=(function(t){V_(r,t);function r(){return t!==null&&t.apply(this,arguments)||this}return r})(__.AbstractMathDocument),O_=(function(){function t(r,e){e===void 0&&(e=5),this.documentClass=A_,this.adaptor=r,this.priority=e}return Object.defineProperty(t.prototype,"name",{get:function(){return this.constructor.NAME},enumerable:!1,configurable:!0}),t.prototype.handlesDocument=function(r){return!1},t.prototype.create=function(r,e){return new this.documentClass(r,this.adaptor,e)},t.NAME="generic",t})();a0.AbstractHandler=O_});var Ou=p(o0=>{"use strict";var C_=o0&&o0.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})();Object.defineProperty(o0,"__esModule",{value:!0});o0.HTMLMathItem=void 0;var I9=Y6(),D_=(function(t){C_(r,t);function r(e,Q,T,n,i){return T===void 0&&(T=!0),n===void 0&&(n={node:null,n:0,delim:""}),i===void 0&&(i={node:null,n:0,delim:""}),t.call(this,e,Q,T,n,i)||this}return Object.defineProperty(r.prototype,"adaptor",{get:function(){return this.inputJax.adaptor},enumerable:!1,configurable:!0}),r.prototype.updateDocument=function(e){if(this.state()<I9.STATE.INSERTED){if(this.inputJax.processStrings){var Q=this.start.node;if(Q===this.end.node)this.end.n&&this.end.n<this.adaptor.value(this.end.node).length&&this.adaptor.split(this.end.node,this.end.n),this.start.n&&(Q=this.adaptor.split(this.start.node,this.start.n)),this.adaptor.replace(this.typesetRoot,Q);else{for(this.start.n&&(Q=this.adaptor.split(Q,this.start.n));Q!==this.end.node;){var T=this.adaptor.next(Q);this.adaptor.remove(Q),Q=T}this.adaptor.insert(this.typesetRoot,Q),this.end.n<this.adaptor.value(Q).length&&this.adaptor.split(Q,this.end.n),this.adaptor.remove(Q)}}else this.adaptor.replace(this.typesetRoot,this.start.node);this.start.node=this.end.node=this.typesetRoot,this.start.n=this.end.n=0,this.state(I9.STATE.INSERTED)}},r.prototype.updateStyleSheet=function(e){e.addStyleSheet()},r.prototype.removeFromDocument=function(e){if(e===void 0&&(e=!1),this.state()>=I9.STATE.TYPESET){var Q=this.adaptor,T=this.start.node,n=Q.text("");if(e){var i=this.start.delim+this.math+this.end.delim;if(this.inputJax.processStrings)n=Q.text(i);else{var a=Q.parse(i,"text/html");n=Q.firstChild(Q.body(a))}}Q.parent(T)&&Q.replace(n,T),this.start.node=this.end.node=n,this.start.n=this.end.n=0}},r})(I9.AbstractMathItem);o0.HTMLMathItem=D_});var Cu=p(s0=>{"use strict";var E_=s0&&s0.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})();Object.defineProperty(s0,"__esModule",{value:!0});s0.HTMLMathList=void 0;var b_=aQ(),S_=(function(t){E_(r,t);function r(){return t!==null&&t.apply(this,arguments)||this}return r})(b_.AbstractMathList);s0.HTMLMathList=S_});var Eu=p(u0=>{"use strict";var Du=u0&&u0.__read||function(t,r){var e=typeof Symbol=="function"&&t[Symbol.iterator];if(!e)return t;var Q=e.call(t),T,n=[],i;try{for(;(r===void 0||r-- >0)&&!(T=Q.next()).done;)n.push(T.value)}catch(a){i={error:a}}finally{try{T&&!T.done&&(e=Q.return)&&e.call(Q)}finally{if(i)throw i.error}}return n};Object.defineProperty(u0,"__esModule",{value:!0});u0.HTMLDomStrings=void 0;var t7=B1(),w_=(function(){function t(r){r===void 0&&(r=null);var e=this.constructor;this.options=(0,t7.userOptions)((0,t7.defaultOptions)({},e.OPTIONS),r),this.init(),this.getPatterns()}return t.prototype.init=function(){this.strings=[],this.string="",this.snodes=[],this.nodes=[],this.stack=[]},t.prototype.getPatterns=function(){var r=(0,t7.makeArray)(this.options.skipHtmlTags),e=(0,t7.makeArray)(this.options.ignoreHtmlClass),Q=(0,t7.makeArray)(this.options.processHtmlClass);this.skipHtmlTags=new RegExp("^(?:"+r.join("|")+")$","i"),this.ignoreHtmlClass=new RegExp("(?:^| )(?:"+e.join("|")+")(?: |$)"),this.processHtmlClass=new RegExp("(?:^| )(?:"+Q+")(?: |$)")},t.prototype.pushString=function(){this.string.match(/\S/)&&(this.strings.push(this.string),this.nodes.push(this.snodes)),this.string="",this.snodes=[]},t.prototype.extendString=function(r,e){this.snodes.push([r,e.length]),this.string+=e},t.prototype.handleText=function(r,e){return e||this.extendString(r,this.adaptor.value(r)),this.adaptor.next(r)},t.prototype.handleTag=function(r,e){if(!e){var Q=this.options.includeHtmlTags[this.adaptor.kind(r)];this.extendString(r,Q)}return this.adaptor.next(r)},t.prototype.handleContainer=function(r,e){this.pushString();var Q=this.adaptor.getAttribute(r,"class")||"",T=this.adaptor.kind(r)||"",n=this.processHtmlClass.exec(Q),i=r;return this.adaptor.firstChild(r)&&!this.adaptor.getAttribute(r,"data-MJX")&&(n||!this.skipHtmlTags.exec(T))?(this.adaptor.next(r)&&this.stack.push([this.adaptor.next(r),e]),i=this.adaptor.firstChild(r),e=(e||this.ignoreHtmlClass.exec(Q))&&!n):i=this.adaptor.next(r),[i,e]},t.prototype.handleOther=function(r,e){return this.pushString(),this.adaptor.next(r)},t.prototype.find=function(r){var e,Q;this.init();for(var T=this.adaptor.next(r),n=!1,i=this.options.includeHtmlTags;r&&r!==T;){var a=this.adaptor.kind(r);a==="#text"?r=this.handleText(r,n):i.hasOwnProperty(a)?r=this.handleTag(r,n):a?(e=Du(this.handleContainer(r,n),2),r=e[0],n=e[1]):r=this.handleOther(r,n),!r&&this.stack.length&&(this.pushString(),Q=Du(this.stack.pop(),2),r=Q[0],n=Q[1])}this.pushString();var o=[this.strings,this.nodes];return this.init(),o},t.OPTIONS={skipHtmlTags:["script","noscript","style","textarea","pre","code","annotation","annotation-xml"],includeHtmlTags:{br:`
 `,wbr:"","#comment":""},ignoreHtmlClass:"mathjax_ignore",processHtmlClass:"mathjax_process"},t})();u0.HTMLDomStrings=w_});var Su=p(Y2=>{"use strict";var B_=Y2&&Y2.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})(),l0=Y2&&Y2.__assign||function(){return l0=Object.assign||function(t){for(var r,e=1,Q=arguments.length;e<Q;e++){r=arguments[e];for(var T in r)Object.prototype.hasOwnProperty.call(r,T)&&(t[T]=r[T])}return t},l0.apply(this,arguments)},F9=Y2&&Y2.__read||function(t,r){var e=typeof Symbol=="function"&&t[Symbol.iterator];if(!e)return t;var Q=e.call(t),T,n=[],i;try{for(;(r===void 0||r-- >0)&&!(T=Q.next()).done;)n.push(T.value)}catch(a){i={error:a}}finally{try{T&&!T.done&&(e=Q.return)&&e.call(Q)}finally{if(i)throw i.error}}return n},k6=Y2&&Y2.__values||function(t){var r=typeof Symbol=="function"&&Symbol.iterator,e=r&&t[r],Q=0;if(e)return e.call(t);if(t&&typeof t.length=="number")return{next:function(){return t&&Q>=t.length&&(t=void 0),{value:t&&t[Q++],done:!t}}};throw new TypeError(r?"Object is not iterable.":"Symbol.iterator is not defined.")};Object.defineProperty(Y2,"__esModule",{value:!0});Y2.HTMLDocument=void 0;var cQ=lQ(),fQ=B1(),P_=Ou(),j_=Cu(),bu=Eu(),hQ=Y6(),N_=(function(t){B_(r,t);function r(e,Q,T){var n=this,i=F9((0,fQ.separateOptions)(T,bu.HTMLDomStrings.OPTIONS),2),a=i[0],o=i[1];return n=t.call(this,e,Q,a)||this,n.domStrings=n.options.DomStrings||new bu.HTMLDomStrings(o),n.domStrings.adaptor=Q,n.styles=[],n}return r.prototype.findPosition=function(e,Q,T,n){var i,a,o=this.adaptor;try{for(var u=k6(n[e]),l=u.next();!l.done;l=u.next()){var c=l.value,f=F9(c,2),h=f[0],d=f[1];if(Q<=d&&o.kind(h)==="#text")return{node:h,n:Math.max(Q,0),delim:T};Q-=d}}catch(L){i={error:L}}finally{try{l&&!l.done&&(a=u.return)&&a.call(u)}finally{if(i)throw i.error}}return{node:null,n:0,delim:T}},r.prototype.mathItem=function(e,Q,T){var n=e.math,i=this.findPosition(e.n,e.start.n,e.open,T),a=this.findPosition(e.n,e.end.n,e.close,T);return new this.options.MathItem(n,Q,e.display,i,a)},r.prototype.findMath=function(e){var Q,T,n,i,a,o,u,l,c;if(!this.processed.isSet("findMath")){this.adaptor.document=this.document,e=(0,fQ.userOptions)({elements:this.options.elements||[this.adaptor.body(this.document)]},e);try{for(var f=k6(this.adaptor.getElements(e.elements,this.document)),h=f.next();!h.done;h=f.next()){var d=h.value,L=F9([null,null],2),v=L[0],x=L[1];try{for(var H=(n=void 0,k6(this.inputJax)),M=H.next();!M.done;M=H.next()){var _=M.value,b=new this.options.MathList;if(_.processStrings){v===null&&(a=F9(this.domStrings.find(d),2),v=a[0],x=a[1]);try{for(var W=(o=void 0,k6(_.findMath(v))),A=W.next();!A.done;A=W.next()){var w=A.value;b.push(this.mathItem(w,_,x))}}catch(J){o={error:J}}finally{try{A&&!A.done&&(u=W.return)&&u.call(W)}finally{if(o)throw o.error}}}else try{for(var B=(l=void 0,k6(_.findMath(d))),k=B.next();!k.done;k=B.next()){var w=k.value,a1=new this.options.MathItem(w.math,_,w.display,w.start,w.end);b.push(a1)}}catch(J){l={error:J}}finally{try{k&&!k.done&&(c=B.return)&&c.call(B)}finally{if(l)throw l.error}}this.math.merge(b)}}catch(J){n={error:J}}finally{try{M&&!M.done&&(i=H.return)&&i.call(H)}finally{if(n)throw n.error}}}}catch(J){Q={error:J}}finally{try{h&&!h.done&&(T=f.return)&&T.call(f)}finally{if(Q)throw Q.error}}this.processed.set("findMath")}return this},r.prototype.updateDocument=function(){return this.processed.isSet("updateDocument")||(this.addPageElements(),this.addStyleSheet(),t.prototype.updateDocument.call(this),this.processed.set("updateDocument")),this},r.prototype.addPageElements=function(){var e=this.adaptor.body(this.document),Q=this.documentPageElements();Q&&this.adaptor.append(e,Q)},r.prototype.addStyleSheet=function(){var e=this.documentStyleSheet(),Q=this.adaptor;if(e&&!Q.parent(e)){var T=Q.head(this.document),n=this.findSheet(T,Q.getAttribute(e,"id"));n?Q.replace(e,n):Q.append(T,e)}},r.prototype.findSheet=function(e,Q){var T,n;if(Q)try{for(var i=k6(this.adaptor.tags(e,"style")),a=i.next();!a.done;a=i.next()){var o=a.value;if(this.adaptor.getAttribute(o,"id")===Q)return o}}catch(u){T={error:u}}finally{try{a&&!a.done&&(n=i.return)&&n.call(i)}finally{if(T)throw T.error}}return null},r.prototype.removeFromDocument=function(e){var Q,T;if(e===void 0&&(e=!1),this.processed.isSet("updateDocument"))try{for(var n=k6(this.math),i=n.next();!i.done;i=n.next()){var a=i.value;a.state()>=hQ.STATE.INSERTED&&a.state(hQ.STATE.TYPESET,e)}}catch(o){Q={error:o}}finally{try{i&&!i.done&&(T=n.return)&&T.call(n)}finally{if(Q)throw Q.error}}return this.processed.clear("updateDocument"),this},r.prototype.documentStyleSheet=function(){return this.outputJax.styleSheet(this)},r.prototype.documentPageElements=function(){return this.outputJax.pageElements(this)},r.prototype.addStyles=function(e){this.styles.push(e)},r.prototype.getStyles=function(){return this.styles},r.KIND="HTML",r.OPTIONS=l0(l0({},cQ.AbstractMathDocument.OPTIONS),{renderActions:(0,fQ.expandable)(l0(l0({},cQ.AbstractMathDocument.OPTIONS.renderActions),{styles:[hQ.STATE.INSERTED+1,"","updateStyleSheet",!1]})),MathList:j_.HTMLMathList,MathItem:P_.HTMLMathItem,DomStrings:null}),r})(cQ.AbstractMathDocument);Y2.HTMLDocument=N_});var wu=p(c0=>{"use strict";var k_=c0&&c0.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})();Object.defineProperty(c0,"__esModule",{value:!0});c0.HTMLHandler=void 0;var I_=Au(),F_=Su(),R_=(function(t){k_(r,t);function r(){var e=t!==null&&t.apply(this,arguments)||this;return e.documentClass=F_.HTMLDocument,e}return r.prototype.handlesDocument=function(e){var Q=this.adaptor;if(typeof e=="string")try{e=Q.parse(e,"text/html")}catch{}return e instanceof Q.window.Document||e instanceof Q.window.HTMLElement||e instanceof Q.window.DocumentFragment},r.prototype.create=function(e,Q){var T=this.adaptor;if(typeof e=="string")e=T.parse(e,"text/html");else if(e instanceof T.window.HTMLElement||e instanceof T.window.DocumentFragment){var n=e;e=T.parse("","text/html"),T.append(T.body(e),n)}return t.prototype.create.call(this,e,Q)},r})(I_.AbstractHandler);c0.HTMLHandler=R_});var Bu=p(R9=>{"use strict";Object.defineProperty(R9,"__esModule",{value:!0});R9.RegisterHTMLHandler=void 0;var q_=H7(),Z_=wu();function G_(t){var r=new Z_.HTMLHandler(t);return q_.mathjax.handlers.register(r),r}R9.RegisterHTMLHandler=G_});var ju=p(t3=>{"use strict";var Pu=t3&&t3.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(t3,"__esModule",{value:!0});t3.ActionConfiguration=t3.ActionMethods=void 0;var W_=i1(),X_=Pu(W1()),z_=T1(),U_=Pu(M2());t3.ActionMethods={};t3.ActionMethods.Macro=U_.default.Macro;t3.ActionMethods.Toggle=function(t,r){for(var e=[],Q;(Q=t.GetArgument(r))!=="\\endtoggle";)e.push(new X_.default(Q,t.stack.env,t.configuration).mml());t.Push(t.create("node","maction",e,{actiontype:"toggle"}))};t3.ActionMethods.Mathtip=function(t,r){var e=t.ParseArg(r),Q=t.ParseArg(r);t.Push(t.create("node","maction",[e,Q],{actiontype:"tooltip"}))};new z_.CommandMap("action-macros",{toggle:"Toggle",mathtip:"Mathtip",texttip:["Macro","\\mathtip{#1}{\\text{#2}}",2]},t3.ActionMethods);t3.ActionConfiguration=W_.Configuration.create("action",{handler:{macro:["action-macros"]}})});var vQ=p(r3=>{"use strict";var Nu=r3&&r3.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})(),LQ=r3&&r3.__assign||function(){return LQ=Object.assign||function(t){for(var r,e=1,Q=arguments.length;e<Q;e++){r=arguments[e];for(var T in r)Object.prototype.hasOwnProperty.call(r,T)&&(t[T]=r[T])}return t},LQ.apply(this,arguments)},pQ=r3&&r3.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(r3,"__esModule",{value:!0});r3.FlalignItem=r3.MultlineItem=void 0;var ku=_3(),$_=pQ(h1()),e3=pQ(H1()),Iu=pQ(l1()),dQ=X1(),K_=(function(t){Nu(r,t);function r(e){for(var Q=[],T=1;T<arguments.length;T++)Q[T-1]=arguments[T];var n=t.call(this,e)||this;return n.factory.configuration.tags.start("multline",!0,Q[0]),n}return Object.defineProperty(r.prototype,"kind",{get:function(){return"multline"},enumerable:!1,configurable:!0}),r.prototype.EndEntry=function(){this.table.length&&$_.default.fixInitialMO(this.factory.configuration,this.nodes);var e=this.getProperty("shove"),Q=this.create("node","mtd",this.nodes,e?{columnalign:e}:{});this.setProperty("shove",null),this.row.push(Q),this.Clear()},r.prototype.EndRow=function(){if(this.row.length!==1)throw new Iu.default("MultlineRowsOneCol","The rows within the %1 environment must have exactly one column","multline");var e=this.create("node","mtr",this.row);this.table.push(e),this.row=[]},r.prototype.EndTable=function(){if(t.prototype.EndTable.call(this),this.table.length){var e=this.table.length-1,Q=-1;e3.default.getAttribute(e3.default.getChildren(this.table[0])[0],"columnalign")||e3.default.setAttribute(e3.default.getChildren(this.table[0])[0],"columnalign",dQ.TexConstant.Align.LEFT),e3.default.getAttribute(e3.default.getChildren(this.table[e])[0],"columnalign")||e3.default.setAttribute(e3.default.getChildren(this.table[e])[0],"columnalign",dQ.TexConstant.Align.RIGHT);var T=this.factory.configuration.tags.getTag();if(T){Q=this.arraydef.side===dQ.TexConstant.Align.LEFT?0:this.table.length-1;var n=this.table[Q],i=this.create("node","mlabeledtr",[T].concat(e3.default.getChildren(n)));e3.default.copyAttributes(n,i),this.table[Q]=i}}this.factory.configuration.tags.end()},r})(ku.ArrayItem);r3.MultlineItem=K_;var J_=(function(t){Nu(r,t);function r(e,Q,T,n,i){var a=t.call(this,e)||this;return a.name=Q,a.numbered=T,a.padded=n,a.center=i,a.factory.configuration.tags.start(Q,T,T),a}return Object.defineProperty(r.prototype,"kind",{get:function(){return"flalign"},enumerable:!1,configurable:!0}),r.prototype.EndEntry=function(){t.prototype.EndEntry.call(this);var e=this.getProperty("xalignat");if(e&&this.row.length>e)throw new Iu.default("XalignOverflow","Extra %1 in row of %2","&",this.name)},r.prototype.EndRow=function(){for(var e,Q=this.row,T=this.getProperty("xalignat");Q.length<T;)Q.push(this.create("node","mtd"));for(this.row=[],this.padded&&this.row.push(this.create("node","mtd"));e=Q.shift();)this.row.push(e),e=Q.shift(),e&&this.row.push(e),(Q.length||this.padded)&&this.row.push(this.create("node","mtd"));this.row.length>this.maxrow&&(this.maxrow=this.row.length),t.prototype.EndRow.call(this);var n=this.table[this.table.length-1];if(this.getProperty("zeroWidthLabel")&&n.isKind("mlabeledtr")){var i=e3.default.getChildren(n)[0],a=this.factory.configuration.options.tagSide,o=LQ({width:0},a==="right"?{lspace:"-1width"}:{}),u=this.create("node","mpadded",e3.default.getChildren(i),o);i.setChildren([u])}},r.prototype.EndTable=function(){if(t.prototype.EndTable.call(this),this.center&&this.maxrow<=2){var e=this.arraydef;delete e.width,delete this.global.indentalign}},r})(ku.EqnArrayItem);r3.FlalignItem=J_});var h0=p(K=>{"use strict";var q9=K&&K.__assign||function(){return q9=Object.assign||function(t){for(var r,e=1,Q=arguments.length;e<Q;e++){r=arguments[e];for(var T in r)Object.prototype.hasOwnProperty.call(r,T)&&(t[T]=r[T])}return t},q9.apply(this,arguments)},Fu=K&&K.__read||function(t,r){var e=typeof Symbol=="function"&&t[Symbol.iterator];if(!e)return t;var Q=e.call(t),T,n=[],i;try{for(;(r===void 0||r-- >0)&&!(T=Q.next()).done;)n.push(T.value)}catch(a){i={error:a}}finally{try{T&&!T.done&&(e=Q.return)&&e.call(Q)}finally{if(i)throw i.error}}return n},f0=K&&K.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(K,"__esModule",{value:!0});K.NEW_OPS=K.AmsMethods=void 0;var V2=f0(h1()),Y_=f0(O3()),O1=f0(H1()),xQ=X1(),Z9=f0(W1()),f6=f0(l1()),tA=b6(),d3=f0(M2()),HQ=R();K.AmsMethods={};K.AmsMethods.AmsEqnArray=function(t,r,e,Q,T,n,i){var a=t.GetBrackets("\\begin{"+r.getName()+"}"),o=d3.default.EqnArray(t,r,e,Q,T,n,i);return V2.default.setArrayAlign(o,a)};K.AmsMethods.AlignAt=function(t,r,e,Q){var T=r.getName(),n,i,a="",o=[];if(Q||(i=t.GetBrackets("\\begin{"+T+"}")),n=t.GetArgument("\\begin{"+T+"}"),n.match(/[^0-9]/))throw new f6.default("PositiveIntegerArg","Argument to %1 must me a positive integer","\\begin{"+T+"}");for(var u=parseInt(n,10);u>0;)a+="rl",o.push("0em 0em"),u--;var l=o.join(" ");if(Q)return K.AmsMethods.EqnArray(t,r,e,Q,a,l);var c=K.AmsMethods.EqnArray(t,r,e,Q,a,l);return V2.default.setArrayAlign(c,i)};K.AmsMethods.Multline=function(t,r,e){t.Push(r),V2.default.checkEqnEnv(t);var Q=t.itemFactory.create("multline",e,t.stack);return Q.arraydef={displaystyle:!0,rowspacing:".5em",columnspacing:"100%",width:t.options.ams.multlineWidth,side:t.options.tagSide,minlabelspacing:t.options.tagIndent,framespacing:t.options.ams.multlineIndent+" 0",frame:"","data-width-includes-label":!0},Q};K.AmsMethods.XalignAt=function(t,r,e,Q){var T=t.GetArgument("\\begin{"+r.getName()+"}");if(T.match(/[^0-9]/))throw new f6.default("PositiveIntegerArg","Argument to %1 must me a positive integer","\\begin{"+r.getName()+"}");var n=Q?"crl":"rlc",i=Q?"fit auto auto":"auto auto fit",a=K.AmsMethods.FlalignArray(t,r,e,Q,!1,n,i,!0);return a.setProperty("xalignat",2*parseInt(T)),a};K.AmsMethods.FlalignArray=function(t,r,e,Q,T,n,i,a){a===void 0&&(a=!1),t.Push(r),V2.default.checkEqnEnv(t),n=n.split("").join(" ").replace(/r/g,"right").replace(/l/g,"left").replace(/c/g,"center");var o=t.itemFactory.create("flalign",r.getName(),e,Q,T,t.stack);return o.arraydef={width:"100%",displaystyle:!0,columnalign:n,columnspacing:"0em",columnwidth:i,rowspacing:"3pt",side:t.options.tagSide,minlabelspacing:a?"0":t.options.tagIndent,"data-width-includes-label":!0},o.setProperty("zeroWidthLabel",a),o};K.NEW_OPS="ams-declare-ops";K.AmsMethods.HandleDeclareOp=function(t,r){var e=t.GetStar()?"*":"",Q=V2.default.trimSpaces(t.GetArgument(r));Q.charAt(0)==="\\"&&(Q=Q.substr(1));var T=t.GetArgument(r);t.configuration.handlers.retrieve(K.NEW_OPS).add(Q,new tA.Macro(Q,K.AmsMethods.Macro,["\\operatorname".concat(e,"{").concat(T,"}")]))};K.AmsMethods.HandleOperatorName=function(t,r){var e=t.GetStar(),Q=V2.default.trimSpaces(t.GetArgument(r)),T=new Z9.default(Q,q9(q9({},t.stack.env),{font:xQ.TexConstant.Variant.NORMAL,multiLetterIdentifiers:/^[-*a-z]+/i,operatorLetters:!0}),t.configuration).mml();if(T.isKind("mi")||(T=t.create("node","TeXAtom",[T])),O1.default.setProperties(T,{movesupsub:e,movablelimits:!0,texClass:HQ.TEXCLASS.OP}),!e){var n=t.GetNext(),i=t.i;n==="\\"&&++t.i&&t.GetCS()!=="limits"&&(t.i=i)}t.Push(T)};K.AmsMethods.SideSet=function(t,r){var e=Fu(Ru(t.ParseArg(r)),2),Q=e[0],T=e[1],n=Fu(Ru(t.ParseArg(r)),2),i=n[0],a=n[1],o=t.ParseArg(r),u=o;Q&&(T?Q.replaceChild(t.create("node","mphantom",[t.create("node","mpadded",[V2.default.copyNode(o,t)],{width:0})]),O1.default.getChildAt(Q,0)):(u=t.create("node","mmultiscripts",[o]),i&&O1.default.appendChildren(u,[O1.default.getChildAt(i,1)||t.create("node","none"),O1.default.getChildAt(i,2)||t.create("node","none")]),O1.default.setProperty(u,"scriptalign","left"),O1.default.appendChildren(u,[t.create("node","mprescripts"),O1.default.getChildAt(Q,1)||t.create("node","none"),O1.default.getChildAt(Q,2)||t.create("node","none")]))),i&&u===o&&(i.replaceChild(o,O1.default.getChildAt(i,0)),u=i);var l=t.create("node","TeXAtom",[],{texClass:HQ.TEXCLASS.OP,movesupsub:!0,movablelimits:!0});T&&(Q&&l.appendChild(Q),l.appendChild(T)),l.appendChild(u),a&&l.appendChild(a),t.Push(l)};function Ru(t){if(!t||t.isInferred&&t.childNodes.length===0)return[null,null];if(t.isKind("msubsup")&&qu(t))return[t,null];var r=O1.default.getChildAt(t,0);return t.isInferred&&r&&qu(r)?(t.childNodes.splice(0,1),[r,t]):[null,t]}function qu(t){var r=t.childNodes[0];return r&&r.isKind("mi")&&r.getText()===""}K.AmsMethods.operatorLetter=function(t,r){return t.stack.env.operatorLetters?Y_.default.variable(t,r):!1};K.AmsMethods.MultiIntegral=function(t,r,e){var Q=t.GetNext();if(Q==="\\"){var T=t.i;Q=t.GetArgument(r),t.i=T,Q==="\\limits"&&(r==="\\idotsint"?e="\\!\\!\\mathop{\\,\\,"+e+"}":e="\\!\\!\\!\\mathop{\\,\\,\\,"+e+"}")}t.string=e+" "+t.string.slice(t.i),t.i=0};K.AmsMethods.xArrow=function(t,r,e,Q,T){var n={width:"+"+V2.default.Em((Q+T)/18),lspace:V2.default.Em(Q/18)},i=t.GetBrackets(r),a=t.ParseArg(r),o=t.create("node","mspace",[],{depth:".25em"}),u=t.create("token","mo",{stretchy:!0,texClass:HQ.TEXCLASS.REL},String.fromCodePoint(e));u=t.create("node","mstyle",[u],{scriptlevel:0});var l=t.create("node","munderover",[u]),c=t.create("node","mpadded",[a,o],n);if(O1.default.setAttribute(c,"voffset","-.2em"),O1.default.setAttribute(c,"height","-.2em"),O1.default.setChild(l,l.over,c),i){var f=new Z9.default(i,t.stack.env,t.configuration).mml(),h=t.create("node","mspace",[],{height:".75em"});c=t.create("node","mpadded",[f,h],n),O1.default.setAttribute(c,"voffset",".15em"),O1.default.setAttribute(c,"depth","-.15em"),O1.default.setChild(l,l.under,c)}O1.default.setProperty(l,"subsupOK",!0),t.Push(l)};K.AmsMethods.HandleShove=function(t,r,e){var Q=t.stack.Top();if(Q.kind!=="multline")throw new f6.default("CommandOnlyAllowedInEnv","%1 only allowed in %2 environment",t.currentCS,"multline");if(Q.Size())throw new f6.default("CommandAtTheBeginingOfLine","%1 must come at the beginning of the line",t.currentCS);Q.setProperty("shove",e)};K.AmsMethods.CFrac=function(t,r){var e=V2.default.trimSpaces(t.GetBrackets(r,"")),Q=t.GetArgument(r),T=t.GetArgument(r),n={l:xQ.TexConstant.Align.LEFT,r:xQ.TexConstant.Align.RIGHT,"":""},i=new Z9.default("\\strut\\textstyle{"+Q+"}",t.stack.env,t.configuration).mml(),a=new Z9.default("\\strut\\textstyle{"+T+"}",t.stack.env,t.configuration).mml(),o=t.create("node","mfrac",[i,a]);if(e=n[e],e==null)throw new f6.default("IllegalAlign","Illegal alignment specified in %1",t.currentCS);e&&O1.default.setProperties(o,{numalign:e,denomalign:e}),t.Push(o)};K.AmsMethods.Genfrac=function(t,r,e,Q,T,n){e==null&&(e=t.GetDelimiterArg(r)),Q==null&&(Q=t.GetDelimiterArg(r)),T==null&&(T=t.GetArgument(r)),n==null&&(n=V2.default.trimSpaces(t.GetArgument(r)));var i=t.ParseArg(r),a=t.ParseArg(r),o=t.create("node","mfrac",[i,a]);if(T!==""&&O1.default.setAttribute(o,"linethickness",T),(e||Q)&&(O1.default.setProperty(o,"withDelims",!0),o=V2.default.fixedFence(t.configuration,e,o,Q)),n!==""){var u=parseInt(n,10),l=["D","T","S","SS"][u];if(l==null)throw new f6.default("BadMathStyleFor","Bad math style for %1",t.currentCS);o=t.create("node","mstyle",[o]),l==="D"?O1.default.setProperties(o,{displaystyle:!0,scriptlevel:0}):O1.default.setProperties(o,{displaystyle:!1,scriptlevel:u-1})}t.Push(o)};K.AmsMethods.HandleTag=function(t,r){if(!t.tags.currentTag.taggable&&t.tags.env)throw new f6.default("CommandNotAllowedInEnv","%1 not allowed in %2 environment",t.currentCS,t.tags.env);if(t.tags.currentTag.tag)throw new f6.default("MultipleCommand","Multiple %1",t.currentCS);var e=t.GetStar(),Q=V2.default.trimSpaces(t.GetArgument(r));t.tags.tag(Q,e)};K.AmsMethods.HandleNoTag=d3.default.HandleNoTag;K.AmsMethods.HandleRef=d3.default.HandleRef;K.AmsMethods.Macro=d3.default.Macro;K.AmsMethods.Accent=d3.default.Accent;K.AmsMethods.Tilde=d3.default.Tilde;K.AmsMethods.Array=d3.default.Array;K.AmsMethods.Spacer=d3.default.Spacer;K.AmsMethods.NamedOp=d3.default.NamedOp;K.AmsMethods.EqnArray=d3.default.EqnArray;K.AmsMethods.Equation=d3.default.Equation});var Gu=p(U3=>{"use strict";var eA=U3&&U3.__createBinding||(Object.create?(function(t,r,e,Q){Q===void 0&&(Q=e);var T=Object.getOwnPropertyDescriptor(r,e);(!T||("get"in T?!r.__esModule:T.writable||T.configurable))&&(T={enumerable:!0,get:function(){return r[e]}}),Object.defineProperty(t,Q,T)}):(function(t,r,e,Q){Q===void 0&&(Q=e),t[Q]=r[e]})),rA=U3&&U3.__setModuleDefault||(Object.create?(function(t,r){Object.defineProperty(t,"default",{enumerable:!0,value:r})}):function(t,r){t.default=r}),QA=U3&&U3.__importStar||function(t){if(t&&t.__esModule)return t;var r={};if(t!=null)for(var e in t)e!=="default"&&Object.prototype.hasOwnProperty.call(t,e)&&eA(r,t,e);return rA(r,t),r},Zu=U3&&U3.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(U3,"__esModule",{value:!0});var G9=h0(),L4=QA(T1()),Q2=X1(),d0=Zu(O3()),I6=Zu(h1()),e7=R(),yQ=A3();new L4.CharacterMap("AMSmath-mathchar0mo",d0.default.mathchar0mo,{iiiint:["\u2A0C",{texClass:e7.TEXCLASS.OP}]});new L4.RegExpMap("AMSmath-operatorLetter",G9.AmsMethods.operatorLetter,/[-*]/i);new L4.CommandMap("AMSmath-macros",{mathring:["Accent","02DA"],nobreakspace:"Tilde",negmedspace:["Spacer",yQ.MATHSPACE.negativemediummathspace],negthickspace:["Spacer",yQ.MATHSPACE.negativethickmathspace],idotsint:["MultiIntegral","\\int\\cdots\\int"],dddot:["Accent","20DB"],ddddot:["Accent","20DC"],sideset:"SideSet",boxed:["Macro","\\fbox{$\\displaystyle{#1}$}",1],tag:"HandleTag",notag:"HandleNoTag",eqref:["HandleRef",!0],substack:["Macro","\\begin{subarray}{c}#1\\end{subarray}",1],injlim:["NamedOp","inj&thinsp;lim"],projlim:["NamedOp","proj&thinsp;lim"],varliminf:["Macro","\\mathop{\\underline{\\mmlToken{mi}{lim}}}"],varlimsup:["Macro","\\mathop{\\overline{\\mmlToken{mi}{lim}}}"],varinjlim:["Macro","\\mathop{\\underrightarrow{\\mmlToken{mi}{lim}}}"],varprojlim:["Macro","\\mathop{\\underleftarrow{\\mmlToken{mi}{lim}}}"],DeclareMathOperator:"HandleDeclareOp",operatorname:"HandleOperatorName",genfrac:"Genfrac",frac:["Genfrac","","","",""],tfrac:["Genfrac","","","","1"],dfrac:["Genfrac","","","","0"],binom:["Genfrac","(",")","0",""],tbinom:["Genfrac","(",")","0","1"],dbinom:["Genfrac","(",")","0","0"],cfrac:"CFrac",shoveleft:["HandleShove",Q2.TexConstant.Align.LEFT],shoveright:["HandleShove",Q2.TexConstant.Align.RIGHT],xrightarrow:["xArrow",8594,5,10],xleftarrow:["xArrow",8592,10,5]},G9.AmsMethods);new L4.EnvironmentMap("AMSmath-environment",d0.default.environment,{"equation*":["Equation",null,!1],"eqnarray*":["EqnArray",null,!1,!0,"rcl",I6.default.cols(0,yQ.MATHSPACE.thickmathspace),".5em"],align:["EqnArray",null,!0,!0,"rl",I6.default.cols(0,2)],"align*":["EqnArray",null,!1,!0,"rl",I6.default.cols(0,2)],multline:["Multline",null,!0],"multline*":["Multline",null,!1],split:["EqnArray",null,!1,!1,"rl",I6.default.cols(0)],gather:["EqnArray",null,!0,!0,"c"],"gather*":["EqnArray",null,!1,!0,"c"],alignat:["AlignAt",null,!0,!0],"alignat*":["AlignAt",null,!1,!0],alignedat:["AlignAt",null,!1,!1],aligned:["AmsEqnArray",null,null,null,"rl",I6.default.cols(0,2),".5em","D"],gathered:["AmsEqnArray",null,null,null,"c",null,".5em","D"],xalignat:["XalignAt",null,!0,!0],"xalignat*":["XalignAt",null,!1,!0],xxalignat:["XalignAt",null,!1,!1],flalign:["FlalignArray",null,!0,!1,!0,"rlc","auto auto fit"],"flalign*":["FlalignArray",null,!1,!1,!0,"rlc","auto auto fit"],subarray:["Array",null,null,null,null,I6.default.cols(0),"0.1em","S",1],smallmatrix:["Array",null,null,null,"c",I6.default.cols(1/3),".2em","S",1],matrix:["Array",null,null,null,"c"],pmatrix:["Array",null,"(",")","c"],bmatrix:["Array",null,"[","]","c"],Bmatrix:["Array",null,"\\{","\\}","c"],vmatrix:["Array",null,"\\vert","\\vert","c"],Vmatrix:["Array",null,"\\Vert","\\Vert","c"],cases:["Array",null,"\\{",".","ll",null,".2em","T"]},G9.AmsMethods);new L4.DelimiterMap("AMSmath-delimiter",d0.default.delimiter,{"\\lvert":["|",{texClass:e7.TEXCLASS.OPEN}],"\\rvert":["|",{texClass:e7.TEXCLASS.CLOSE}],"\\lVert":["\u2016",{texClass:e7.TEXCLASS.OPEN}],"\\rVert":["\u2016",{texClass:e7.TEXCLASS.CLOSE}]});new L4.CharacterMap("AMSsymbols-mathchar0mi",d0.default.mathchar0mi,{digamma:"\u03DD",varkappa:"\u03F0",varGamma:["\u0393",{mathvariant:Q2.TexConstant.Variant.ITALIC}],varDelta:["\u0394",{mathvariant:Q2.TexConstant.Variant.ITALIC}],varTheta:["\u0398",{mathvariant:Q2.TexConstant.Variant.ITALIC}],varLambda:["\u039B",{mathvariant:Q2.TexConstant.Variant.ITALIC}],varXi:["\u039E",{mathvariant:Q2.TexConstant.Variant.ITALIC}],varPi:["\u03A0",{mathvariant:Q2.TexConstant.Variant.ITALIC}],varSigma:["\u03A3",{mathvariant:Q2.TexConstant.Variant.ITALIC}],varUpsilon:["\u03A5",{mathvariant:Q2.TexConstant.Variant.ITALIC}],varPhi:["\u03A6",{mathvariant:Q2.TexConstant.Variant.ITALIC}],varPsi:["\u03A8",{mathvariant:Q2.TexConstant.Variant.ITALIC}],varOmega:["\u03A9",{mathvariant:Q2.TexConstant.Variant.ITALIC}],beth:"\u2136",gimel:"\u2137",daleth:"\u2138",backprime:["\u2035",{variantForm:!0}],hslash:"\u210F",varnothing:["\u2205",{variantForm:!0}],blacktriangle:"\u25B4",triangledown:["\u25BD",{variantForm:!0}],blacktriangledown:"\u25BE",square:"\u25FB",Box:"\u25FB",blacksquare:"\u25FC",lozenge:"\u25CA",Diamond:"\u25CA",blacklozenge:"\u29EB",circledS:["\u24C8",{mathvariant:Q2.TexConstant.Variant.NORMAL}],bigstar:"\u2605",sphericalangle:"\u2222",measuredangle:"\u2221",nexists:"\u2204",complement:"\u2201",mho:"\u2127",eth:["\xF0",{mathvariant:Q2.TexConstant.Variant.NORMAL}],Finv:"\u2132",diagup:"\u2571",Game:"\u2141",diagdown:"\u2572",Bbbk:["k",{mathvariant:Q2.TexConstant.Variant.DOUBLESTRUCK}],yen:"\xA5",circledR:"\xAE",checkmark:"\u2713",maltese:"\u2720"});new L4.CharacterMap("AMSsymbols-mathchar0mo",d0.default.mathchar0mo,{dotplus:"\u2214",ltimes:"\u22C9",smallsetminus:["\u2216",{variantForm:!0}],rtimes:"\u22CA",Cap:"\u22D2",doublecap:"\u22D2",leftthreetimes:"\u22CB",Cup:"\u22D3",doublecup:"\u22D3",rightthreetimes:"\u22CC",barwedge:"\u22BC",curlywedge:"\u22CF",veebar:"\u22BB",curlyvee:"\u22CE",doublebarwedge:"\u2A5E",boxminus:"\u229F",circleddash:"\u229D",boxtimes:"\u22A0",circledast:"\u229B",boxdot:"\u22A1",circledcirc:"\u229A",boxplus:"\u229E",centerdot:["\u22C5",{variantForm:!0}],divideontimes:"\u22C7",intercal:"\u22BA",leqq:"\u2266",geqq:"\u2267",leqslant:"\u2A7D",geqslant:"\u2A7E",eqslantless:"\u2A95",eqslantgtr:"\u2A96",lesssim:"\u2272",gtrsim:"\u2273",lessapprox:"\u2A85",gtrapprox:"\u2A86",approxeq:"\u224A",lessdot:"\u22D6",gtrdot:"\u22D7",lll:"\u22D8",llless:"\u22D8",ggg:"\u22D9",gggtr:"\u22D9",lessgtr:"\u2276",gtrless:"\u2277",lesseqgtr:"\u22DA",gtreqless:"\u22DB",lesseqqgtr:"\u2A8B",gtreqqless:"\u2A8C",doteqdot:"\u2251",Doteq:"\u2251",eqcirc:"\u2256",risingdotseq:"\u2253",circeq:"\u2257",fallingdotseq:"\u2252",triangleq:"\u225C",backsim:"\u223D",thicksim:["\u223C",{variantForm:!0}],backsimeq:"\u22CD",thickapprox:["\u2248",{variantForm:!0}],subseteqq:"\u2AC5",supseteqq:"\u2AC6",Subset:"\u22D0",Supset:"\u22D1",sqsubset:"\u228F",sqsupset:"\u2290",preccurlyeq:"\u227C",succcurlyeq:"\u227D",curlyeqprec:"\u22DE",curlyeqsucc:"\u22DF",precsim:"\u227E",succsim:"\u227F",precapprox:"\u2AB7",succapprox:"\u2AB8",vartriangleleft:"\u22B2",lhd:"\u22B2",vartriangleright:"\u22B3",rhd:"\u22B3",trianglelefteq:"\u22B4",unlhd:"\u22B4",trianglerighteq:"\u22B5",unrhd:"\u22B5",vDash:["\u22A8",{variantForm:!0}],Vdash:"\u22A9",Vvdash:"\u22AA",smallsmile:["\u2323",{variantForm:!0}],shortmid:["\u2223",{variantForm:!0}],smallfrown:["\u2322",{variantForm:!0}],shortparallel:["\u2225",{variantForm:!0}],bumpeq:"\u224F",between:"\u226C",Bumpeq:"\u224E",pitchfork:"\u22D4",varpropto:["\u221D",{variantForm:!0}],backepsilon:"\u220D",blacktriangleleft:"\u25C2",blacktriangleright:"\u25B8",therefore:"\u2234",because:"\u2235",eqsim:"\u2242",vartriangle:["\u25B3",{variantForm:!0}],Join:"\u22C8",nless:"\u226E",ngtr:"\u226F",nleq:"\u2270",ngeq:"\u2271",nleqslant:["\u2A87",{variantForm:!0}],ngeqslant:["\u2A88",{variantForm:!0}],nleqq:["\u2270",{variantForm:!0}],ngeqq:["\u2271",{variantForm:!0}],lneq:"\u2A87",gneq:"\u2A88",lneqq:"\u2268",gneqq:"\u2269",lvertneqq:["\u2268",{variantForm:!0}],gvertneqq:["\u2269",{variantForm:!0}],lnsim:"\u22E6",gnsim:"\u22E7",lnapprox:"\u2A89",gnapprox:"\u2A8A",nprec:"\u2280",nsucc:"\u2281",npreceq:["\u22E0",{variantForm:!0}],nsucceq:["\u22E1",{variantForm:!0}],precneqq:"\u2AB5",succneqq:"\u2AB6",precnsim:"\u22E8",succnsim:"\u22E9",precnapprox:"\u2AB9",succnapprox:"\u2ABA",nsim:"\u2241",ncong:"\u2247",nshortmid:["\u2224",{variantForm:!0}],nshortparallel:["\u2226",{variantForm:!0}],nmid:"\u2224",nparallel:"\u2226",nvdash:"\u22AC",nvDash:"\u22AD",nVdash:"\u22AE",nVDash:"\u22AF",ntriangleleft:"\u22EA",ntriangleright:"\u22EB",ntrianglelefteq:"\u22EC",ntrianglerighteq:"\u22ED",nsubseteq:"\u2288",nsupseteq:"\u2289",nsubseteqq:["\u2288",{variantForm:!0}],nsupseteqq:["\u2289",{variantForm:!0}],subsetneq:"\u228A",supsetneq:"\u228B",varsubsetneq:["\u228A",{variantForm:!0}],varsupsetneq:["\u228B",{variantForm:!0}],subsetneqq:"\u2ACB",supsetneqq:"\u2ACC",varsubsetneqq:["\u2ACB",{variantForm:!0}],varsupsetneqq:["\u2ACC",{variantForm:!0}],leftleftarrows:"\u21C7",rightrightarrows:"\u21C9",leftrightarrows:"\u21C6",rightleftarrows:"\u21C4",Lleftarrow:"\u21DA",Rrightarrow:"\u21DB",twoheadleftarrow:"\u219E",twoheadrightarrow:"\u21A0",leftarrowtail:"\u21A2",rightarrowtail:"\u21A3",looparrowleft:"\u21AB",looparrowright:"\u21AC",leftrightharpoons:"\u21CB",rightleftharpoons:["\u21CC",{variantForm:!0}],curvearrowleft:"\u21B6",curvearrowright:"\u21B7",circlearrowleft:"\u21BA",circlearrowright:"\u21BB",Lsh:"\u21B0",Rsh:"\u21B1",upuparrows:"\u21C8",downdownarrows:"\u21CA",upharpoonleft:"\u21BF",upharpoonright:"\u21BE",downharpoonleft:"\u21C3",restriction:"\u21BE",multimap:"\u22B8",downharpoonright:"\u21C2",leftrightsquigarrow:"\u21AD",rightsquigarrow:"\u21DD",leadsto:"\u21DD",dashrightarrow:"\u21E2",dashleftarrow:"\u21E0",nleftarrow:"\u219A",nrightarrow:"\u219B",nLeftarrow:"\u21CD",nRightarrow:"\u21CF",nleftrightarrow:"\u21AE",nLeftrightarrow:"\u21CE"});new L4.DelimiterMap("AMSsymbols-delimiter",d0.default.delimiter,{"\\ulcorner":"\u231C","\\urcorner":"\u231D","\\llcorner":"\u231E","\\lrcorner":"\u231F"});new L4.CommandMap("AMSsymbols-macros",{implies:["Macro","\\;\\Longrightarrow\\;"],impliedby:["Macro","\\;\\Longleftarrow\\;"]},G9.AmsMethods)});var mQ=p(h6=>{"use strict";var TA=h6&&h6.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})(),W9;Object.defineProperty(h6,"__esModule",{value:!0});h6.AmsConfiguration=h6.AmsTags=void 0;var Xu=i1(),X9=vQ(),nA=s4(),Wu=h0();Gu();var iA=T1(),zu=(function(t){TA(r,t);function r(){return t!==null&&t.apply(this,arguments)||this}return r})(nA.AbstractTags);h6.AmsTags=zu;var aA=function(t){new iA.CommandMap(Wu.NEW_OPS,{},{}),t.append(Xu.Configuration.local({handler:{macro:[Wu.NEW_OPS]},priority:-1}))};h6.AmsConfiguration=Xu.Configuration.create("ams",{handler:{character:["AMSmath-operatorLetter"],delimiter:["AMSsymbols-delimiter","AMSmath-delimiter"],macro:["AMSsymbols-mathchar0mi","AMSsymbols-mathchar0mo","AMSsymbols-delimiter","AMSsymbols-macros","AMSmath-mathchar0mo","AMSmath-macros","AMSmath-delimiter"],environment:["AMSmath-environment"]},items:(W9={},W9[X9.MultlineItem.prototype.kind]=X9.MultlineItem,W9[X9.FlalignItem.prototype.kind]=X9.FlalignItem,W9),tags:{ams:zu},init:aA,config:function(t,r){r.parseOptions.options.multlineWidth&&(r.parseOptions.options.ams.multlineWidth=r.parseOptions.options.multlineWidth),delete r.parseOptions.options.multlineWidth},options:{multlineWidth:"",ams:{multlineWidth:"100%",multlineIndent:"1em"}}})});var $u=p(r7=>{"use strict";var Uu=r7&&r7.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(r7,"__esModule",{value:!0});var z9=Uu(W1()),oA=B7(),sA=R(),L0=Uu(H1()),p4={};p4.CD=function(t,r){t.Push(r);var e=t.itemFactory.create("array"),Q=t.configuration.options.amscd;return e.setProperties({minw:t.stack.env.CD_minw||Q.harrowsize,minh:t.stack.env.CD_minh||Q.varrowsize}),e.arraydef={columnalign:"center",columnspacing:Q.colspace,rowspacing:Q.rowspace,displaystyle:!0},e};p4.arrow=function(t,r){var e=t.string.charAt(t.i);if(e.match(/[><VA.|=]/))t.i++;else return(0,oA.Other)(t,r);var Q=t.stack.Top();(!Q.isKind("array")||Q.Size())&&(p4.cell(t,r),Q=t.stack.Top());for(var T=Q,n=T.table.length%2===1,i=(T.row.length+(n?0:1))%2;i;)p4.cell(t,r),i--;var a,o={minsize:T.getProperty("minw"),stretchy:!0},u={minsize:T.getProperty("minh"),stretchy:!0,symmetric:!0,lspace:0,rspace:0};if(e!==".")if(e==="|")a=t.create("token","mo",u,"\u2225");else if(e==="=")a=t.create("token","mo",o,"=");else{var l={">":"\u2192","<":"\u2190",V:"\u2193",A:"\u2191"}[e],c=t.GetUpTo(r+e,e),f=t.GetUpTo(r+e,e);if(e===">"||e==="<"){if(a=t.create("token","mo",o,l),c||(c="\\kern "+T.getProperty("minw")),c||f){var h={width:"+.67em",lspace:".33em"};if(a=t.create("node","munderover",[a]),c){var d=new z9.default(c,t.stack.env,t.configuration).mml(),L=t.create("node","mpadded",[d],h);L0.default.setAttribute(L,"voffset",".1em"),L0.default.setChild(a,a.over,L)}if(f){var v=new z9.default(f,t.stack.env,t.configuration).mml();L0.default.setChild(a,a.under,t.create("node","mpadded",[v],h))}t.configuration.options.amscd.hideHorizontalLabels&&(a=t.create("node","mpadded",a,{depth:0,height:".67em"}))}}else{var x=t.create("token","mo",u,l);a=x,(c||f)&&(a=t.create("node","mrow"),c&&L0.default.appendChildren(a,[new z9.default("\\scriptstyle\\llap{"+c+"}",t.stack.env,t.configuration).mml()]),x.texClass=sA.TEXCLASS.ORD,L0.default.appendChildren(a,[x]),f&&L0.default.appendChildren(a,[new z9.default("\\scriptstyle\\rlap{"+f+"}",t.stack.env,t.configuration).mml()]))}}a&&t.Push(a),p4.cell(t,r)};p4.cell=function(t,r){var e=t.stack.Top();(e.table||[]).length%2===0&&(e.row||[]).length===0&&t.Push(t.create("node","mpadded",[],{height:"8.5pt",depth:"2pt"})),t.Push(t.itemFactory.create("cell").setProperties({isEntry:!0,name:r}))};p4.minCDarrowwidth=function(t,r){t.stack.env.CD_minw=t.GetDimen(r)};p4.minCDarrowheight=function(t,r){t.stack.env.CD_minh=t.GetDimen(r)};r7.default=p4});var Ju=p($3=>{"use strict";var uA=$3&&$3.__createBinding||(Object.create?(function(t,r,e,Q){Q===void 0&&(Q=e);var T=Object.getOwnPropertyDescriptor(r,e);(!T||("get"in T?!r.__esModule:T.writable||T.configurable))&&(T={enumerable:!0,get:function(){return r[e]}}),Object.defineProperty(t,Q,T)}):(function(t,r,e,Q){Q===void 0&&(Q=e),t[Q]=r[e]})),lA=$3&&$3.__setModuleDefault||(Object.create?(function(t,r){Object.defineProperty(t,"default",{enumerable:!0,value:r})}):function(t,r){t.default=r}),cA=$3&&$3.__importStar||function(t){if(t&&t.__esModule)return t;var r={};if(t!=null)for(var e in t)e!=="default"&&Object.prototype.hasOwnProperty.call(t,e)&&uA(r,t,e);return lA(r,t),r},Ku=$3&&$3.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty($3,"__esModule",{value:!0});var gQ=cA(T1()),fA=Ku(O3()),MQ=Ku($u());new gQ.EnvironmentMap("amscd_environment",fA.default.environment,{CD:"CD"},MQ.default);new gQ.CommandMap("amscd_macros",{minCDarrowwidth:"minCDarrowwidth",minCDarrowheight:"minCDarrowheight"},MQ.default);new gQ.MacroMap("amscd_special",{"@":"arrow"},MQ.default)});var Yu=p(U9=>{"use strict";Object.defineProperty(U9,"__esModule",{value:!0});U9.AmsCdConfiguration=void 0;var hA=i1();Ju();U9.AmsCdConfiguration=hA.Configuration.create("amscd",{handler:{character:["amscd_special"],macro:["amscd_macros"],environment:["amscd_environment"]},options:{amscd:{colspace:"5pt",rowspace:"5pt",harrowsize:"2.75em",varrowsize:"1.75em",hideHorizontalLabels:!1}}})});var tl=p(K3=>{"use strict";var dA=K3&&K3.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(K3,"__esModule",{value:!0});K3.BboxConfiguration=K3.BboxMethods=void 0;var LA=i1(),pA=T1(),$9=dA(l1());K3.BboxMethods={};K3.BboxMethods.BBox=function(t,r){for(var e=t.GetBrackets(r,""),Q=t.ParseArg(r),T=e.split(/,/),n,i,a,o=0,u=T.length;o<u;o++){var l=T[o].trim(),c=l.match(/^(\.\d+|\d+(\.\d*)?)(pt|em|ex|mu|px|in|cm|mm)$/);if(c){if(n)throw new $9.default("MultipleBBoxProperty","%1 specified twice in %2","Padding",r);var f=xA(c[1]+c[3]);f&&(n={height:"+"+f,depth:"+"+f,lspace:f,width:"+"+2*parseInt(c[1],10)+c[3]})}else if(l.match(/^([a-z0-9]+|\#[0-9a-f]{6}|\#[0-9a-f]{3})$/i)){if(i)throw new $9.default("MultipleBBoxProperty","%1 specified twice in %2","Background",r);i=l}else if(l.match(/^[-a-z]+:/i)){if(a)throw new $9.default("MultipleBBoxProperty","%1 specified twice in %2","Style",r);a=vA(l)}else if(l!=="")throw new $9.default("InvalidBBoxProperty",`"%1" doesn't look like a color, a padding dimension, or a style`,l)}n&&(Q=t.create("node","mpadded",[Q],n)),(i||a)&&(n={},i&&Object.assign(n,{mathbackground:i}),a&&Object.assign(n,{style:a}),Q=t.create("node","mstyle",[Q],n)),t.Push(Q)};var vA=function(t){return t},xA=function(t){return t};new pA.CommandMap("bbox",{bbox:"BBox"},K3.BboxMethods);K3.BboxConfiguration=LA.Configuration.create("bbox",{handler:{macro:["bbox"]}})});var Ql=p(T2=>{"use strict";var HA=T2&&T2.__values||function(t){var r=typeof Symbol=="function"&&Symbol.iterator,e=r&&t[r],Q=0;if(e)return e.call(t);if(t&&typeof t.length=="number")return{next:function(){return t&&Q>=t.length&&(t=void 0),{value:t&&t[Q++],done:!t}}};throw new TypeError(r?"Object is not iterable.":"Symbol.iterator is not defined.")},yA=T2&&T2.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(T2,"__esModule",{value:!0});T2.BoldsymbolConfiguration=T2.rewriteBoldTokens=T2.createBoldToken=T2.BoldsymbolMethods=void 0;var mA=i1(),p0=yA(H1()),Q3=X1(),gA=T1(),MA=D7(),v4={};v4[Q3.TexConstant.Variant.NORMAL]=Q3.TexConstant.Variant.BOLD;v4[Q3.TexConstant.Variant.ITALIC]=Q3.TexConstant.Variant.BOLDITALIC;v4[Q3.TexConstant.Variant.FRAKTUR]=Q3.TexConstant.Variant.BOLDFRAKTUR;v4[Q3.TexConstant.Variant.SCRIPT]=Q3.TexConstant.Variant.BOLDSCRIPT;v4[Q3.TexConstant.Variant.SANSSERIF]=Q3.TexConstant.Variant.BOLDSANSSERIF;v4["-tex-calligraphic"]="-tex-bold-calligraphic";v4["-tex-oldstyle"]="-tex-bold-oldstyle";v4["-tex-mathit"]=Q3.TexConstant.Variant.BOLDITALIC;T2.BoldsymbolMethods={};T2.BoldsymbolMethods.Boldsymbol=function(t,r){var e=t.stack.env.boldsymbol;t.stack.env.boldsymbol=!0;var Q=t.ParseArg(r);t.stack.env.boldsymbol=e,t.Push(Q)};new gA.CommandMap("boldsymbol",{boldsymbol:"Boldsymbol"},T2.BoldsymbolMethods);function el(t,r,e,Q){var T=MA.NodeFactory.createToken(t,r,e,Q);return r!=="mtext"&&t.configuration.parser.stack.env.boldsymbol&&(p0.default.setProperty(T,"fixBold",!0),t.configuration.addNode("fixBold",T)),T}T2.createBoldToken=el;function rl(t){var r,e;try{for(var Q=HA(t.data.getList("fixBold")),T=Q.next();!T.done;T=Q.next()){var n=T.value;if(p0.default.getProperty(n,"fixBold")){var i=p0.default.getAttribute(n,"mathvariant");i==null?p0.default.setAttribute(n,"mathvariant",Q3.TexConstant.Variant.BOLD):p0.default.setAttribute(n,"mathvariant",v4[i]||i),p0.default.removeProperties(n,"fixBold")}}}catch(a){r={error:a}}finally{try{T&&!T.done&&(e=Q.return)&&e.call(Q)}finally{if(r)throw r.error}}}T2.rewriteBoldTokens=rl;T2.BoldsymbolConfiguration=mA.Configuration.create("boldsymbol",{handler:{macro:["boldsymbol"]},nodes:{token:el},postprocessors:[rl]})});var nl=p(d6=>{"use strict";var VA=d6&&d6.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})(),_A=d6&&d6.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(d6,"__esModule",{value:!0});d6.BraketItem=void 0;var Tl=D6(),VQ=R(),AA=_A(h1()),OA=(function(t){VA(r,t);function r(){return t!==null&&t.apply(this,arguments)||this}return Object.defineProperty(r.prototype,"kind",{get:function(){return"braket"},enumerable:!1,configurable:!0}),Object.defineProperty(r.prototype,"isOpen",{get:function(){return!0},enumerable:!1,configurable:!0}),r.prototype.checkItem=function(e){return e.isKind("close")?[[this.factory.create("mml",this.toMml())],!0]:e.isKind("mml")?(this.Push(e.toMml()),this.getProperty("single")?[[this.toMml()],!0]:Tl.BaseItem.fail):t.prototype.checkItem.call(this,e)},r.prototype.toMml=function(){var e=t.prototype.toMml.call(this),Q=this.getProperty("open"),T=this.getProperty("close");if(this.getProperty("stretchy"))return AA.default.fenced(this.factory.configuration,Q,e,T);var n={fence:!0,stretchy:!1,symmetric:!0,texClass:VQ.TEXCLASS.OPEN},i=this.create("token","mo",n,Q);n.texClass=VQ.TEXCLASS.CLOSE;var a=this.create("token","mo",n,T),o=this.create("node","mrow",[i,e,a],{open:Q,close:T,texClass:VQ.TEXCLASS.INNER});return o},r})(Tl.BaseItem);d6.BraketItem=OA});var al=p(Q7=>{"use strict";var il=Q7&&Q7.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(Q7,"__esModule",{value:!0});var CA=il(M2()),_Q=R(),DA=il(l1()),K9={};K9.Macro=CA.default.Macro;K9.Braket=function(t,r,e,Q,T,n){var i=t.GetNext();if(i==="")throw new DA.default("MissingArgFor","Missing argument for %1",t.currentCS);var a=!0;i==="{"&&(t.i++,a=!1),t.Push(t.itemFactory.create("braket").setProperties({barmax:n,barcount:0,open:e,close:Q,stretchy:T,single:a}))};K9.Bar=function(t,r){var e=r==="|"?"|":"\u2225",Q=t.stack.Top();if(Q.kind!=="braket"||Q.getProperty("barcount")>=Q.getProperty("barmax")){var T=t.create("token","mo",{texClass:_Q.TEXCLASS.ORD,stretchy:!1},e);t.Push(T);return}e==="|"&&t.GetNext()==="|"&&(t.i++,e="\u2225");var n=Q.getProperty("stretchy");if(!n){var i=t.create("token","mo",{stretchy:!1,braketbar:!0},e);t.Push(i);return}var a=t.create("node","TeXAtom",[],{texClass:_Q.TEXCLASS.CLOSE});t.Push(a),Q.setProperty("barcount",Q.getProperty("barcount")+1),a=t.create("token","mo",{stretchy:!0,braketbar:!0},e),t.Push(a),a=t.create("node","TeXAtom",[],{texClass:_Q.TEXCLASS.OPEN}),t.Push(a)};Q7.default=K9});var ul=p(J9=>{"use strict";var EA=J9&&J9.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(J9,"__esModule",{value:!0});var ol=T1(),sl=EA(al());new ol.CommandMap("Braket-macros",{bra:["Macro","{\\langle {#1} \\vert}",1],ket:["Macro","{\\vert {#1} \\rangle}",1],braket:["Braket","\u27E8","\u27E9",!1,1/0],set:["Braket","{","}",!1,1],Bra:["Macro","{\\left\\langle {#1} \\right\\vert}",1],Ket:["Macro","{\\left\\vert {#1} \\right\\rangle}",1],Braket:["Braket","\u27E8","\u27E9",!0,1/0],Set:["Braket","{","}",!0,1],ketbra:["Macro","{\\vert {#1} \\rangle\\langle {#2} \\vert}",2],Ketbra:["Macro","{\\left\\vert {#1} \\right\\rangle\\left\\langle {#2} \\right\\vert}",2],"|":"Bar"},sl.default);new ol.MacroMap("Braket-characters",{"|":"Bar"},sl.default)});var cl=p(Y9=>{"use strict";var AQ;Object.defineProperty(Y9,"__esModule",{value:!0});Y9.BraketConfiguration=void 0;var bA=i1(),ll=nl();ul();Y9.BraketConfiguration=bA.Configuration.create("braket",{handler:{character:["Braket-characters"],macro:["Braket-macros"]},items:(AQ={},AQ[ll.BraketItem.prototype.kind]=ll.BraketItem,AQ)})});var rt=p(z=>{"use strict";var SA=z&&z.__read||function(t,r){var e=typeof Symbol=="function"&&t[Symbol.iterator];if(!e)return t;var Q=e.call(t),T,n=[],i;try{for(;(r===void 0||r-- >0)&&!(T=Q.next()).done;)n.push(T.value)}catch(a){i={error:a}}finally{try{T&&!T.done&&(e=Q.return)&&e.call(Q)}finally{if(i)throw i.error}}return n},wA=z&&z.__values||function(t){var r=typeof Symbol=="function"&&Symbol.iterator,e=r&&t[r],Q=0;if(e)return e.call(t);if(t&&typeof t.length=="number")return{next:function(){return t&&Q>=t.length&&(t=void 0),{value:t&&t[Q++],done:!t}}};throw new TypeError(r?"Object is not iterable.":"Symbol.iterator is not defined.")},dl=z&&z.__importDefault||function(t){return t&&t.__esModule?t:{default:t}},OQ;Object.defineProperty(z,"__esModule",{value:!0});z.clearDocument=z.saveDocument=z.makeBsprAttributes=z.removeProperty=z.getProperty=z.setProperty=z.balanceRules=void 0;var L3=dl(H1()),et=dl(h1()),T7=null,CQ=null,L6=function(t){CQ.root=t;var r=T7.outputJax.getBBox(CQ,T7).w;return r},EQ=function(t){for(var r=0;t&&!L3.default.isType(t,"mtable");){if(L3.default.isType(t,"text"))return null;if(L3.default.isType(t,"mrow")){t=t.childNodes[0],r=0;continue}t=t.parent.childNodes[r],r++}return t},Ll=function(t,r){return t.childNodes[r==="up"?1:0].childNodes[0].childNodes[0].childNodes[0].childNodes[0]},pl=function(t,r){return t.childNodes[r].childNodes[0].childNodes[0]},vl=function(t){return pl(t,0)},BA=function(t){return pl(t,t.childNodes.length-1)},xl=function(t,r){return t.childNodes[r==="up"?0:1].childNodes[0].childNodes[0].childNodes[0]},PA=function(t){for(;t&&!L3.default.isType(t,"mtd");)t=t.parent;return t},jA=function(t){return t.parent.childNodes[t.parent.childNodes.indexOf(t)+1]},Hl=function(t){for(;t&&(0,z.getProperty)(t,"inference")==null;)t=t.parent;return t},DQ=function(t,r,e){e===void 0&&(e=!1);var Q=0;if(t===r)return Q;if(t!==r.parent){var T=t.childNodes,n=e?T.length-1:0;L3.default.isType(T[n],"mspace")&&(Q+=L6(T[n])),t=r.parent}if(t===r)return Q;var i=t.childNodes,a=e?i.length-1:0;return i[a]!==r&&(Q+=L6(i[a])),Q},fl=function(t,r){r===void 0&&(r=!1);var e=EQ(t),Q=xl(e,(0,z.getProperty)(e,"inferenceRule")),T=DQ(t,e,r),n=L6(e),i=L6(Q);return T+(n-i)/2},tt=function(t,r,e,Q){if(Q===void 0&&(Q=!1),(0,z.getProperty)(r,"inferenceRule")||(0,z.getProperty)(r,"labelledRule")){var T=t.nodeFactory.create("node","mrow");r.parent.replaceChild(T,r),T.setChildren([r]),NA(r,T),r=T}var n=Q?r.childNodes.length-1:0,i=r.childNodes[n];if(L3.default.isType(i,"mspace")){L3.default.setAttribute(i,"width",et.default.Em(et.default.dimen2em(L3.default.getAttribute(i,"width"))+e));return}if(i=t.nodeFactory.create("node","mspace",[],{width:et.default.Em(e)}),Q){r.appendChild(i);return}i.parent=r,r.childNodes.unshift(i)},NA=function(t,r){var e=["inference","proof","maxAdjust","labelledRule"];e.forEach(function(Q){var T=(0,z.getProperty)(t,Q);T!=null&&((0,z.setProperty)(r,Q,T),(0,z.removeProperty)(t,Q))})},kA=function(t){var r=t.nodeLists.sequent;if(r)for(var e=r.length-1,Q=void 0;Q=r[e];e--){if((0,z.getProperty)(Q,"sequentProcessed")){(0,z.removeProperty)(Q,"sequentProcessed");continue}var T=[],n=Hl(Q);if((0,z.getProperty)(n,"inference")===1){for(T.push(Q);(0,z.getProperty)(n,"inference")===1;){n=EQ(n);var i=vl(Ll(n,(0,z.getProperty)(n,"inferenceRule"))),a=(0,z.getProperty)(i,"inferenceRule")?xl(i,(0,z.getProperty)(i,"inferenceRule")):i;(0,z.getProperty)(a,"sequent")&&(Q=a.childNodes[0],T.push(Q),(0,z.setProperty)(Q,"sequentProcessed",!0)),n=i}IA(t,T)}}},hl=function(t,r,e,Q,T){var n=t.nodeFactory.create("node","mspace",[],{width:et.default.Em(T)});if(Q==="left"){var i=r.childNodes[e].childNodes[0];n.parent=i,i.childNodes.unshift(n)}else r.childNodes[e].appendChild(n);(0,z.setProperty)(r.parent,"sequentAdjust_"+Q,T)},IA=function(t,r){for(var e=r.pop();r.length;){var Q=r.pop(),T=SA(FA(e,Q),2),n=T[0],i=T[1];(0,z.getProperty)(e.parent,"axiom")&&(hl(t,n<0?e:Q,0,"left",Math.abs(n)),hl(t,i<0?e:Q,2,"right",Math.abs(i))),e=Q}},FA=function(t,r){var e=L6(t.childNodes[2]),Q=L6(r.childNodes[2]),T=L6(t.childNodes[0]),n=L6(r.childNodes[0]),i=T-n,a=e-Q;return[i,a]},RA=function(t){var r,e;CQ=new t.document.options.MathItem("",null,t.math.display);var Q=t.data;kA(Q);var T=Q.nodeLists.inference||[];try{for(var n=wA(T),i=n.next();!i.done;i=n.next()){var a=i.value,o=(0,z.getProperty)(a,"proof"),u=EQ(a),l=Ll(u,(0,z.getProperty)(u,"inferenceRule")),c=vl(l);if((0,z.getProperty)(c,"inference")){var f=fl(c);if(f){tt(Q,c,-f);var h=DQ(a,u,!1);tt(Q,a,f-h)}}var d=BA(l);if((0,z.getProperty)(d,"inference")!=null){var L=fl(d,!0);tt(Q,d,-L,!0);var v=DQ(a,u,!0),x=(0,z.getProperty)(a,"maxAdjust");x!=null&&(L=Math.max(L,x));var H=void 0;if(o||!(H=PA(a))){tt(Q,(0,z.getProperty)(a,"proof")?a:a.parent,L-v,!0);continue}var M=jA(H);if(M){var _=Q.nodeFactory.create("node","mspace",[],{width:L-v+"em"});M.appendChild(_),a.removeProperty("maxAdjust");continue}var b=Hl(H);b&&(L=(0,z.getProperty)(b,"maxAdjust")?Math.max((0,z.getProperty)(b,"maxAdjust"),L):L,(0,z.setProperty)(b,"maxAdjust",L))}}}catch(W){r={error:W}}finally{try{i&&!i.done&&(e=n.return)&&e.call(n)}finally{if(r)throw r.error}}};z.balanceRules=RA;var n7="bspr_",qA=(OQ={},OQ[n7+"maxAdjust"]=!0,OQ),ZA=function(t,r,e){L3.default.setProperty(t,n7+r,e)};z.setProperty=ZA;var GA=function(t,r){return L3.default.getProperty(t,n7+r)};z.getProperty=GA;var WA=function(t,r){t.removeProperty(n7+r)};z.removeProperty=WA;var XA=function(t){t.data.root.walkTree(function(r,e){var Q=[];r.getPropertyNames().forEach(function(T){!qA[T]&&T.match(RegExp("^"+n7))&&Q.push(T+":"+r.getProperty(T))}),Q.length&&L3.default.setAttribute(r,"semantics",Q.join(";"))})};z.makeBsprAttributes=XA;var zA=function(t){if(T7=t.document,!("getBBox"in T7.outputJax))throw Error("The bussproofs extension requires an output jax with a getBBox() method")};z.saveDocument=zA;var UA=function(t){T7=null};z.clearDocument=UA});var gl=p(L2=>{"use strict";var $A=L2&&L2.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})(),KA=L2&&L2.__createBinding||(Object.create?(function(t,r,e,Q){Q===void 0&&(Q=e);var T=Object.getOwnPropertyDescriptor(r,e);(!T||("get"in T?!r.__esModule:T.writable||T.configurable))&&(T={enumerable:!0,get:function(){return r[e]}}),Object.defineProperty(t,Q,T)}):(function(t,r,e,Q){Q===void 0&&(Q=e),t[Q]=r[e]})),JA=L2&&L2.__setModuleDefault||(Object.create?(function(t,r){Object.defineProperty(t,"default",{enumerable:!0,value:r})}):function(t,r){t.default=r}),YA=L2&&L2.__importStar||function(t){if(t&&t.__esModule)return t;var r={};if(t!=null)for(var e in t)e!=="default"&&Object.prototype.hasOwnProperty.call(t,e)&&KA(r,t,e);return JA(r,t),r},ml=L2&&L2.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(L2,"__esModule",{value:!0});L2.ProofTreeItem=void 0;var tO=ml(l1()),yl=D6(),eO=ml(zt()),rO=YA(rt()),QO=(function(t){$A(r,t);function r(){var e=t!==null&&t.apply(this,arguments)||this;return e.leftLabel=null,e.rigthLabel=null,e.innerStack=new eO.default(e.factory,{},!0),e}return Object.defineProperty(r.prototype,"kind",{get:function(){return"proofTree"},enumerable:!1,configurable:!0}),r.prototype.checkItem=function(e){if(e.isKind("end")&&e.getName()==="prooftree"){var Q=this.toMml();return rO.setProperty(Q,"proof",!0),[[this.factory.create("mml",Q),e],!0]}if(e.isKind("stop"))throw new tO.default("EnvMissingEnd","Missing \\end{%1}",this.getName());return this.innerStack.Push(e),yl.BaseItem.fail},r.prototype.toMml=function(){var e=t.prototype.toMml.call(this),Q=this.innerStack.Top();if(Q.isKind("start")&&!Q.Size())return e;this.innerStack.Push(this.factory.create("stop"));var T=this.innerStack.Top().toMml();return this.create("node","mrow",[T,e],{})},r})(yl.BaseItem);L2.ProofTreeItem=QO});var Cl=p(s2=>{"use strict";var TO=s2&&s2.__createBinding||(Object.create?(function(t,r,e,Q){Q===void 0&&(Q=e);var T=Object.getOwnPropertyDescriptor(r,e);(!T||("get"in T?!r.__esModule:T.writable||T.configurable))&&(T={enumerable:!0,get:function(){return r[e]}}),Object.defineProperty(t,Q,T)}):(function(t,r,e,Q){Q===void 0&&(Q=e),t[Q]=r[e]})),nO=s2&&s2.__setModuleDefault||(Object.create?(function(t,r){Object.defineProperty(t,"default",{enumerable:!0,value:r})}):function(t,r){t.default=r}),iO=s2&&s2.__importStar||function(t){if(t&&t.__esModule)return t;var r={};if(t!=null)for(var e in t)e!=="default"&&Object.prototype.hasOwnProperty.call(t,e)&&TO(r,t,e);return nO(r,t),r},Vl=s2&&s2.__read||function(t,r){var e=typeof Symbol=="function"&&t[Symbol.iterator];if(!e)return t;var Q=e.call(t),T,n=[],i;try{for(;(r===void 0||r-- >0)&&!(T=Q.next()).done;)n.push(T.value)}catch(a){i={error:a}}finally{try{T&&!T.done&&(e=Q.return)&&e.call(Q)}finally{if(i)throw i.error}}return n},Ml=s2&&s2.__spreadArray||function(t,r,e){if(e||arguments.length===2)for(var Q=0,T=r.length,n;Q<T;Q++)(n||!(Q in r))&&(n||(n=Array.prototype.slice.call(r,0,Q)),n[Q]=r[Q]);return t.concat(n||Array.prototype.slice.call(r))},wQ=s2&&s2.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(s2,"__esModule",{value:!0});var p3=wQ(l1()),bQ=wQ(W1()),SQ=wQ(h1()),x4=iO(rt()),J3={};J3.Prooftree=function(t,r){t.Push(r);var e=t.itemFactory.create("proofTree").setProperties({name:r.getName(),line:"solid",currentLine:"solid",rootAtTop:!1});return e};J3.Axiom=function(t,r){var e=t.stack.Top();if(e.kind!=="proofTree")throw new p3.default("IllegalProofCommand","Proof commands only allowed in prooftree environment.");var Q=_l(t,t.GetArgument(r));x4.setProperty(Q,"axiom",!0),e.Push(Q)};var _l=function(t,r){var e=SQ.default.internalMath(t,SQ.default.trimSpaces(r),0);if(!e[0].childNodes[0].childNodes.length)return t.create("node","mrow",[]);var Q=t.create("node","mspace",[],{width:".5ex"}),T=t.create("node","mspace",[],{width:".5ex"});return t.create("node","mrow",Ml(Ml([Q],Vl(e),!1),[T],!1))};J3.Inference=function(t,r,e){var Q=t.stack.Top();if(Q.kind!=="proofTree")throw new p3.default("IllegalProofCommand","Proof commands only allowed in prooftree environment.");if(Q.Size()<e)throw new p3.default("BadProofTree","Proof tree badly specified.");var T=Q.getProperty("rootAtTop"),n=e===1&&!Q.Peek()[0].childNodes.length?0:e,i=[];do i.length&&i.unshift(t.create("node","mtd",[],{})),i.unshift(t.create("node","mtd",[Q.Pop()],{rowalign:T?"top":"bottom"})),e--;while(e>0);var a=t.create("node","mtr",i,{}),o=t.create("node","mtable",[a],{framespacing:"0 0"}),u=_l(t,t.GetArgument(r)),l=Q.getProperty("currentLine");l!==Q.getProperty("line")&&Q.setProperty("currentLine",Q.getProperty("line"));var c=Al(t,o,[u],Q.getProperty("left"),Q.getProperty("right"),l,T);Q.setProperty("left",null),Q.setProperty("right",null),x4.setProperty(c,"inference",n),t.configuration.addNode("inference",c),Q.Push(c)};function Al(t,r,e,Q,T,n,i){var a=t.create("node","mtr",[t.create("node","mtd",[r],{})],{}),o=t.create("node","mtr",[t.create("node","mtd",e,{})],{}),u=t.create("node","mtable",i?[o,a]:[a,o],{align:"top 2",rowlines:n,framespacing:"0 0"});x4.setProperty(u,"inferenceRule",i?"up":"down");var l,c;Q&&(l=t.create("node","mpadded",[Q],{height:"+.5em",width:"+.5em",voffset:"-.15em"}),x4.setProperty(l,"prooflabel","left")),T&&(c=t.create("node","mpadded",[T],{height:"+.5em",width:"+.5em",voffset:"-.15em"}),x4.setProperty(c,"prooflabel","right"));var f,h;if(Q&&T)f=[l,u,c],h="both";else if(Q)f=[l,u],h="left";else if(T)f=[u,c],h="right";else return u;return u=t.create("node","mrow",f),x4.setProperty(u,"labelledRule",h),u}J3.Label=function(t,r,e){var Q=t.stack.Top();if(Q.kind!=="proofTree")throw new p3.default("IllegalProofCommand","Proof commands only allowed in prooftree environment.");var T=SQ.default.internalMath(t,t.GetArgument(r),0),n=T.length>1?t.create("node","mrow",T,{}):T[0];Q.setProperty(e,n)};J3.SetLine=function(t,r,e,Q){var T=t.stack.Top();if(T.kind!=="proofTree")throw new p3.default("IllegalProofCommand","Proof commands only allowed in prooftree environment.");T.setProperty("currentLine",e),Q&&T.setProperty("line",e)};J3.RootAtTop=function(t,r,e){var Q=t.stack.Top();if(Q.kind!=="proofTree")throw new p3.default("IllegalProofCommand","Proof commands only allowed in prooftree environment.");Q.setProperty("rootAtTop",e)};J3.AxiomF=function(t,r){var e=t.stack.Top();if(e.kind!=="proofTree")throw new p3.default("IllegalProofCommand","Proof commands only allowed in prooftree environment.");var Q=Ol(t,r);x4.setProperty(Q,"axiom",!0),e.Push(Q)};function Ol(t,r){var e=t.GetNext();if(e!=="$")throw new p3.default("IllegalUseOfCommand","Use of %1 does not match it's definition.",r);t.i++;var Q=t.GetUpTo(r,"$");if(Q.indexOf("\\fCenter")===-1)throw new p3.default("IllegalUseOfCommand","Missing \\fCenter in %1.",r);var T=Vl(Q.split("\\fCenter"),2),n=T[0],i=T[1],a=new bQ.default(n,t.stack.env,t.configuration).mml(),o=new bQ.default(i,t.stack.env,t.configuration).mml(),u=new bQ.default("\\fCenter",t.stack.env,t.configuration).mml(),l=t.create("node","mtd",[a],{}),c=t.create("node","mtd",[u],{}),f=t.create("node","mtd",[o],{}),h=t.create("node","mtr",[l,c,f],{}),d=t.create("node","mtable",[h],{columnspacing:".5ex",columnalign:"center 2"});return x4.setProperty(d,"sequent",!0),t.configuration.addNode("sequent",h),d}J3.FCenter=function(t,r){};J3.InferenceF=function(t,r,e){var Q=t.stack.Top();if(Q.kind!=="proofTree")throw new p3.default("IllegalProofCommand","Proof commands only allowed in prooftree environment.");if(Q.Size()<e)throw new p3.default("BadProofTree","Proof tree badly specified.");var T=Q.getProperty("rootAtTop"),n=e===1&&!Q.Peek()[0].childNodes.length?0:e,i=[];do i.length&&i.unshift(t.create("node","mtd",[],{})),i.unshift(t.create("node","mtd",[Q.Pop()],{rowalign:T?"top":"bottom"})),e--;while(e>0);var a=t.create("node","mtr",i,{}),o=t.create("node","mtable",[a],{framespacing:"0 0"}),u=Ol(t,r),l=Q.getProperty("currentLine");l!==Q.getProperty("line")&&Q.setProperty("currentLine",Q.getProperty("line"));var c=Al(t,o,[u],Q.getProperty("left"),Q.getProperty("right"),l,T);Q.setProperty("left",null),Q.setProperty("right",null),x4.setProperty(c,"inference",n),t.configuration.addNode("inference",c),Q.Push(c)};s2.default=J3});var Sl=p(Qt=>{"use strict";var Dl=Qt&&Qt.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(Qt,"__esModule",{value:!0});var El=Dl(Cl()),aO=Dl(O3()),bl=T1();new bl.CommandMap("Bussproofs-macros",{AxiomC:"Axiom",UnaryInfC:["Inference",1],BinaryInfC:["Inference",2],TrinaryInfC:["Inference",3],QuaternaryInfC:["Inference",4],QuinaryInfC:["Inference",5],RightLabel:["Label","right"],LeftLabel:["Label","left"],AXC:"Axiom",UIC:["Inference",1],BIC:["Inference",2],TIC:["Inference",3],RL:["Label","right"],LL:["Label","left"],noLine:["SetLine","none",!1],singleLine:["SetLine","solid",!1],solidLine:["SetLine","solid",!1],dashedLine:["SetLine","dashed",!1],alwaysNoLine:["SetLine","none",!0],alwaysSingleLine:["SetLine","solid",!0],alwaysSolidLine:["SetLine","solid",!0],alwaysDashedLine:["SetLine","dashed",!0],rootAtTop:["RootAtTop",!0],alwaysRootAtTop:["RootAtTop",!0],rootAtBottom:["RootAtTop",!1],alwaysRootAtBottom:["RootAtTop",!1],fCenter:"FCenter",Axiom:"AxiomF",UnaryInf:["InferenceF",1],BinaryInf:["InferenceF",2],TrinaryInf:["InferenceF",3],QuaternaryInf:["InferenceF",4],QuinaryInf:["InferenceF",5]},El.default);new bl.EnvironmentMap("Bussproofs-environments",aO.default.environment,{prooftree:["Prooftree",null,!1]},El.default)});var Bl=p(nt=>{"use strict";var BQ;Object.defineProperty(nt,"__esModule",{value:!0});nt.BussproofsConfiguration=void 0;var oO=i1(),wl=gl(),Tt=rt();Sl();nt.BussproofsConfiguration=oO.Configuration.create("bussproofs",{handler:{macro:["Bussproofs-macros"],environment:["Bussproofs-environments"]},items:(BQ={},BQ[wl.ProofTreeItem.prototype.kind]=wl.ProofTreeItem,BQ),preprocessors:[[Tt.saveDocument,1]],postprocessors:[[Tt.clearDocument,3],[Tt.makeBsprAttributes,2],[Tt.balanceRules,1]]})});var PQ=p(_2=>{"use strict";var sO=_2&&_2.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(_2,"__esModule",{value:!0});_2.EncloseConfiguration=_2.EncloseMethods=_2.ENCLOSE_OPTIONS=void 0;var uO=i1(),lO=T1(),cO=sO(h1());_2.ENCLOSE_OPTIONS={"data-arrowhead":1,color:1,mathcolor:1,background:1,mathbackground:1,"data-padding":1,"data-thickness":1};_2.EncloseMethods={};_2.EncloseMethods.Enclose=function(t,r){var e=t.GetArgument(r).replace(/,/g," "),Q=t.GetBrackets(r,""),T=t.ParseArg(r),n=cO.default.keyvalOptions(Q,_2.ENCLOSE_OPTIONS);n.notation=e,t.Push(t.create("node","menclose",[T],n))};new lO.CommandMap("enclose",{enclose:"Enclose"},_2.EncloseMethods);_2.EncloseConfiguration=uO.Configuration.create("enclose",{handler:{macro:["enclose"]}})});var Nl=p(v3=>{"use strict";var fO=v3&&v3.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(v3,"__esModule",{value:!0});v3.CancelConfiguration=v3.CancelMethods=void 0;var hO=i1(),F6=X1(),dO=T1(),Pl=fO(h1()),jl=PQ();v3.CancelMethods={};v3.CancelMethods.Cancel=function(t,r,e){var Q=t.GetBrackets(r,""),T=t.ParseArg(r),n=Pl.default.keyvalOptions(Q,jl.ENCLOSE_OPTIONS);n.notation=e,t.Push(t.create("node","menclose",[T],n))};v3.CancelMethods.CancelTo=function(t,r){var e=t.GetBrackets(r,""),Q=t.ParseArg(r),T=t.ParseArg(r),n=Pl.default.keyvalOptions(e,jl.ENCLOSE_OPTIONS);n.notation=[F6.TexConstant.Notation.UPDIAGONALSTRIKE,F6.TexConstant.Notation.UPDIAGONALARROW,F6.TexConstant.Notation.NORTHEASTARROW].join(" "),Q=t.create("node","mpadded",[Q],{depth:"-.1em",height:"+.1em",voffset:".1em"}),t.Push(t.create("node","msup",[t.create("node","menclose",[T],n),Q]))};new dO.CommandMap("cancel",{cancel:["Cancel",F6.TexConstant.Notation.UPDIAGONALSTRIKE],bcancel:["Cancel",F6.TexConstant.Notation.DOWNDIAGONALSTRIKE],xcancel:["Cancel",F6.TexConstant.Notation.UPDIAGONALSTRIKE+" "+F6.TexConstant.Notation.DOWNDIAGONALSTRIKE],cancelto:"CancelTo"},v3.CancelMethods);v3.CancelConfiguration=hO.Configuration.create("cancel",{handler:{macro:["cancel"]}})});var NQ=p(p2=>{"use strict";var LO=p2&&p2.__read||function(t,r){var e=typeof Symbol=="function"&&t[Symbol.iterator];if(!e)return t;var Q=e.call(t),T,n=[],i;try{for(;(r===void 0||r-- >0)&&!(T=Q.next()).done;)n.push(T.value)}catch(a){i={error:a}}finally{try{T&&!T.done&&(e=Q.return)&&e.call(Q)}finally{if(i)throw i.error}}return n},pO=p2&&p2.__spreadArray||function(t,r,e){if(e||arguments.length===2)for(var Q=0,T=r.length,n;Q<T;Q++)(n||!(Q in r))&&(n||(n=Array.prototype.slice.call(r,0,Q)),n[Q]=r[Q]);return t.concat(n||Array.prototype.slice.call(r))},jQ=p2&&p2.__values||function(t){var r=typeof Symbol=="function"&&Symbol.iterator,e=r&&t[r],Q=0;if(e)return e.call(t);if(t&&typeof t.length=="number")return{next:function(){return t&&Q>=t.length&&(t=void 0),{value:t&&t[Q++],done:!t}}};throw new TypeError(r?"Object is not iterable.":"Symbol.iterator is not defined.")},kl=p2&&p2.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(p2,"__esModule",{value:!0});p2.EmpheqUtil=void 0;var it=kl(h1()),vO=kl(W1());p2.EmpheqUtil={environment:function(t,r,e,Q){var T=Q[0],n=t.itemFactory.create(T+"-begin").setProperties({name:r,end:T});t.Push(e.apply(void 0,pO([t,n],LO(Q.slice(1)),!1)))},splitOptions:function(t,r){return r===void 0&&(r=null),it.default.keyvalOptions(t,r,!0)},columnCount:function(t){var r,e,Q=0;try{for(var T=jQ(t.childNodes),n=T.next();!n.done;n=T.next()){var i=n.value,a=i.childNodes.length-(i.isKind("mlabeledtr")?1:0);a>Q&&(Q=a)}}catch(o){r={error:o}}finally{try{n&&!n.done&&(e=T.return)&&e.call(T)}finally{if(r)throw r.error}}return Q},cellBlock:function(t,r,e,Q){var T,n,i=e.create("node","mpadded",[],{height:0,depth:0,voffset:"-1height"}),a=new vO.default(t,e.stack.env,e.configuration),o=a.mml();Q&&a.configuration.tags.label&&(a.configuration.tags.currentTag.env=Q,a.configuration.tags.getTag(!0));try{for(var u=jQ(o.isInferred?o.childNodes:[o]),l=u.next();!l.done;l=u.next()){var c=l.value;i.appendChild(c)}}catch(f){T={error:f}}finally{try{l&&!l.done&&(n=u.return)&&n.call(u)}finally{if(T)throw T.error}}return i.appendChild(e.create("node","mphantom",[e.create("node","mpadded",[r],{width:0})])),i},topRowTable:function(t,r){var e=it.default.copyNode(t,r);return e.setChildren(e.childNodes.slice(0,1)),e.attributes.set("align","baseline 1"),t.factory.create("mphantom",{},[r.create("node","mpadded",[e],{width:0})])},rowspanCell:function(t,r,e,Q,T){t.appendChild(Q.create("node","mpadded",[this.cellBlock(r,it.default.copyNode(e,Q),Q,T),this.topRowTable(e,Q)],{height:0,depth:0,voffset:"height"}))},left:function(t,r,e,Q,T){var n,i;T===void 0&&(T=""),t.attributes.set("columnalign","right "+(t.attributes.get("columnalign")||"")),t.attributes.set("columnspacing","0em "+(t.attributes.get("columnspacing")||""));var a;try{for(var o=jQ(t.childNodes.slice(0).reverse()),u=o.next();!u.done;u=o.next()){var l=u.value;a=Q.create("node","mtd"),l.childNodes.unshift(a),a.parent=l,l.isKind("mlabeledtr")&&(l.childNodes[0]=l.childNodes[1],l.childNodes[1]=a)}}catch(c){n={error:c}}finally{try{u&&!u.done&&(i=o.return)&&i.call(o)}finally{if(n)throw n.error}}this.rowspanCell(a,e,r,Q,T)},right:function(t,r,e,Q,T){T===void 0&&(T=""),t.childNodes.length===0&&t.appendChild(Q.create("node","mtr"));for(var n=p2.EmpheqUtil.columnCount(t),i=t.childNodes[0];i.childNodes.length<n;)i.appendChild(Q.create("node","mtd"));var a=i.appendChild(Q.create("node","mtd"));p2.EmpheqUtil.rowspanCell(a,e,r,Q,T),t.attributes.set("columnalign",(t.attributes.get("columnalign")||"").split(/ /).slice(0,n).join(" ")+" left"),t.attributes.set("columnspacing",(t.attributes.get("columnspacing")||"").split(/ /).slice(0,n-1).join(" ")+" 0em")},adjustTable:function(t,r){var e=t.getProperty("left"),Q=t.getProperty("right");if(e||Q){var T=t.Last,n=it.default.copyNode(T,r);e&&this.left(T,n,e,r),Q&&this.right(T,n,Q,r)}},allowEnv:{equation:!0,align:!0,gather:!0,flalign:!0,alignat:!0,multline:!0},checkEnv:function(t){return this.allowEnv.hasOwnProperty(t.replace(/\*$/,""))||!1}}});var Wl=p(n2=>{"use strict";var Rl=n2&&n2.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})(),FQ=n2&&n2.__importDefault||function(t){return t&&t.__esModule?t:{default:t}},kQ;Object.defineProperty(n2,"__esModule",{value:!0});n2.CasesConfiguration=n2.CasesMethods=n2.CasesTags=n2.CasesBeginItem=void 0;var xO=i1(),ql=T1(),Il=FQ(h1()),Fl=FQ(M2()),HO=FQ(l1()),yO=_3(),mO=mQ(),Zl=NQ(),IQ=(function(t){Rl(r,t);function r(){return t!==null&&t.apply(this,arguments)||this}return Object.defineProperty(r.prototype,"kind",{get:function(){return"cases-begin"},enumerable:!1,configurable:!0}),r.prototype.checkItem=function(e){return e.isKind("end")&&e.getName()===this.getName()&&this.getProperty("end")?(this.setProperty("end",!1),[[],!0]):t.prototype.checkItem.call(this,e)},r})(yO.BeginItem);n2.CasesBeginItem=IQ;var Gl=(function(t){Rl(r,t);function r(){var e=t!==null&&t.apply(this,arguments)||this;return e.subcounter=0,e}return r.prototype.start=function(e,Q,T){this.subcounter=0,t.prototype.start.call(this,e,Q,T)},r.prototype.autoTag=function(){this.currentTag.tag==null&&(this.currentTag.env==="subnumcases"?(this.subcounter===0&&this.counter++,this.subcounter++,this.tag(this.formatNumber(this.counter,this.subcounter),!1)):((this.subcounter===0||this.currentTag.env!=="numcases-left")&&this.counter++,this.tag(this.formatNumber(this.counter),!1)))},r.prototype.formatNumber=function(e,Q){return Q===void 0&&(Q=null),e.toString()+(Q===null?"":String.fromCharCode(96+Q))},r})(mO.AmsTags);n2.CasesTags=Gl;n2.CasesMethods={NumCases:function(t,r){if(t.stack.env.closing===r.getName()){delete t.stack.env.closing,t.Push(t.itemFactory.create("end").setProperty("name",r.getName()));var e=t.stack.Top(),Q=e.Last,T=Il.default.copyNode(Q,t),n=e.getProperty("left");return Zl.EmpheqUtil.left(Q,T,n+"\\empheqlbrace\\,",t,"numcases-left"),t.Push(t.itemFactory.create("end").setProperty("name",r.getName())),null}else{var n=t.GetArgument("\\begin{"+r.getName()+"}");r.setProperty("left",n);var i=Fl.default.EqnArray(t,r,!0,!0,"ll");return i.arraydef.displaystyle=!1,i.arraydef.rowspacing=".2em",i.setProperty("numCases",!0),t.Push(r),i}},Entry:function(t,r){if(!t.stack.Top().getProperty("numCases"))return Fl.default.Entry(t,r);t.Push(t.itemFactory.create("cell").setProperties({isEntry:!0,name:r}));for(var e=t.string,Q=0,T=t.i,n=e.length;T<n;){var i=e.charAt(T);if(i==="{")Q++,T++;else if(i==="}"){if(Q===0)break;Q--,T++}else{if(i==="&"&&Q===0)throw new HO.default("ExtraCasesAlignTab","Extra alignment tab in text for numcase environment");if(i==="\\"&&Q===0){var a=(e.slice(T+1).match(/^[a-z]+|./i)||[])[0];if(a==="\\"||a==="cr"||a==="end"||a==="label")break;T+=a.length}else T++}}var o=e.substr(t.i,T-t.i).replace(/^\s*/,"");t.PushAll(Il.default.internalMath(t,o,0)),t.i=T}};new ql.EnvironmentMap("cases-env",Zl.EmpheqUtil.environment,{numcases:["NumCases","cases"],subnumcases:["NumCases","cases"]},n2.CasesMethods);new ql.MacroMap("cases-macros",{"&":"Entry"},n2.CasesMethods);n2.CasesConfiguration=xO.Configuration.create("cases",{handler:{environment:["cases-env"],character:["cases-macros"]},items:(kQ={},kQ[IQ.prototype.kind]=IQ,kQ),tags:{cases:Gl}})});var $l=p(Y3=>{"use strict";var gO=Y3&&Y3.__values||function(t){var r=typeof Symbol=="function"&&Symbol.iterator,e=r&&t[r],Q=0;if(e)return e.call(t);if(t&&typeof t.length=="number")return{next:function(){return t&&Q>=t.length&&(t=void 0),{value:t&&t[Q++],done:!t}}};throw new TypeError(r?"Object is not iterable.":"Symbol.iterator is not defined.")},RQ=Y3&&Y3.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(Y3,"__esModule",{value:!0});Y3.CenternotConfiguration=Y3.filterCenterOver=void 0;var MO=i1(),Xl=RQ(W1()),zl=RQ(H1()),VO=T1(),_O=RQ(M2());new VO.CommandMap("centernot",{centerOver:"CenterOver",centernot:["Macro","\\centerOver{#1}{{\u29F8}}",1]},{CenterOver:function(t,r){var e="{"+t.GetArgument(r)+"}",Q=t.ParseArg(r),T=new Xl.default(e,t.stack.env,t.configuration).mml(),n=t.create("node","TeXAtom",[new Xl.default(e,t.stack.env,t.configuration).mml(),t.create("node","mpadded",[t.create("node","mpadded",[Q],{width:0,lspace:"-.5width"}),t.create("node","mphantom",[T])],{width:0,lspace:"-.5width"})]);t.configuration.addNode("centerOver",T),t.Push(n)},Macro:_O.default.Macro});function Ul(t){var r,e,Q=t.data;try{for(var T=gO(Q.getList("centerOver")),n=T.next();!n.done;n=T.next()){var i=n.value,a=zl.default.getTexClass(i.childNodes[0].childNodes[0]);a!==null&&zl.default.setProperties(i.parent.parent.parent.parent.parent.parent,{texClass:a})}}catch(o){r={error:o}}finally{try{n&&!n.done&&(e=T.return)&&e.call(T)}finally{if(r)throw r.error}}}Y3.filterCenterOver=Ul;Y3.CenternotConfiguration=MO.Configuration.create("centernot",{handler:{macro:["centernot"]},postprocessors:[Ul]})});var ec=p(x3=>{"use strict";var Kl=x3&&x3.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(x3,"__esModule",{value:!0});x3.ColorMethods=void 0;var Jl=Kl(H1()),Yl=Kl(h1());function tc(t){var r="+".concat(t),e=t.replace(/^.*?([a-z]*)$/,"$1"),Q=2*parseFloat(r);return{width:"+".concat(Q).concat(e),height:r,depth:r,lspace:t}}x3.ColorMethods={};x3.ColorMethods.Color=function(t,r){var e=t.GetBrackets(r,""),Q=t.GetArgument(r),T=t.configuration.packageData.get("color").model,n=T.getColor(e,Q),i=t.itemFactory.create("style").setProperties({styles:{mathcolor:n}});t.stack.env.color=n,t.Push(i)};x3.ColorMethods.TextColor=function(t,r){var e=t.GetBrackets(r,""),Q=t.GetArgument(r),T=t.configuration.packageData.get("color").model,n=T.getColor(e,Q),i=t.stack.env.color;t.stack.env.color=n;var a=t.ParseArg(r);i?t.stack.env.color=i:delete t.stack.env.color;var o=t.create("node","mstyle",[a],{mathcolor:n});t.Push(o)};x3.ColorMethods.DefineColor=function(t,r){var e=t.GetArgument(r),Q=t.GetArgument(r),T=t.GetArgument(r),n=t.configuration.packageData.get("color").model;n.defineColor(Q,e,T)};x3.ColorMethods.ColorBox=function(t,r){var e=t.GetArgument(r),Q=Yl.default.internalMath(t,t.GetArgument(r)),T=t.configuration.packageData.get("color").model,n=t.create("node","mpadded",Q,{mathbackground:T.getColor("named",e)});Jl.default.setProperties(n,tc(t.options.color.padding)),t.Push(n)};x3.ColorMethods.FColorBox=function(t,r){var e=t.GetArgument(r),Q=t.GetArgument(r),T=Yl.default.internalMath(t,t.GetArgument(r)),n=t.options.color,i=t.configuration.packageData.get("color").model,a=t.create("node","mpadded",T,{mathbackground:i.getColor("named",Q),style:"border: ".concat(n.borderWidth," solid ").concat(i.getColor("named",e))});Jl.default.setProperties(a,tc(n.padding)),t.Push(a)}});var rc=p(at=>{"use strict";Object.defineProperty(at,"__esModule",{value:!0});at.COLORS=void 0;at.COLORS=new Map([["Apricot","#FBB982"],["Aquamarine","#00B5BE"],["Bittersweet","#C04F17"],["Black","#221E1F"],["Blue","#2D2F92"],["BlueGreen","#00B3B8"],["BlueViolet","#473992"],["BrickRed","#B6321C"],["Brown","#792500"],["BurntOrange","#F7921D"],["CadetBlue","#74729A"],["CarnationPink","#F282B4"],["Cerulean","#00A2E3"],["CornflowerBlue","#41B0E4"],["Cyan","#00AEEF"],["Dandelion","#FDBC42"],["DarkOrchid","#A4538A"],["Emerald","#00A99D"],["ForestGreen","#009B55"],["Fuchsia","#8C368C"],["Goldenrod","#FFDF42"],["Gray","#949698"],["Green","#00A64F"],["GreenYellow","#DFE674"],["JungleGreen","#00A99A"],["Lavender","#F49EC4"],["LimeGreen","#8DC73E"],["Magenta","#EC008C"],["Mahogany","#A9341F"],["Maroon","#AF3235"],["Melon","#F89E7B"],["MidnightBlue","#006795"],["Mulberry","#A93C93"],["NavyBlue","#006EB8"],["OliveGreen","#3C8031"],["Orange","#F58137"],["OrangeRed","#ED135A"],["Orchid","#AF72B0"],["Peach","#F7965A"],["Periwinkle","#7977B8"],["PineGreen","#008B72"],["Plum","#92268F"],["ProcessBlue","#00B0F0"],["Purple","#99479B"],["RawSienna","#974006"],["Red","#ED1B23"],["RedOrange","#F26035"],["RedViolet","#A1246B"],["Rhodamine","#EF559F"],["RoyalBlue","#0071BC"],["RoyalPurple","#613F99"],["RubineRed","#ED017D"],["Salmon","#F69289"],["SeaGreen","#3FBC9D"],["Sepia","#671800"],["SkyBlue","#46C5DD"],["SpringGreen","#C6DC67"],["Tan","#DA9D76"],["TealBlue","#00AEB3"],["Thistle","#D883B7"],["Turquoise","#00B4CE"],["Violet","#58429B"],["VioletRed","#EF58A0"],["White","#FFFFFF"],["WildStrawberry","#EE2967"],["Yellow","#FFF200"],["YellowGreen","#98CC70"],["YellowOrange","#FAA21A"]])});var nc=p(p6=>{"use strict";var Tc=p6&&p6.__values||function(t){var r=typeof Symbol=="function"&&Symbol.iterator,e=r&&t[r],Q=0;if(e)return e.call(t);if(t&&typeof t.length=="number")return{next:function(){return t&&Q>=t.length&&(t=void 0),{value:t&&t[Q++],done:!t}}};throw new TypeError(r?"Object is not iterable.":"Symbol.iterator is not defined.")},AO=p6&&p6.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(p6,"__esModule",{value:!0});p6.ColorModel=void 0;var H4=AO(l1()),Qc=rc(),i7=new Map,OO=(function(){function t(){this.userColors=new Map}return t.prototype.normalizeColor=function(r,e){if(!r||r==="named")return e;if(i7.has(r)){var Q=i7.get(r);return Q(e)}throw new H4.default("UndefinedColorModel","Color model '%1' not defined",r)},t.prototype.getColor=function(r,e){return!r||r==="named"?this.getColorByName(e):this.normalizeColor(r,e)},t.prototype.getColorByName=function(r){return this.userColors.has(r)?this.userColors.get(r):Qc.COLORS.has(r)?Qc.COLORS.get(r):r},t.prototype.defineColor=function(r,e,Q){var T=this.normalizeColor(r,Q);this.userColors.set(e,T)},t})();p6.ColorModel=OO;i7.set("rgb",function(t){var r,e,Q=t.trim().split(/\s*,\s*/),T="#";if(Q.length!==3)throw new H4.default("ModelArg1","Color values for the %1 model require 3 numbers","rgb");try{for(var n=Tc(Q),i=n.next();!i.done;i=n.next()){var a=i.value;if(!a.match(/^(\d+(\.\d*)?|\.\d+)$/))throw new H4.default("InvalidDecimalNumber","Invalid decimal number");var o=parseFloat(a);if(o<0||o>1)throw new H4.default("ModelArg2","Color values for the %1 model must be between %2 and %3","rgb","0","1");var u=Math.floor(o*255).toString(16);u.length<2&&(u="0"+u),T+=u}}catch(l){r={error:l}}finally{try{i&&!i.done&&(e=n.return)&&e.call(n)}finally{if(r)throw r.error}}return T});i7.set("RGB",function(t){var r,e,Q=t.trim().split(/\s*,\s*/),T="#";if(Q.length!==3)throw new H4.default("ModelArg1","Color values for the %1 model require 3 numbers","RGB");try{for(var n=Tc(Q),i=n.next();!i.done;i=n.next()){var a=i.value;if(!a.match(/^\d+$/))throw new H4.default("InvalidNumber","Invalid number");var o=parseInt(a);if(o>255)throw new H4.default("ModelArg2","Color values for the %1 model must be between %2 and %3","RGB","0","255");var u=o.toString(16);u.length<2&&(u="0"+u),T+=u}}catch(l){r={error:l}}finally{try{i&&!i.done&&(e=n.return)&&e.call(n)}finally{if(r)throw r.error}}return T});i7.set("gray",function(t){if(!t.match(/^\s*(\d+(\.\d*)?|\.\d+)\s*$/))throw new H4.default("InvalidDecimalNumber","Invalid decimal number");var r=parseFloat(t);if(r<0||r>1)throw new H4.default("ModelArg2","Color values for the %1 model must be between %2 and %3","gray","0","1");var e=Math.floor(r*255).toString(16);return e.length<2&&(e="0"+e),"#".concat(e).concat(e).concat(e)})});var ic=p(ot=>{"use strict";Object.defineProperty(ot,"__esModule",{value:!0});ot.ColorConfiguration=void 0;var CO=T1(),DO=i1(),EO=ec(),bO=nc();new CO.CommandMap("color",{color:"Color",textcolor:"TextColor",definecolor:"DefineColor",colorbox:"ColorBox",fcolorbox:"FColorBox"},EO.ColorMethods);var SO=function(t,r){r.parseOptions.packageData.set("color",{model:new bO.ColorModel})};ot.ColorConfiguration=DO.Configuration.create("color",{handler:{macro:["color"]},options:{color:{padding:"5px",borderWidth:"2px"}},config:SO})});var ac=p(R6=>{"use strict";Object.defineProperty(R6,"__esModule",{value:!0});R6.ColorConfiguration=R6.ColorV2Methods=void 0;var wO=T1(),BO=i1();R6.ColorV2Methods={Color:function(t,r){var e=t.GetArgument(r),Q=t.stack.env.color;t.stack.env.color=e;var T=t.ParseArg(r);Q?t.stack.env.color=Q:delete t.stack.env.color;var n=t.create("node","mstyle",[T],{mathcolor:e});t.Push(n)}};new wO.CommandMap("colorv2",{color:"Color"},R6.ColorV2Methods);R6.ColorConfiguration=BO.Configuration.create("colorv2",{handler:{macro:["colorv2"]}})});var sc=p(t4=>{"use strict";var PO=t4&&t4.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})(),jO=t4&&t4.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(t4,"__esModule",{value:!0});t4.ColortblConfiguration=t4.ColorArrayItem=void 0;var NO=_3(),oc=i1(),kO=T1(),qQ=jO(l1()),ZQ=(function(t){PO(r,t);function r(){var e=t!==null&&t.apply(this,arguments)||this;return e.color={cell:"",row:"",col:[]},e.hasColor=!1,e}return r.prototype.EndEntry=function(){t.prototype.EndEntry.call(this);var e=this.row[this.row.length-1],Q=this.color.cell||this.color.row||this.color.col[this.row.length-1];Q&&(e.attributes.set("mathbackground",Q),this.color.cell="",this.hasColor=!0)},r.prototype.EndRow=function(){t.prototype.EndRow.call(this),this.color.row=""},r.prototype.createMml=function(){var e=t.prototype.createMml.call(this),Q=e.isKind("mrow")?e.childNodes[1]:e;return Q.isKind("menclose")&&(Q=Q.childNodes[0].childNodes[0]),this.hasColor&&Q.attributes.get("frame")==="none"&&Q.attributes.set("frame",""),e},r})(NO.ArrayItem);t4.ColorArrayItem=ZQ;new kO.CommandMap("colortbl",{cellcolor:["TableColor","cell"],rowcolor:["TableColor","row"],columncolor:["TableColor","col"]},{TableColor:function(t,r,e){var Q=t.configuration.packageData.get("color").model,T=t.GetBrackets(r,""),n=Q.getColor(T,t.GetArgument(r)),i=t.stack.Top();if(!(i instanceof ZQ))throw new qQ.default("UnsupportedTableColor","Unsupported use of %1",t.currentCS);if(e==="col"){if(i.table.length)throw new qQ.default("ColumnColorNotTop","%1 must be in the top row",r);i.color.col[i.row.length]=n,t.GetBrackets(r,"")&&t.GetBrackets(r,"")}else if(i.color[e]=n,e==="row"&&(i.Size()||i.row.length))throw new qQ.default("RowColorNotFirst","%1 must be at the beginning of a row",r)}});var IO=function(t,r){r.parseOptions.packageData.has("color")||oc.ConfigurationHandler.get("color").config(t,r)};t4.ColortblConfiguration=oc.Configuration.create("colortbl",{handler:{macro:["colortbl"]},items:{array:ZQ},priority:10,config:[IO,10]})});var o7=p(a7=>{"use strict";var uc=a7&&a7.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(a7,"__esModule",{value:!0});var GQ=uc(h1()),q6=uc(l1()),st=b6(),WQ;(function(t){function r(f,h){var d=[f,h.char];if(h.attributes)for(var L in h.attributes)d.push(L),d.push(h.attributes[L]);return d}t.disassembleSymbol=r;function e(f){for(var h=f[0],d=f[1],L={},v=2;v<f.length;v=v+2)L[f[v]]=f[v+1];return new st.Symbol(h,d,L)}t.assembleSymbol=e;function Q(f,h){var d=f.GetNext();if(d!=="\\")throw new q6.default("MissingCS","%1 must be followed by a control sequence",h);var L=GQ.default.trimSpaces(f.GetArgument(h));return L.substr(1)}t.GetCSname=Q;function T(f,h){var d=GQ.default.trimSpaces(f.GetArgument(h));if(d.charAt(0)==="\\"&&(d=d.substr(1)),!d.match(/^(.|[a-z]+)$/i))throw new q6.default("IllegalControlSequenceName","Illegal control sequence name for %1",h);return d}t.GetCsNameArgument=T;function n(f,h){var d=f.GetBrackets(h);if(d&&(d=GQ.default.trimSpaces(d),!d.match(/^[0-9]+$/)))throw new q6.default("IllegalParamNumber","Illegal number of parameters specified in %1",h);return d}t.GetArgCount=n;function i(f,h,d){for(var L=f.GetNext(),v=[],x=0,H=f.i;f.i<f.string.length;){if(L=f.GetNext(),L==="#"){if(H!==f.i&&(v[x]=f.string.substr(H,f.i-H)),L=f.string.charAt(++f.i),!L.match(/^[1-9]$/))throw new q6.default("CantUseHash2","Illegal use of # in template for %1",d);if(parseInt(L)!==++x)throw new q6.default("SequentialParam","Parameters for %1 must be numbered sequentially",d);H=f.i+1}else if(L==="{")return H!==f.i&&(v[x]=f.string.substr(H,f.i-H)),v.length>0?[x.toString()].concat(v):x;f.i++}throw new q6.default("MissingReplacementString","Missing replacement string for definition of %1",h)}t.GetTemplate=i;function a(f,h,d){if(d==null)return f.GetArgument(h);for(var L=f.i,v=0,x=0;f.i<f.string.length;){var H=f.string.charAt(f.i);if(H==="{")f.i===L&&(x=1),f.GetArgument(h),v=f.i-L;else{if(o(f,d))return x&&(L++,v-=2),f.string.substr(L,v);if(H==="\\"){f.i++,v++,x=0;var M=f.string.substr(f.i).match(/[a-z]+|./i);M&&(f.i+=M[0].length,v=f.i-L)}else f.i++,v++,x=0}}throw new q6.default("RunawayArgument","Runaway argument for %1?",h)}t.GetParameter=a;function o(f,h){return f.string.substr(f.i,h.length)!==h||h.match(/\\[a-z]+$/i)&&f.string.charAt(f.i+h.length).match(/[a-z]/i)?0:(f.i+=h.length,1)}t.MatchParam=o;function u(f,h,d,L){var v=f.configuration.handlers,x=v.retrieve(t.NEW_DELIMITER);x.add(h,new st.Symbol(h,d,L))}t.addDelimiter=u;function l(f,h,d,L,v){v===void 0&&(v="");var x=f.configuration.handlers,H=x.retrieve(t.NEW_COMMAND);H.add(h,new st.Macro(v||h,d,L))}t.addMacro=l;function c(f,h,d,L){var v=f.configuration.handlers,x=v.retrieve(t.NEW_ENVIRONMENT);x.add(h,new st.Macro(h,d,L))}t.addEnvironment=c,t.NEW_DELIMITER="new-Delimiter",t.NEW_COMMAND="new-Command",t.NEW_ENVIRONMENT="new-Environment"})(WQ||(WQ={}));a7.default=WQ});var lt=p(H3=>{"use strict";var FO=H3&&H3.__createBinding||(Object.create?(function(t,r,e,Q){Q===void 0&&(Q=e);var T=Object.getOwnPropertyDescriptor(r,e);(!T||("get"in T?!r.__esModule:T.writable||T.configurable))&&(T={enumerable:!0,get:function(){return r[e]}}),Object.defineProperty(t,Q,T)}):(function(t,r,e,Q){Q===void 0&&(Q=e),t[Q]=r[e]})),RO=H3&&H3.__setModuleDefault||(Object.create?(function(t,r){Object.defineProperty(t,"default",{enumerable:!0,value:r})}):function(t,r){t.default=r}),qO=H3&&H3.__importStar||function(t){if(t&&t.__esModule)return t;var r={};if(t!=null)for(var e in t)e!=="default"&&Object.prototype.hasOwnProperty.call(t,e)&&FO(r,t,e);return RO(r,t),r},ut=H3&&H3.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(H3,"__esModule",{value:!0});var ZO=ut(l1()),GO=qO(T1()),WO=ut(M2()),Z6=ut(h1()),w1=ut(o7()),A2={};A2.NewCommand=function(t,r){var e=w1.default.GetCsNameArgument(t,r),Q=w1.default.GetArgCount(t,r),T=t.GetBrackets(r),n=t.GetArgument(r);w1.default.addMacro(t,e,A2.Macro,[n,Q,T])};A2.NewEnvironment=function(t,r){var e=Z6.default.trimSpaces(t.GetArgument(r)),Q=w1.default.GetArgCount(t,r),T=t.GetBrackets(r),n=t.GetArgument(r),i=t.GetArgument(r);w1.default.addEnvironment(t,e,A2.BeginEnv,[!0,n,i,Q,T])};A2.MacroDef=function(t,r){var e=w1.default.GetCSname(t,r),Q=w1.default.GetTemplate(t,r,"\\"+e),T=t.GetArgument(r);Q instanceof Array?w1.default.addMacro(t,e,A2.MacroWithTemplate,[T].concat(Q)):w1.default.addMacro(t,e,A2.Macro,[T,Q])};A2.Let=function(t,r){var e=w1.default.GetCSname(t,r),Q=t.GetNext();Q==="="&&(t.i++,Q=t.GetNext());var T=t.configuration.handlers;if(Q==="\\"){r=w1.default.GetCSname(t,r);var n=T.get("delimiter").lookup("\\"+r);if(n){w1.default.addDelimiter(t,"\\"+e,n.char,n.attributes);return}var i=T.get("macro").applicable(r);if(!i)return;if(i instanceof GO.MacroMap){var a=i.lookup(r);w1.default.addMacro(t,e,a.func,a.args,a.symbol);return}n=i.lookup(r);var o=w1.default.disassembleSymbol(e,n),u=function(c,f){for(var h=[],d=2;d<arguments.length;d++)h[d-2]=arguments[d];var L=w1.default.assembleSymbol(h);return i.parser(c,L)};w1.default.addMacro(t,e,u,o);return}t.i++;var l=T.get("delimiter").lookup(Q);if(l){w1.default.addDelimiter(t,"\\"+e,l.char,l.attributes);return}w1.default.addMacro(t,e,A2.Macro,[Q])};A2.MacroWithTemplate=function(t,r,e,Q){for(var T=[],n=4;n<arguments.length;n++)T[n-4]=arguments[n];var i=parseInt(Q,10);if(i){var a=[];if(t.GetNext(),T[0]&&!w1.default.MatchParam(t,T[0]))throw new ZO.default("MismatchUseDef","Use of %1 doesn't match its definition",r);for(var o=0;o<i;o++)a.push(w1.default.GetParameter(t,r,T[o+1]));e=Z6.default.substituteArgs(t,a,e)}t.string=Z6.default.addArgs(t,e,t.string.slice(t.i)),t.i=0,Z6.default.checkMaxMacros(t)};A2.BeginEnv=function(t,r,e,Q,T,n){if(r.getProperty("end")&&t.stack.env.closing===r.getName()){delete t.stack.env.closing;var i=t.string.slice(t.i);return t.string=Q,t.i=0,t.Parse(),t.string=i,t.i=0,t.itemFactory.create("end").setProperty("name",r.getName())}if(T){var a=[];if(n!=null){var o=t.GetBrackets("\\begin{"+r.getName()+"}");a.push(o??n)}for(var u=a.length;u<T;u++)a.push(t.GetArgument("\\begin{"+r.getName()+"}"));e=Z6.default.substituteArgs(t,a,e),Q=Z6.default.substituteArgs(t,[],Q)}return t.string=Z6.default.addArgs(t,e,t.string.slice(t.i)),t.i=0,t.itemFactory.create("beginEnv").setProperty("name",r.getName())};A2.Macro=WO.default.Macro;H3.default=A2});var XQ=p(v6=>{"use strict";var XO=v6&&v6.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})(),zO=v6&&v6.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(v6,"__esModule",{value:!0});v6.BeginEnvItem=void 0;var lc=zO(l1()),UO=D6(),$O=(function(t){XO(r,t);function r(){return t!==null&&t.apply(this,arguments)||this}return Object.defineProperty(r.prototype,"kind",{get:function(){return"beginEnv"},enumerable:!1,configurable:!0}),Object.defineProperty(r.prototype,"isOpen",{get:function(){return!0},enumerable:!1,configurable:!0}),r.prototype.checkItem=function(e){if(e.isKind("end")){if(e.getName()!==this.getName())throw new lc.default("EnvBadEnd","\\begin{%1} ended with \\end{%2}",this.getName(),e.getName());return[[this.factory.create("mml",this.toMml())],!0]}if(e.isKind("stop"))throw new lc.default("EnvMissingEnd","Missing \\end{%1}",this.getName());return t.prototype.checkItem.call(this,e)},r})(UO.BaseItem);v6.BeginEnvItem=$O});var vc=p(x6=>{"use strict";var dc=x6&&x6.__values||function(t){var r=typeof Symbol=="function"&&Symbol.iterator,e=r&&t[r],Q=0;if(e)return e.call(t);if(t&&typeof t.length=="number")return{next:function(){return t&&Q>=t.length&&(t=void 0),{value:t&&t[Q++],done:!t}}};throw new TypeError(r?"Object is not iterable.":"Symbol.iterator is not defined.")},Lc=x6&&x6.__importDefault||function(t){return t&&t.__esModule?t:{default:t}},zQ;Object.defineProperty(x6,"__esModule",{value:!0});x6.ConfigMacrosConfiguration=void 0;var pc=i1(),cc=B1(),fc=T1(),KO=Lc(O3()),UQ=b6(),$Q=Lc(lt()),hc=XQ(),KQ="configmacros-map",JQ="configmacros-env-map";function JO(t){new fc.CommandMap(KQ,{},{}),new fc.EnvironmentMap(JQ,KO.default.environment,{},{}),t.append(pc.Configuration.local({handler:{macro:[KQ],environment:[JQ]},priority:3}))}function YO(t,r){tC(r),eC(r)}function tC(t){var r,e,Q=t.parseOptions.handlers.retrieve(KQ),T=t.parseOptions.options.macros;try{for(var n=dc(Object.keys(T)),i=n.next();!i.done;i=n.next()){var a=i.value,o=typeof T[a]=="string"?[T[a]]:T[a],u=Array.isArray(o[2])?new UQ.Macro(a,$Q.default.MacroWithTemplate,o.slice(0,2).concat(o[2])):new UQ.Macro(a,$Q.default.Macro,o);Q.add(a,u)}}catch(l){r={error:l}}finally{try{i&&!i.done&&(e=n.return)&&e.call(n)}finally{if(r)throw r.error}}}function eC(t){var r,e,Q=t.parseOptions.handlers.retrieve(JQ),T=t.parseOptions.options.environments;try{for(var n=dc(Object.keys(T)),i=n.next();!i.done;i=n.next()){var a=i.value;Q.add(a,new UQ.Macro(a,$Q.default.BeginEnv,[!0].concat(T[a])))}}catch(o){r={error:o}}finally{try{i&&!i.done&&(e=n.return)&&e.call(n)}finally{if(r)throw r.error}}}x6.ConfigMacrosConfiguration=pc.Configuration.create("configmacros",{init:JO,config:YO,items:(zQ={},zQ[hc.BeginEnvItem.prototype.kind]=hc.BeginEnvItem,zQ),options:{macros:(0,cc.expandable)({}),environments:(0,cc.expandable)({})}})});var yc=p(i2=>{"use strict";var rC=i2&&i2.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})(),QC=i2&&i2.__read||function(t,r){var e=typeof Symbol=="function"&&t[Symbol.iterator];if(!e)return t;var Q=e.call(t),T,n=[],i;try{for(;(r===void 0||r-- >0)&&!(T=Q.next()).done;)n.push(T.value)}catch(a){i={error:a}}finally{try{T&&!T.done&&(e=Q.return)&&e.call(Q)}finally{if(i)throw i.error}}return n},xc=i2&&i2.__importDefault||function(t){return t&&t.__esModule?t:{default:t}},YQ;Object.defineProperty(i2,"__esModule",{value:!0});i2.EmpheqConfiguration=i2.EmpheqMethods=i2.EmpheqBeginItem=void 0;var TC=i1(),Hc=T1(),nC=xc(h1()),iC=xc(l1()),aC=_3(),ct=NQ(),tT=(function(t){rC(r,t);function r(){return t!==null&&t.apply(this,arguments)||this}return Object.defineProperty(r.prototype,"kind",{get:function(){return"empheq-begin"},enumerable:!1,configurable:!0}),r.prototype.checkItem=function(e){return e.isKind("end")&&e.getName()===this.getName()&&this.setProperty("end",!1),t.prototype.checkItem.call(this,e)},r})(aC.BeginItem);i2.EmpheqBeginItem=tT;i2.EmpheqMethods={Empheq:function(t,r){if(t.stack.env.closing===r.getName()){delete t.stack.env.closing,t.Push(t.itemFactory.create("end").setProperty("name",t.stack.global.empheq)),t.stack.global.empheq="";var e=t.stack.Top();ct.EmpheqUtil.adjustTable(e,t),t.Push(t.itemFactory.create("end").setProperty("name","empheq"))}else{nC.default.checkEqnEnv(t),delete t.stack.global.eqnenv;var Q=t.GetBrackets("\\begin{"+r.getName()+"}")||"",T=QC((t.GetArgument("\\begin{"+r.getName()+"}")||"").split(/=/),2),n=T[0],i=T[1];if(!ct.EmpheqUtil.checkEnv(n))throw new iC.default("UnknownEnv",'Unknown environment "%1"',n);Q&&r.setProperties(ct.EmpheqUtil.splitOptions(Q,{left:1,right:1})),t.stack.global.empheq=n,t.string="\\begin{"+n+"}"+(i?"{"+i+"}":"")+t.string.slice(t.i),t.i=0,t.Push(r)}},EmpheqMO:function(t,r,e){t.Push(t.create("token","mo",{},e))},EmpheqDelim:function(t,r){var e=t.GetDelimiter(r);t.Push(t.create("token","mo",{stretchy:!0,symmetric:!0},e))}};new Hc.EnvironmentMap("empheq-env",ct.EmpheqUtil.environment,{empheq:["Empheq","empheq"]},i2.EmpheqMethods);new Hc.CommandMap("empheq-macros",{empheqlbrace:["EmpheqMO","{"],empheqrbrace:["EmpheqMO","}"],empheqlbrack:["EmpheqMO","["],empheqrbrack:["EmpheqMO","]"],empheqlangle:["EmpheqMO","\u27E8"],empheqrangle:["EmpheqMO","\u27E9"],empheqlparen:["EmpheqMO","("],empheqrparen:["EmpheqMO",")"],empheqlvert:["EmpheqMO","|"],empheqrvert:["EmpheqMO","|"],empheqlVert:["EmpheqMO","\u2016"],empheqrVert:["EmpheqMO","\u2016"],empheqlfloor:["EmpheqMO","\u230A"],empheqrfloor:["EmpheqMO","\u230B"],empheqlceil:["EmpheqMO","\u2308"],empheqrceil:["EmpheqMO","\u2309"],empheqbiglbrace:["EmpheqMO","{"],empheqbigrbrace:["EmpheqMO","}"],empheqbiglbrack:["EmpheqMO","["],empheqbigrbrack:["EmpheqMO","]"],empheqbiglangle:["EmpheqMO","\u27E8"],empheqbigrangle:["EmpheqMO","\u27E9"],empheqbiglparen:["EmpheqMO","("],empheqbigrparen:["EmpheqMO",")"],empheqbiglvert:["EmpheqMO","|"],empheqbigrvert:["EmpheqMO","|"],empheqbiglVert:["EmpheqMO","\u2016"],empheqbigrVert:["EmpheqMO","\u2016"],empheqbiglfloor:["EmpheqMO","\u230A"],empheqbigrfloor:["EmpheqMO","\u230B"],empheqbiglceil:["EmpheqMO","\u2308"],empheqbigrceil:["EmpheqMO","\u2309"],empheql:"EmpheqDelim",empheqr:"EmpheqDelim",empheqbigl:"EmpheqDelim",empheqbigr:"EmpheqDelim"},i2.EmpheqMethods);i2.EmpheqConfiguration=TC.Configuration.create("empheq",{handler:{macro:["empheq-macros"],environment:["empheq-env"]},items:(YQ={},YQ[tT.prototype.kind]=tT,YQ)})});var mc=p(ft=>{"use strict";var oC=ft&&ft.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(ft,"__esModule",{value:!0});var sC=oC(lt()),uC=T1();new uC.CommandMap("Newcommand-macros",{newcommand:"NewCommand",renewcommand:"NewCommand",newenvironment:"NewEnvironment",renewenvironment:"NewEnvironment",def:"MacroDef",let:"Let"},sC.default)});var QT=p(T3=>{"use strict";var lC=T3&&T3.__createBinding||(Object.create?(function(t,r,e,Q){Q===void 0&&(Q=e);var T=Object.getOwnPropertyDescriptor(r,e);(!T||("get"in T?!r.__esModule:T.writable||T.configurable))&&(T={enumerable:!0,get:function(){return r[e]}}),Object.defineProperty(t,Q,T)}):(function(t,r,e,Q){Q===void 0&&(Q=e),t[Q]=r[e]})),cC=T3&&T3.__setModuleDefault||(Object.create?(function(t,r){Object.defineProperty(t,"default",{enumerable:!0,value:r})}):function(t,r){t.default=r}),fC=T3&&T3.__importStar||function(t){if(t&&t.__esModule)return t;var r={};if(t!=null)for(var e in t)e!=="default"&&Object.prototype.hasOwnProperty.call(t,e)&&lC(r,t,e);return cC(r,t),r},Vc=T3&&T3.__importDefault||function(t){return t&&t.__esModule?t:{default:t}},eT;Object.defineProperty(T3,"__esModule",{value:!0});T3.NewcommandConfiguration=void 0;var _c=i1(),gc=XQ(),G6=Vc(o7());mc();var Mc=Vc(O3()),rT=fC(T1()),hC=function(t){new rT.DelimiterMap(G6.default.NEW_DELIMITER,Mc.default.delimiter,{}),new rT.CommandMap(G6.default.NEW_COMMAND,{},{}),new rT.EnvironmentMap(G6.default.NEW_ENVIRONMENT,Mc.default.environment,{},{}),t.append(_c.Configuration.local({handler:{character:[],delimiter:[G6.default.NEW_DELIMITER],macro:[G6.default.NEW_DELIMITER,G6.default.NEW_COMMAND],environment:[G6.default.NEW_ENVIRONMENT]},priority:-1}))};T3.NewcommandConfiguration=_c.Configuration.create("newcommand",{handler:{macro:["Newcommand-macros"]},items:(eT={},eT[gc.BeginEnvItem.prototype.kind]=gc.BeginEnvItem,eT),options:{maxMacros:1e3},init:hC})});var Oc=p(n3=>{"use strict";var Ac=n3&&n3.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(n3,"__esModule",{value:!0});n3.ExtpfeilConfiguration=n3.ExtpfeilMethods=void 0;var dC=i1(),LC=T1(),pC=h0(),vC=Ac(o7()),xC=QT(),TT=Ac(l1());n3.ExtpfeilMethods={};n3.ExtpfeilMethods.xArrow=pC.AmsMethods.xArrow;n3.ExtpfeilMethods.NewExtArrow=function(t,r){var e=t.GetArgument(r),Q=t.GetArgument(r),T=t.GetArgument(r);if(!e.match(/^\\([a-z]+|.)$/i))throw new TT.default("NewextarrowArg1","First argument to %1 must be a control sequence name",r);if(!Q.match(/^(\d+),(\d+)$/))throw new TT.default("NewextarrowArg2","Second argument to %1 must be two integers separated by a comma",r);if(!T.match(/^(\d+|0x[0-9A-F]+)$/i))throw new TT.default("NewextarrowArg3","Third argument to %1 must be a unicode character number",r);e=e.substr(1);var n=Q.split(",");vC.default.addMacro(t,e,n3.ExtpfeilMethods.xArrow,[parseInt(T),parseInt(n[0]),parseInt(n[1])])};new LC.CommandMap("extpfeil",{xtwoheadrightarrow:["xArrow",8608,12,16],xtwoheadleftarrow:["xArrow",8606,17,13],xmapsto:["xArrow",8614,6,7],xlongequal:["xArrow",61,7,7],xtofrom:["xArrow",8644,12,12],Newextarrow:"NewExtArrow"},n3.ExtpfeilMethods);var HC=function(t){xC.NewcommandConfiguration.init(t)};n3.ExtpfeilConfiguration=dC.Configuration.create("extpfeil",{handler:{macro:["extpfeil"]},init:HC})});var Cc=p(ht=>{"use strict";Object.defineProperty(ht,"__esModule",{value:!0});ht.GensymbConfiguration=void 0;var yC=i1(),mC=X1(),gC=T1();function MC(t,r){var e=r.attributes||{};e.mathvariant=mC.TexConstant.Variant.NORMAL,e.class="MathML-Unit";var Q=t.create("token","mi",e,r.char);t.Push(Q)}new gC.CharacterMap("gensymb-symbols",MC,{ohm:"\u2126",degree:"\xB0",celsius:"\u2103",perthousand:"\u2030",micro:"\xB5"});ht.GensymbConfiguration=yC.Configuration.create("gensymb",{handler:{macro:["gensymb-symbols"]}})});var Dc=p(s7=>{"use strict";var VC=s7&&s7.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(s7,"__esModule",{value:!0});var e4=VC(H1()),u7={};u7.Href=function(t,r){var e=t.GetArgument(r),Q=dt(t,r);e4.default.setAttribute(Q,"href",e),t.Push(Q)};u7.Class=function(t,r){var e=t.GetArgument(r),Q=dt(t,r),T=e4.default.getAttribute(Q,"class");T&&(e=T+" "+e),e4.default.setAttribute(Q,"class",e),t.Push(Q)};u7.Style=function(t,r){var e=t.GetArgument(r),Q=dt(t,r),T=e4.default.getAttribute(Q,"style");T&&(e.charAt(e.length-1)!==";"&&(e+=";"),e=T+" "+e),e4.default.setAttribute(Q,"style",e),t.Push(Q)};u7.Id=function(t,r){var e=t.GetArgument(r),Q=dt(t,r);e4.default.setAttribute(Q,"id",e),t.Push(Q)};var dt=function(t,r){var e=t.ParseArg(r);if(!e4.default.isInferred(e))return e;var Q=e4.default.getChildren(e);if(Q.length===1)return Q[0];var T=t.create("node","mrow");return e4.default.copyChildren(e,T),e4.default.copyAttributes(e,T),T};s7.default=u7});var Ec=p(v0=>{"use strict";var _C=v0&&v0.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(v0,"__esModule",{value:!0});v0.HtmlConfiguration=void 0;var AC=i1(),OC=T1(),CC=_C(Dc());new OC.CommandMap("html_macros",{href:"Href",class:"Class",style:"Style",cssId:"Id"},CC.default);v0.HtmlConfiguration=AC.Configuration.create("html",{handler:{macro:["html_macros"]}})});var iT=p(H6=>{"use strict";var DC=H6&&H6.__read||function(t,r){var e=typeof Symbol=="function"&&t[Symbol.iterator];if(!e)return t;var Q=e.call(t),T,n=[],i;try{for(;(r===void 0||r-- >0)&&!(T=Q.next()).done;)n.push(T.value)}catch(a){i={error:a}}finally{try{T&&!T.done&&(e=Q.return)&&e.call(Q)}finally{if(i)throw i.error}}return n},nT=H6&&H6.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(H6,"__esModule",{value:!0});H6.MathtoolsUtil=void 0;var EC=_3(),Lt=nT(h1()),bC=nT(W1()),bc=nT(l1()),SC=b6(),wC=B1(),BC=aT(),PC=oT();H6.MathtoolsUtil={setDisplayLevel:function(t,r){if(r){var e=DC((0,wC.lookup)(r,{"\\displaystyle":[!0,0],"\\textstyle":[!1,0],"\\scriptstyle":[!1,1],"\\scriptscriptstyle":[!1,2]},[null,null]),2),Q=e[0],T=e[1];Q!==null&&(t.attributes.set("displaystyle",Q),t.attributes.set("scriptlevel",T))}},checkAlignment:function(t,r){var e=t.stack.Top();if(e.kind!==EC.EqnArrayItem.prototype.kind)throw new bc.default("NotInAlignment","%1 can only be used in aligment environments",r);return e},addPairedDelims:function(t,r,e){var Q=t.handlers.retrieve(PC.PAIREDDELIMS);Q.add(r,new SC.Macro(r,BC.MathtoolsMethods.PairedDelimiters,e))},spreadLines:function(t,r){if(t.isKind("mtable")){var e=t.attributes.get("rowspacing");if(e){var Q=Lt.default.dimen2em(r);e=e.split(/ /).map(function(T){return Lt.default.Em(Math.max(0,Lt.default.dimen2em(T)+Q))}).join(" ")}else e=r;t.attributes.set("rowspacing",e)}},plusOrMinus:function(t,r){if(r=r.trim(),!r.match(/^[-+]?(?:\d+(?:\.\d*)?|\.\d+)$/))throw new bc.default("NotANumber","Argument to %1 is not a number",t);return r.match(/^[-+]/)?r:"+"+r},getScript:function(t,r,e){var Q=Lt.default.trimSpaces(t.GetArgument(r));if(Q==="")return t.create("node","none");var T=t.options.mathtools["prescript-".concat(e,"-format")];return T&&(Q="".concat(T,"{").concat(Q,"}")),new bC.default(Q,t.stack.env,t.configuration).mml()}}});var aT=p(J1=>{"use strict";var c7=J1&&J1.__assign||function(){return c7=Object.assign||function(t){for(var r,e=1,Q=arguments.length;e<Q;e++){r=arguments[e];for(var T in r)Object.prototype.hasOwnProperty.call(r,T)&&(t[T]=r[T])}return t},c7.apply(this,arguments)},sT=J1&&J1.__read||function(t,r){var e=typeof Symbol=="function"&&t[Symbol.iterator];if(!e)return t;var Q=e.call(t),T,n=[],i;try{for(;(r===void 0||r-- >0)&&!(T=Q.next()).done;)n.push(T.value)}catch(a){i={error:a}}finally{try{T&&!T.done&&(e=Q.return)&&e.call(Q)}finally{if(i)throw i.error}}return n},Sc=J1&&J1.__values||function(t){var r=typeof Symbol=="function"&&Symbol.iterator,e=r&&t[r],Q=0;if(e)return e.call(t);if(t&&typeof t.length=="number")return{next:function(){return t&&Q>=t.length&&(t=void 0),{value:t&&t[Q++],done:!t}}};throw new TypeError(r?"Object is not iterable.":"Symbol.iterator is not defined.")},W6=J1&&J1.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(J1,"__esModule",{value:!0});J1.MathtoolsMethods=void 0;var i3=W6(h1()),uT=h0(),wc=W6(M2()),pt=W6(W1()),y4=W6(l1()),y3=W6(H1()),lT=R(),Bc=A3(),jC=B1(),l7=W6(o7()),NC=W6(lt()),a2=iT();J1.MathtoolsMethods={MtMatrix:function(t,r,e,Q){var T=t.GetBrackets("\\begin{".concat(r.getName(),"}"),"c");return J1.MathtoolsMethods.Array(t,r,e,Q,T)},MtSmallMatrix:function(t,r,e,Q,T){return T||(T=t.GetBrackets("\\begin{".concat(r.getName(),"}"),t.options.mathtools["smallmatrix-align"])),J1.MathtoolsMethods.Array(t,r,e,Q,T,i3.default.Em(1/3),".2em","S",1)},MtMultlined:function(t,r){var e,Q="\\begin{".concat(r.getName(),"}"),T=t.GetBrackets(Q,t.options.mathtools["multlined-pos"]||"c"),n=T?t.GetBrackets(Q,""):"";T&&!T.match(/^[cbt]$/)&&(e=sT([T,n],2),n=e[0],T=e[1]),t.Push(r);var i=t.itemFactory.create("multlined",t,r);return i.arraydef={displaystyle:!0,rowspacing:".5em",width:n||"auto",columnwidth:"100%"},i3.default.setArrayAlign(i,T||"c")},HandleShove:function(t,r,e){var Q=t.stack.Top();if(Q.kind!=="multline"&&Q.kind!=="multlined")throw new y4.default("CommandInMultlined","%1 can only appear within the multline or multlined environments",r);if(Q.Size())throw new y4.default("CommandAtTheBeginingOfLine","%1 must come at the beginning of the line",r);Q.setProperty("shove",e);var T=t.GetBrackets(r),n=t.ParseArg(r);if(T){var i=t.create("node","mrow",[]),a=t.create("node","mspace",[],{width:T});e==="left"?(i.appendChild(a),i.appendChild(n)):(i.appendChild(n),i.appendChild(a)),n=i}t.Push(n)},SpreadLines:function(t,r){var e,Q;if(t.stack.env.closing===r.getName()){delete t.stack.env.closing;var T=t.stack.Pop(),n=T.toMml(),i=T.getProperty("spread");if(n.isInferred)try{for(var a=Sc(y3.default.getChildren(n)),o=a.next();!o.done;o=a.next()){var u=o.value;a2.MathtoolsUtil.spreadLines(u,i)}}catch(l){e={error:l}}finally{try{o&&!o.done&&(Q=a.return)&&Q.call(a)}finally{if(e)throw e.error}}else a2.MathtoolsUtil.spreadLines(n,i);t.Push(n)}else{var i=t.GetDimen("\\begin{".concat(r.getName(),"}"));r.setProperty("spread",i),t.Push(r)}},Cases:function(t,r,e,Q,T){var n=t.itemFactory.create("array").setProperty("casesEnv",r.getName());return n.arraydef={rowspacing:".2em",columnspacing:"1em",columnalign:"left"},T==="D"&&(n.arraydef.displaystyle=!0),n.setProperties({open:e,close:Q}),t.Push(r),n},MathLap:function(t,r,e,Q){var T=t.GetBrackets(r,"").trim(),n=t.create("node","mstyle",[t.create("node","mpadded",[t.ParseArg(r)],c7({width:0},e==="r"?{}:{lspace:e==="l"?"-1width":"-.5width"}))],{"data-cramped":Q});a2.MathtoolsUtil.setDisplayLevel(n,T),t.Push(t.create("node","TeXAtom",[n]))},Cramped:function(t,r){var e=t.GetBrackets(r,"").trim(),Q=t.ParseArg(r),T=t.create("node","mstyle",[Q],{"data-cramped":!0});a2.MathtoolsUtil.setDisplayLevel(T,e),t.Push(T)},MtLap:function(t,r,e){var Q=i3.default.internalMath(t,t.GetArgument(r),0),T=t.create("node","mpadded",Q,{width:0});e!=="r"&&y3.default.setAttribute(T,"lspace",e==="l"?"-1width":"-.5width"),t.Push(T)},MathMakeBox:function(t,r){var e=t.GetBrackets(r),Q=t.GetBrackets(r,"c"),T=t.create("node","mpadded",[t.ParseArg(r)]);e&&y3.default.setAttribute(T,"width",e);var n=(0,jC.lookup)(Q,{c:"center",r:"right"},"");n&&y3.default.setAttribute(T,"data-align",n),t.Push(T)},MathMBox:function(t,r){t.Push(t.create("node","mrow",[t.ParseArg(r)]))},UnderOverBracket:function(t,r){var e=(0,Bc.length2em)(t.GetBrackets(r,".1em"),.1),Q=t.GetBrackets(r,".2em"),T=t.GetArgument(r),n=sT(r.charAt(1)==="o"?["over","accent","bottom"]:["under","accentunder","top"],3),i=n[0],a=n[1],o=n[2],u=(0,Bc.em)(e),l=new pt.default(T,t.stack.env,t.configuration).mml(),c=new pt.default(T,t.stack.env,t.configuration).mml(),f=t.create("node","mpadded",[t.create("node","mphantom",[c])],{style:"border: ".concat(u," solid; border-").concat(o,": none"),height:Q,depth:0}),h=i3.default.underOver(t,l,f,i,!0),d=y3.default.getChildAt(y3.default.getChildAt(h,0),0);y3.default.setAttribute(d,a,!0),t.Push(h)},Aboxed:function(t,r){var e=a2.MathtoolsUtil.checkAlignment(t,r);e.row.length%2===1&&e.row.push(t.create("node","mtd",[]));var Q=t.GetArgument(r),T=t.string.substr(t.i);t.string=Q+"&&\\endAboxed",t.i=0;var n=t.GetUpTo(r,"&"),i=t.GetUpTo(r,"&");t.GetUpTo(r,"\\endAboxed");var a=i3.default.substituteArgs(t,[n,i],"\\rlap{\\boxed{#1{}#2}}\\kern.267em\\phantom{#1}&\\phantom{{}#2}\\kern.267em");t.string=a+T,t.i=0},ArrowBetweenLines:function(t,r){var e=a2.MathtoolsUtil.checkAlignment(t,r);if(e.Size()||e.row.length)throw new y4.default("BetweenLines","%1 must be on a row by itself",r);var Q=t.GetStar(),T=t.GetBrackets(r,"\\Updownarrow");Q&&(e.EndEntry(),e.EndEntry());var n=Q?"\\quad"+T:T+"\\quad",i=new pt.default(n,t.stack.env,t.configuration).mml();t.Push(i),e.EndEntry(),e.EndRow()},VDotsWithin:function(t,r){var e=t.stack.Top(),Q=e.getProperty("flushspaceabove")===e.table.length,T="\\mmlToken{mi}{}"+t.GetArgument(r)+"\\mmlToken{mi}{}",n=new pt.default(T,t.stack.env,t.configuration).mml(),i=t.create("node","mpadded",[t.create("node","mpadded",[t.create("node","mo",[t.create("text","\u22EE")])],c7({width:0,lspace:"-.5width"},Q?{height:"-.6em",voffset:"-.18em"}:{})),t.create("node","mphantom",[n])],{lspace:".5width"});t.Push(i)},ShortVDotsWithin:function(t,r){var e=t.stack.Top(),Q=t.GetStar();J1.MathtoolsMethods.FlushSpaceAbove(t,"\\MTFlushSpaceAbove"),!Q&&e.EndEntry(),J1.MathtoolsMethods.VDotsWithin(t,"\\vdotswithin"),Q&&e.EndEntry(),J1.MathtoolsMethods.FlushSpaceBelow(t,"\\MTFlushSpaceBelow")},FlushSpaceAbove:function(t,r){var e=a2.MathtoolsUtil.checkAlignment(t,r);e.setProperty("flushspaceabove",e.table.length),e.addRowSpacing("-"+t.options.mathtools.shortvdotsadjustabove)},FlushSpaceBelow:function(t,r){var e=a2.MathtoolsUtil.checkAlignment(t,r);e.Size()&&e.EndEntry(),e.EndRow(),e.addRowSpacing("-"+t.options.mathtools.shortvdotsadjustbelow)},PairedDelimiters:function(t,r,e,Q,T,n,i,a){T===void 0&&(T="#1"),n===void 0&&(n=1),i===void 0&&(i=""),a===void 0&&(a="");var o=t.GetStar(),u=o?"":t.GetBrackets(r),l=sT(o?["\\left","\\right"]:u?[u+"l",u+"r"]:["",""],2),c=l[0],f=l[1],h=o?"\\middle":u||"";if(n){for(var d=[],L=d.length;L<n;L++)d.push(t.GetArgument(r));i=i3.default.substituteArgs(t,d,i),T=i3.default.substituteArgs(t,d,T),a=i3.default.substituteArgs(t,d,a)}T=T.replace(/\\delimsize/g,h),t.string=[i,c,e,T,f,Q,a,t.string.substr(t.i)].reduce(function(v,x){return i3.default.addArgs(t,v,x)},""),t.i=0,i3.default.checkMaxMacros(t)},DeclarePairedDelimiter:function(t,r){var e=l7.default.GetCsNameArgument(t,r),Q=t.GetArgument(r),T=t.GetArgument(r);a2.MathtoolsUtil.addPairedDelims(t.configuration,e,[Q,T])},DeclarePairedDelimiterX:function(t,r){var e=l7.default.GetCsNameArgument(t,r),Q=l7.default.GetArgCount(t,r),T=t.GetArgument(r),n=t.GetArgument(r),i=t.GetArgument(r);a2.MathtoolsUtil.addPairedDelims(t.configuration,e,[T,n,i,Q])},DeclarePairedDelimiterXPP:function(t,r){var e=l7.default.GetCsNameArgument(t,r),Q=l7.default.GetArgCount(t,r),T=t.GetArgument(r),n=t.GetArgument(r),i=t.GetArgument(r),a=t.GetArgument(r),o=t.GetArgument(r);a2.MathtoolsUtil.addPairedDelims(t.configuration,e,[n,i,o,Q,T,a])},CenterColon:function(t,r,e,Q,T){Q===void 0&&(Q=!1),T===void 0&&(T=!1);var n=t.options.mathtools,i=t.create("token","mo",{},":");if(e&&(n.centercolon||Q)){var a=n["centercolon-offset"];i=t.create("node","mpadded",[i],c7({voffset:a,height:"+".concat(a),depth:"-".concat(a)},T?{width:n["thincolon-dw"],lspace:n["thincolon-dx"]}:{}))}t.Push(i)},Relation:function(t,r,e,Q){var T=t.options.mathtools;T["use-unicode"]&&Q?t.Push(t.create("token","mo",{texClass:lT.TEXCLASS.REL},Q)):(e="\\mathrel{"+e.replace(/:/g,"\\MTThinColon").replace(/-/g,"\\mathrel{-}")+"}",t.string=i3.default.addArgs(t,e,t.string.substr(t.i)),t.i=0)},NArrow:function(t,r,e,Q){t.Push(t.create("node","TeXAtom",[t.create("token","mtext",{},e),t.create("node","mpadded",[t.create("node","mpadded",[t.create("node","menclose",[t.create("node","mspace",[],{height:".2em",depth:0,width:".4em"})],{notation:"updiagonalstrike","data-thickness":".05em","data-padding":0})],{width:0,lspace:"-.5width",voffset:Q}),t.create("node","mphantom",[t.create("token","mtext",{},e)])],{width:0,lspace:"-.5width"})],{texClass:lT.TEXCLASS.REL}))},SplitFrac:function(t,r,e){var Q=t.ParseArg(r),T=t.ParseArg(r);t.Push(t.create("node","mstyle",[t.create("node","mfrac",[t.create("node","mstyle",[Q,t.create("token","mi"),t.create("token","mspace",{width:"1em"})],{scriptlevel:0}),t.create("node","mstyle",[t.create("token","mspace",{width:"1em"}),t.create("token","mi"),T],{scriptlevel:0})],{linethickness:0,numalign:"left",denomalign:"right"})],{displaystyle:e,scriptlevel:0}))},XMathStrut:function(t,r){var e=t.GetBrackets(r),Q=t.GetArgument(r);Q=a2.MathtoolsUtil.plusOrMinus(r,Q),e=a2.MathtoolsUtil.plusOrMinus(r,e||Q),t.Push(t.create("node","TeXAtom",[t.create("node","mpadded",[t.create("node","mphantom",[t.create("token","mo",{stretchy:!1},"(")])],{width:0,height:Q+"height",depth:e+"depth"})],{texClass:lT.TEXCLASS.ORD}))},Prescript:function(t,r){var e=a2.MathtoolsUtil.getScript(t,r,"sup"),Q=a2.MathtoolsUtil.getScript(t,r,"sub"),T=a2.MathtoolsUtil.getScript(t,r,"arg");if(y3.default.isType(e,"none")&&y3.default.isType(Q,"none")){t.Push(T);return}var n=t.create("node","mmultiscripts",[T]);y3.default.getChildren(n).push(null,null),y3.default.appendChildren(n,[t.create("node","mprescripts"),Q,e]),n.setProperty("fixPrescript",!0),t.Push(n)},NewTagForm:function(t,r,e){e===void 0&&(e=!1);var Q=t.tags;if(!("mtFormats"in Q))throw new y4.default("TagsNotMT","%1 can only be used with ams or mathtools tags",r);var T=t.GetArgument(r).trim();if(!T)throw new y4.default("InvalidTagFormID","Tag form name can't be empty");var n=t.GetBrackets(r,""),i=t.GetArgument(r),a=t.GetArgument(r);if(!e&&Q.mtFormats.has(T))throw new y4.default("DuplicateTagForm","Duplicate tag form: %1",T);Q.mtFormats.set(T,[i,a,n])},UseTagForm:function(t,r){var e=t.tags;if(!("mtFormats"in e))throw new y4.default("TagsNotMT","%1 can only be used with ams or mathtools tags",r);var Q=t.GetArgument(r).trim();if(!Q){e.mtCurrent=null;return}if(!e.mtFormats.has(Q))throw new y4.default("UndefinedTagForm","Undefined tag form: %1",Q);e.mtCurrent=e.mtFormats.get(Q)},SetOptions:function(t,r){var e,Q,T=t.options.mathtools;if(!T["allow-mathtoolsset"])throw new y4.default("ForbiddenMathtoolsSet","%1 is disabled",r);var n={};Object.keys(T).forEach(function(c){c!=="pariedDelimiters"&&c!=="tagforms"&&c!=="allow-mathtoolsset"&&(n[c]=1)});var i=t.GetArgument(r),a=i3.default.keyvalOptions(i,n,!0);try{for(var o=Sc(Object.keys(a)),u=o.next();!u.done;u=o.next()){var l=u.value;T[l]=a[l]}}catch(c){e={error:c}}finally{try{u&&!u.done&&(Q=o.return)&&Q.call(o)}finally{if(e)throw e.error}}},Array:wc.default.Array,Macro:wc.default.Macro,xArrow:uT.AmsMethods.xArrow,HandleRef:uT.AmsMethods.HandleRef,AmsEqnArray:uT.AmsMethods.AmsEqnArray,MacroWithTemplate:NC.default.MacroWithTemplate}});var Nc=p(vt=>{"use strict";var kC=vt&&vt.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(vt,"__esModule",{value:!0});var jc=kC(O3()),xt=T1(),Pc=X1(),cT=aT();new xt.CommandMap("mathtools-macros",{shoveleft:["HandleShove",Pc.TexConstant.Align.LEFT],shoveright:["HandleShove",Pc.TexConstant.Align.RIGHT],xleftrightarrow:["xArrow",8596,10,10],xLeftarrow:["xArrow",8656,12,7],xRightarrow:["xArrow",8658,7,12],xLeftrightarrow:["xArrow",8660,12,12],xhookleftarrow:["xArrow",8617,10,5],xhookrightarrow:["xArrow",8618,5,10],xmapsto:["xArrow",8614,10,10],xrightharpoondown:["xArrow",8641,5,10],xleftharpoondown:["xArrow",8637,10,5],xrightleftharpoons:["xArrow",8652,10,10],xrightharpoonup:["xArrow",8640,5,10],xleftharpoonup:["xArrow",8636,10,5],xleftrightharpoons:["xArrow",8651,10,10],mathllap:["MathLap","l",!1],mathrlap:["MathLap","r",!1],mathclap:["MathLap","c",!1],clap:["MtLap","c"],textllap:["MtLap","l"],textrlap:["MtLap","r"],textclap:["MtLap","c"],cramped:"Cramped",crampedllap:["MathLap","l",!0],crampedrlap:["MathLap","r",!0],crampedclap:["MathLap","c",!0],crampedsubstack:["Macro","\\begin{crampedsubarray}{c}#1\\end{crampedsubarray}",1],mathmbox:"MathMBox",mathmakebox:"MathMakeBox",overbracket:"UnderOverBracket",underbracket:"UnderOverBracket",refeq:"HandleRef",MoveEqLeft:["Macro","\\hspace{#1em}&\\hspace{-#1em}",1,"2"],Aboxed:"Aboxed",ArrowBetweenLines:"ArrowBetweenLines",vdotswithin:"VDotsWithin",shortvdotswithin:"ShortVDotsWithin",MTFlushSpaceAbove:"FlushSpaceAbove",MTFlushSpaceBelow:"FlushSpaceBelow",DeclarePairedDelimiter:"DeclarePairedDelimiter",DeclarePairedDelimiterX:"DeclarePairedDelimiterX",DeclarePairedDelimiterXPP:"DeclarePairedDelimiterXPP",DeclarePairedDelimiters:"DeclarePairedDelimiter",DeclarePairedDelimitersX:"DeclarePairedDelimiterX",DeclarePairedDelimitersXPP:"DeclarePairedDelimiterXPP",centercolon:["CenterColon",!0,!0],ordinarycolon:["CenterColon",!1],MTThinColon:["CenterColon",!0,!0,!0],coloneqq:["Relation",":=","\u2254"],Coloneqq:["Relation","::=","\u2A74"],coloneq:["Relation",":-"],Coloneq:["Relation","::-"],eqqcolon:["Relation","=:","\u2255"],Eqqcolon:["Relation","=::"],eqcolon:["Relation","-:","\u2239"],Eqcolon:["Relation","-::"],colonapprox:["Relation",":\\approx"],Colonapprox:["Relation","::\\approx"],colonsim:["Relation",":\\sim"],Colonsim:["Relation","::\\sim"],dblcolon:["Relation","::","\u2237"],nuparrow:["NArrow","\u2191",".06em"],ndownarrow:["NArrow","\u2193",".25em"],bigtimes:["Macro","\\mathop{\\Large\\kern-.1em\\boldsymbol{\\times}\\kern-.1em}"],splitfrac:["SplitFrac",!1],splitdfrac:["SplitFrac",!0],xmathstrut:"XMathStrut",prescript:"Prescript",newtagform:["NewTagForm",!1],renewtagform:["NewTagForm",!0],usetagform:"UseTagForm",adjustlimits:["MacroWithTemplate","\\mathop{{#1}\\vphantom{{#3}}}_{{#2}\\vphantom{{#4}}}\\mathop{{#3}\\vphantom{{#1}}}_{{#4}\\vphantom{{#2}}}",4,,"_",,"_"],mathtoolsset:"SetOptions"},cT.MathtoolsMethods);new xt.EnvironmentMap("mathtools-environments",jc.default.environment,{dcases:["Array",null,"\\{","","ll",null,".2em","D"],rcases:["Array",null,"","\\}","ll",null,".2em"],drcases:["Array",null,"","\\}","ll",null,".2em","D"],"dcases*":["Cases",null,"{","","D"],"rcases*":["Cases",null,"","}"],"drcases*":["Cases",null,"","}","D"],"cases*":["Cases",null,"{",""],"matrix*":["MtMatrix",null,null,null],"pmatrix*":["MtMatrix",null,"(",")"],"bmatrix*":["MtMatrix",null,"[","]"],"Bmatrix*":["MtMatrix",null,"\\{","\\}"],"vmatrix*":["MtMatrix",null,"\\vert","\\vert"],"Vmatrix*":["MtMatrix",null,"\\Vert","\\Vert"],"smallmatrix*":["MtSmallMatrix",null,null,null],psmallmatrix:["MtSmallMatrix",null,"(",")","c"],"psmallmatrix*":["MtSmallMatrix",null,"(",")"],bsmallmatrix:["MtSmallMatrix",null,"[","]","c"],"bsmallmatrix*":["MtSmallMatrix",null,"[","]"],Bsmallmatrix:["MtSmallMatrix",null,"\\{","\\}","c"],"Bsmallmatrix*":["MtSmallMatrix",null,"\\{","\\}"],vsmallmatrix:["MtSmallMatrix",null,"\\vert","\\vert","c"],"vsmallmatrix*":["MtSmallMatrix",null,"\\vert","\\vert"],Vsmallmatrix:["MtSmallMatrix",null,"\\Vert","\\Vert","c"],"Vsmallmatrix*":["MtSmallMatrix",null,"\\Vert","\\Vert"],crampedsubarray:["Array",null,null,null,null,"0em","0.1em","S'",1],multlined:"MtMultlined",spreadlines:["SpreadLines",!0],lgathered:["AmsEqnArray",null,null,null,"l",null,".5em","D"],rgathered:["AmsEqnArray",null,null,null,"r",null,".5em","D"]},cT.MathtoolsMethods);new xt.DelimiterMap("mathtools-delimiters",jc.default.delimiter,{"\\lparen":"(","\\rparen":")"});new xt.CommandMap("mathtools-characters",{":":["CenterColon",!0]},cT.MathtoolsMethods)});var Ic=p(a3=>{"use strict";var IC=a3&&a3.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})(),FC=a3&&a3.__values||function(t){var r=typeof Symbol=="function"&&Symbol.iterator,e=r&&t[r],Q=0;if(e)return e.call(t);if(t&&typeof t.length=="number")return{next:function(){return t&&Q>=t.length&&(t=void 0),{value:t&&t[Q++],done:!t}}};throw new TypeError(r?"Object is not iterable.":"Symbol.iterator is not defined.")},RC=a3&&a3.__read||function(t,r){var e=typeof Symbol=="function"&&t[Symbol.iterator];if(!e)return t;var Q=e.call(t),T,n=[],i;try{for(;(r===void 0||r-- >0)&&!(T=Q.next()).done;)n.push(T.value)}catch(a){i={error:a}}finally{try{T&&!T.done&&(e=Q.return)&&e.call(Q)}finally{if(i)throw i.error}}return n},qC=a3&&a3.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(a3,"__esModule",{value:!0});a3.MathtoolsTagFormat=void 0;var ZC=qC(l1()),fT=s4(),kc=0;function GC(t,r){var e=r.parseOptions.options.tags;e!=="base"&&t.tags.hasOwnProperty(e)&&fT.TagsFactory.add(e,t.tags[e]);var Q=fT.TagsFactory.create(r.parseOptions.options.tags).constructor,T=(function(i){IC(a,i);function a(){var o,u,l=i.call(this)||this;l.mtFormats=new Map,l.mtCurrent=null;var c=r.parseOptions.options.mathtools.tagforms;try{for(var f=FC(Object.keys(c)),h=f.next();!h.done;h=f.next()){var d=h.value;if(!Array.isArray(c[d])||c[d].length!==3)throw new ZC.default("InvalidTagFormDef",'The tag form definition for "%1" should be an array fo three strings',d);l.mtFormats.set(d,c[d])}}catch(L){o={error:L}}finally{try{h&&!h.done&&(u=f.return)&&u.call(f)}finally{if(o)throw o.error}}return l}return a.prototype.formatTag=function(o){if(this.mtCurrent){var u=RC(this.mtCurrent,3),l=u[0],c=u[1],f=u[2];return f?"".concat(l).concat(f,"{").concat(o,"}").concat(c):"".concat(l).concat(o).concat(c)}return i.prototype.formatTag.call(this,o)},a})(Q);kc++;var n="MathtoolsTags-"+kc;fT.TagsFactory.add(n,T),r.parseOptions.options.tags=n}a3.MathtoolsTagFormat=GC});var Rc=p(y6=>{"use strict";var WC=y6&&y6.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})(),XC=y6&&y6.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(y6,"__esModule",{value:!0});y6.MultlinedItem=void 0;var zC=vQ(),x0=XC(H1()),Fc=X1(),UC=(function(t){WC(r,t);function r(){return t!==null&&t.apply(this,arguments)||this}return Object.defineProperty(r.prototype,"kind",{get:function(){return"multlined"},enumerable:!1,configurable:!0}),r.prototype.EndTable=function(){if((this.Size()||this.row.length)&&(this.EndEntry(),this.EndRow()),this.table.length>1){var e=this.factory.configuration.options.mathtools,Q=e.multlinegap,T=e["firstline-afterskip"]||Q,n=e["lastline-preskip"]||Q,i=x0.default.getChildren(this.table[0])[0];x0.default.getAttribute(i,"columnalign")!==Fc.TexConstant.Align.RIGHT&&i.appendChild(this.create("node","mspace",[],{width:T}));var a=x0.default.getChildren(this.table[this.table.length-1])[0];if(x0.default.getAttribute(a,"columnalign")!==Fc.TexConstant.Align.LEFT){var o=x0.default.getChildren(a)[0];o.childNodes.unshift(null);var u=this.create("node","mspace",[],{width:n});x0.default.setChild(o,0,u)}}t.prototype.EndTable.call(this)},r})(zC.MultlineItem);y6.MultlinedItem=UC});var oT=p(v2=>{"use strict";var Ht=v2&&v2.__values||function(t){var r=typeof Symbol=="function"&&Symbol.iterator,e=r&&t[r],Q=0;if(e)return e.call(t);if(t&&typeof t.length=="number")return{next:function(){return t&&Q>=t.length&&(t=void 0),{value:t&&t[Q++],done:!t}}};throw new TypeError(r?"Object is not iterable.":"Symbol.iterator is not defined.")},$C=v2&&v2.__importDefault||function(t){return t&&t.__esModule?t:{default:t}},hT;Object.defineProperty(v2,"__esModule",{value:!0});v2.MathtoolsConfiguration=v2.fixPrescripts=v2.PAIREDDELIMS=void 0;var Gc=i1(),KC=T1(),f7=$C(H1()),qc=B1();Nc();var JC=iT(),YC=Ic(),Zc=Rc();v2.PAIREDDELIMS="mathtools-paired-delims";function tD(t){new KC.CommandMap(v2.PAIREDDELIMS,{},{}),t.append(Gc.Configuration.local({handler:{macro:[v2.PAIREDDELIMS]},priority:-5}))}function eD(t,r){var e,Q,T=r.parseOptions,n=T.options.mathtools.pairedDelimiters;try{for(var i=Ht(Object.keys(n)),a=i.next();!a.done;a=i.next()){var o=a.value;JC.MathtoolsUtil.addPairedDelims(T,o,n[o])}}catch(u){e={error:u}}finally{try{a&&!a.done&&(Q=i.return)&&Q.call(i)}finally{if(e)throw e.error}}(0,YC.MathtoolsTagFormat)(t,r)}function Wc(t){var r,e,Q,T,n,i,a=t.data;try{for(var o=Ht(a.getList("mmultiscripts")),u=o.next();!u.done;u=o.next()){var l=u.value;if(l.getProperty("fixPrescript")){var c=f7.default.getChildren(l),f=0;try{for(var h=(Q=void 0,Ht([1,2])),d=h.next();!d.done;d=h.next()){var L=d.value;c[L]||(f7.default.setChild(l,L,a.nodeFactory.create("node","none")),f++)}}catch(H){Q={error:H}}finally{try{d&&!d.done&&(T=h.return)&&T.call(h)}finally{if(Q)throw Q.error}}try{for(var v=(n=void 0,Ht([4,5])),x=v.next();!x.done;x=v.next()){var L=x.value;f7.default.isType(c[L],"mrow")&&f7.default.getChildren(c[L]).length===0&&f7.default.setChild(l,L,a.nodeFactory.create("node","none"))}}catch(H){n={error:H}}finally{try{x&&!x.done&&(i=v.return)&&i.call(v)}finally{if(n)throw n.error}}f===2&&c.splice(1,2)}}}catch(H){r={error:H}}finally{try{u&&!u.done&&(e=o.return)&&e.call(o)}finally{if(r)throw r.error}}}v2.fixPrescripts=Wc;v2.MathtoolsConfiguration=Gc.Configuration.create("mathtools",{handler:{macro:["mathtools-macros","mathtools-delimiters"],environment:["mathtools-environments"],delimiter:["mathtools-delimiters"],character:["mathtools-characters"]},items:(hT={},hT[Zc.MultlinedItem.prototype.kind]=Zc.MultlinedItem,hT),init:tD,config:eD,postprocessors:[[Wc,-6]],options:{mathtools:{multlinegap:"1em","multlined-pos":"c","firstline-afterskip":"","lastline-preskip":"","smallmatrix-align":"c",shortvdotsadjustabove:".2em",shortvdotsadjustbelow:".2em",centercolon:!1,"centercolon-offset":".04em","thincolon-dx":"-.04em","thincolon-dw":"-.08em","use-unicode":!1,"prescript-sub-format":"","prescript-sup-format":"","prescript-arg-format":"","allow-mathtoolsset":!0,pairedDelimiters:(0,qc.expandable)({}),tagforms:(0,qc.expandable)({})}}})});var Xc=p(yt=>{"use strict";Object.defineProperty(yt,"__esModule",{value:!0});yt.mhchemParser=void 0;var rD=(function(){function t(){}return t.toTex=function(r,e){return v1.go(V.go(r,e),e!=="tex")},t})();yt.mhchemParser=rD;function x2(t){var r,e,Q={};for(r in t)for(e in t[r]){var T=e.split("|");t[r][e].stateArray=T;for(var n=0;n<T.length;n++)Q[T[n]]=[]}for(r in t)for(e in t[r])for(var T=t[r][e].stateArray||[],n=0;n<T.length;n++){var i=t[r][e];i.action_=[].concat(i.action_);for(var a=0;a<i.action_.length;a++)typeof i.action_[a]=="string"&&(i.action_[a]={type_:i.action_[a]});for(var o=r.split("|"),u=0;u<o.length;u++)if(T[n]==="*"){var l=void 0;for(l in Q)Q[l].push({pattern:o[u],task:i})}else Q[T[n]].push({pattern:o[u],task:i})}return Q}var V={go:function(t,r){if(!t)return[];r===void 0&&(r="ce");var e="0",Q={};Q.parenthesisLevel=0,t=t.replace(/\n/g," "),t=t.replace(/[\u2212\u2013\u2014\u2010]/g,"-"),t=t.replace(/[\u2026]/g,"...");for(var T,n=10,i=[];;){T!==t?(n=10,T=t):n--;var a=V.stateMachines[r],o=a.transitions[e]||a.transitions["*"];t:for(var u=0;u<o.length;u++){var l=V.patterns.match_(o[u].pattern,t);if(l){for(var c=o[u].task,f=0;f<c.action_.length;f++){var h=void 0;if(a.actions[c.action_[f].type_])h=a.actions[c.action_[f].type_](Q,l.match_,c.action_[f].option);else if(V.actions[c.action_[f].type_])h=V.actions[c.action_[f].type_](Q,l.match_,c.action_[f].option);else throw["MhchemBugA","mhchem bug A. Please report. ("+c.action_[f].type_+")"];V.concatArray(i,h)}if(e=c.nextState||e,t.length>0){if(c.revisit||(t=l.remainder),!c.toContinue)break t}else return i}}if(n<=0)throw["MhchemBugU","mhchem bug U. Please report."]}},concatArray:function(t,r){if(r)if(Array.isArray(r))for(var e=0;e<r.length;e++)t.push(r[e]);else t.push(r)},patterns:{patterns:{empty:/^$/,else:/^./,else2:/^./,space:/^\s/,"space A":/^\s(?=[A-Z\\$])/,space$:/^\s$/,"a-z":/^[a-z]/,x:/^x/,x$:/^x$/,i$:/^i$/,letters:/^(?:[a-zA-Z\u03B1-\u03C9\u0391-\u03A9?@]|(?:\\(?:alpha|beta|gamma|delta|epsilon|zeta|eta|theta|iota|kappa|lambda|mu|nu|xi|omicron|pi|rho|sigma|tau|upsilon|phi|chi|psi|omega|Gamma|Delta|Theta|Lambda|Xi|Pi|Sigma|Upsilon|Phi|Psi|Omega)(?:\s+|\{\}|(?![a-zA-Z]))))+/,"\\greek":/^\\(?:alpha|beta|gamma|delta|epsilon|zeta|eta|theta|iota|kappa|lambda|mu|nu|xi|omicron|pi|rho|sigma|tau|upsilon|phi|chi|psi|omega|Gamma|Delta|Theta|Lambda|Xi|Pi|Sigma|Upsilon|Phi|Psi|Omega)(?:\s+|\{\}|(?![a-zA-Z]))/,"one lowercase latin letter $":/^(?:([a-z])(?:$|[^a-zA-Z]))$/,"$one lowercase latin letter$ $":/^\$(?:([a-z])(?:$|[^a-zA-Z]))\$$/,"one lowercase greek letter $":/^(?:\$?[\u03B1-\u03C9]\$?|\$?\\(?:alpha|beta|gamma|delta|epsilon|zeta|eta|theta|iota|kappa|lambda|mu|nu|xi|omicron|pi|rho|sigma|tau|upsilon|phi|chi|psi|omega)\s*\$?)(?:\s+|\{\}|(?![a-zA-Z]))$/,digits:/^[0-9]+/,"-9.,9":/^[+\-]?(?:[0-9]+(?:[,.][0-9]+)?|[0-9]*(?:\.[0-9]+))/,"-9.,9 no missing 0":/^[+\-]?[0-9]+(?:[.,][0-9]+)?/,"(-)(9.,9)(e)(99)":function(t){var r=t.match(/^(\+\-|\+\/\-|\+|\-|\\pm\s?)?([0-9]+(?:[,.][0-9]+)?|[0-9]*(?:\.[0-9]+))?(\((?:[0-9]+(?:[,.][0-9]+)?|[0-9]*(?:\.[0-9]+))\))?(?:(?:([eE])|\s*(\*|x|\\times|\u00D7)\s*10\^)([+\-]?[0-9]+|\{[+\-]?[0-9]+\}))?/);return r&&r[0]?{match_:r.slice(1),remainder:t.substr(r[0].length)}:null},"(-)(9)^(-9)":/^(\+\-|\+\/\-|\+|\-|\\pm\s?)?([0-9]+(?:[,.][0-9]+)?|[0-9]*(?:\.[0-9]+)?)\^([+\-]?[0-9]+|\{[+\-]?[0-9]+\})/,"state of aggregation $":function(t){var r=V.patterns.findObserveGroups(t,"",/^\([a-z]{1,3}(?=[\),])/,")","");if(r&&r.remainder.match(/^($|[\s,;\)\]\}])/))return r;var e=t.match(/^(?:\((?:\\ca\s?)?\$[amothc]\$\))/);return e?{match_:e[0],remainder:t.substr(e[0].length)}:null},"_{(state of aggregation)}$":/^_\{(\([a-z]{1,3}\))\}/,"{[(":/^(?:\\\{|\[|\()/,")]}":/^(?:\)|\]|\\\})/,", ":/^[,;]\s*/,",":/^[,;]/,".":/^[.]/,". __* ":/^([.\u22C5\u00B7\u2022]|[*])\s*/,"...":/^\.\.\.(?=$|[^.])/,"^{(...)}":function(t){return V.patterns.findObserveGroups(t,"^{","","","}")},"^($...$)":function(t){return V.patterns.findObserveGroups(t,"^","$","$","")},"^a":/^\^([0-9]+|[^\\_])/,"^\\x{}{}":function(t){return V.patterns.findObserveGroups(t,"^",/^\\[a-zA-Z]+\{/,"}","","","{","}","",!0)},"^\\x{}":function(t){return V.patterns.findObserveGroups(t,"^",/^\\[a-zA-Z]+\{/,"}","")},"^\\x":/^\^(\\[a-zA-Z]+)\s*/,"^(-1)":/^\^(-?\d+)/,"'":/^'/,"_{(...)}":function(t){return V.patterns.findObserveGroups(t,"_{","","","}")},"_($...$)":function(t){return V.patterns.findObserveGroups(t,"_","$","$","")},_9:/^_([+\-]?[0-9]+|[^\\])/,"_\\x{}{}":function(t){return V.patterns.findObserveGroups(t,"_",/^\\[a-zA-Z]+\{/,"}","","","{","}","",!0)},"_\\x{}":function(t){return V.patterns.findObserveGroups(t,"_",/^\\[a-zA-Z]+\{/,"}","")},"_\\x":/^_(\\[a-zA-Z]+)\s*/,"^_":/^(?:\^(?=_)|\_(?=\^)|[\^_]$)/,"{}^":/^\{\}(?=\^)/,"{}":/^\{\}/,"{...}":function(t){return V.patterns.findObserveGroups(t,"","{","}","")},"{(...)}":function(t){return V.patterns.findObserveGroups(t,"{","","","}")},"$...$":function(t){return V.patterns.findObserveGroups(t,"","$","$","")},"${(...)}$__$(...)$":function(t){return V.patterns.findObserveGroups(t,"${","","","}$")||V.patterns.findObserveGroups(t,"$","","","$")},"=<>":/^[=<>]/,"#":/^[#\u2261]/,"+":/^\+/,"-$":/^-(?=[\s_},;\]/]|$|\([a-z]+\))/,"-9":/^-(?=[0-9])/,"- orbital overlap":/^-(?=(?:[spd]|sp)(?:$|[\s,;\)\]\}]))/,"-":/^-/,"pm-operator":/^(?:\\pm|\$\\pm\$|\+-|\+\/-)/,operator:/^(?:\+|(?:[\-=<>]|<<|>>|\\approx|\$\\approx\$)(?=\s|$|-?[0-9]))/,arrowUpDown:/^(?:v|\(v\)|\^|\(\^\))(?=$|[\s,;\)\]\}])/,"\\bond{(...)}":function(t){return V.patterns.findObserveGroups(t,"\\bond{","","","}")},"->":/^(?:<->|<-->|->|<-|<=>>|<<=>|<=>|[\u2192\u27F6\u21CC])/,CMT:/^[CMT](?=\[)/,"[(...)]":function(t){return V.patterns.findObserveGroups(t,"[","","","]")},"1st-level escape":/^(&|\\\\|\\hline)\s*/,"\\,":/^(?:\\[,\ ;:])/,"\\x{}{}":function(t){return V.patterns.findObserveGroups(t,"",/^\\[a-zA-Z]+\{/,"}","","","{","}","",!0)},"\\x{}":function(t){return V.patterns.findObserveGroups(t,"",/^\\[a-zA-Z]+\{/,"}","")},"\\ca":/^\\ca(?:\s+|(?![a-zA-Z]))/,"\\x":/^(?:\\[a-zA-Z]+\s*|\\[_&{}%])/,orbital:/^(?:[0-9]{1,2}[spdfgh]|[0-9]{0,2}sp)(?=$|[^a-zA-Z])/,others:/^[\/~|]/,"\\frac{(...)}":function(t){return V.patterns.findObserveGroups(t,"\\frac{","","","}","{","","","}")},"\\overset{(...)}":function(t){return V.patterns.findObserveGroups(t,"\\overset{","","","}","{","","","}")},"\\underset{(...)}":function(t){return V.patterns.findObserveGroups(t,"\\underset{","","","}","{","","","}")},"\\underbrace{(...)}":function(t){return V.patterns.findObserveGroups(t,"\\underbrace{","","","}_","{","","","}")},"\\color{(...)}":function(t){return V.patterns.findObserveGroups(t,"\\color{","","","}")},"\\color{(...)}{(...)}":function(t){return V.patterns.findObserveGroups(t,"\\color{","","","}","{","","","}")||V.patterns.findObserveGroups(t,"\\color","\\","",/^(?=\{)/,"{","","","}")},"\\ce{(...)}":function(t){return V.patterns.findObserveGroups(t,"\\ce{","","","}")},"\\pu{(...)}":function(t){return V.patterns.findObserveGroups(t,"\\pu{","","","}")},oxidation$:/^(?:[+-][IVX]+|(?:\\pm|\$\\pm\$|\+-|\+\/-)\s*0)$/,"d-oxidation$":/^(?:[+-]?[IVX]+|(?:\\pm|\$\\pm\$|\+-|\+\/-)\s*0)$/,"1/2$":/^[+\-]?(?:[0-9]+|\$[a-z]\$|[a-z])\/[0-9]+(?:\$[a-z]\$|[a-z])?$/,amount:function(t){var r;if(r=t.match(/^(?:(?:(?:\([+\-]?[0-9]+\/[0-9]+\)|[+\-]?(?:[0-9]+|\$[a-z]\$|[a-z])\/[0-9]+|[+\-]?[0-9]+[.,][0-9]+|[+\-]?\.[0-9]+|[+\-]?[0-9]+)(?:[a-z](?=\s*[A-Z]))?)|[+\-]?[a-z](?=\s*[A-Z])|\+(?!\s))/),r)return{match_:r[0],remainder:t.substr(r[0].length)};var e=V.patterns.findObserveGroups(t,"","$","$","");return e&&(r=e.match_.match(/^\$(?:\(?[+\-]?(?:[0-9]*[a-z]?[+\-])?[0-9]*[a-z](?:[+\-][0-9]*[a-z]?)?\)?|\+|-)\$$/),r)?{match_:r[0],remainder:t.substr(r[0].length)}:null},amount2:function(t){return this.amount(t)},"(KV letters),":/^(?:[A-Z][a-z]{0,2}|i)(?=,)/,formula$:function(t){if(t.match(/^\([a-z]+\)$/))return null;var r=t.match(/^(?:[a-z]|(?:[0-9\ \+\-\,\.\(\)]+[a-z])+[0-9\ \+\-\,\.\(\)]*|(?:[a-z][0-9\ \+\-\,\.\(\)]+)+[a-z]?)$/);return r?{match_:r[0],remainder:t.substr(r[0].length)}:null},uprightEntities:/^(?:pH|pOH|pC|pK|iPr|iBu)(?=$|[^a-zA-Z])/,"/":/^\s*(\/)\s*/,"//":/^\s*(\/\/)\s*/,"*":/^\s*[*.]\s*/},findObserveGroups:function(t,r,e,Q,T,n,i,a,o,u){var l=function(x,H){if(typeof H=="string")return x.indexOf(H)!==0?null:H;var M=x.match(H);return M?M[0]:null},c=function(x,H,M){for(var _=0;H<x.length;){var b=x.charAt(H),W=l(x.substr(H),M);if(W!==null&&_===0)return{endMatchBegin:H,endMatchEnd:H+W.length};if(b==="{")_++;else if(b==="}"){if(_===0)throw["ExtraCloseMissingOpen","Extra close brace or missing open brace"];_--}H++}return _>0,null},f=l(t,r);if(f===null||(t=t.substr(f.length),f=l(t,e),f===null))return null;var h=c(t,f.length,Q||T);if(h===null)return null;var d=t.substring(0,Q?h.endMatchEnd:h.endMatchBegin);if(n||i){var L=this.findObserveGroups(t.substr(h.endMatchEnd),n,i,a,o);if(L===null)return null;var v=[d,L.match_];return{match_:u?v.join(""):v,remainder:L.remainder}}else return{match_:d,remainder:t.substr(h.endMatchEnd)}},match_:function(t,r){var e=V.patterns.patterns[t];if(e===void 0)throw["MhchemBugP","mhchem bug P. Please report. ("+t+")"];if(typeof e=="function")return V.patterns.patterns[t](r);var Q=r.match(e);return Q?Q.length>2?{match_:Q.slice(1),remainder:r.substr(Q[0].length)}:{match_:Q[1]||Q[0],remainder:r.substr(Q[0].length)}:null}},actions:{"a=":function(t,r){t.a=(t.a||"")+r},"b=":function(t,r){t.b=(t.b||"")+r},"p=":function(t,r){t.p=(t.p||"")+r},"o=":function(t,r){t.o=(t.o||"")+r},"o=+p1":function(t,r,e){t.o=(t.o||"")+e},"q=":function(t,r){t.q=(t.q||"")+r},"d=":function(t,r){t.d=(t.d||"")+r},"rm=":function(t,r){t.rm=(t.rm||"")+r},"text=":function(t,r){t.text_=(t.text_||"")+r},insert:function(t,r,e){return{type_:e}},"insert+p1":function(t,r,e){return{type_:e,p1:r}},"insert+p1+p2":function(t,r,e){return{type_:e,p1:r[0],p2:r[1]}},copy:function(t,r){return r},write:function(t,r,e){return e},rm:function(t,r){return{type_:"rm",p1:r}},text:function(t,r){return V.go(r,"text")},"tex-math":function(t,r){return V.go(r,"tex-math")},"tex-math tight":function(t,r){return V.go(r,"tex-math tight")},bond:function(t,r,e){return{type_:"bond",kind_:e||r}},"color0-output":function(t,r){return{type_:"color0",color:r}},ce:function(t,r){return V.go(r,"ce")},pu:function(t,r){return V.go(r,"pu")},"1/2":function(t,r){var e=[];r.match(/^[+\-]/)&&(e.push(r.substr(0,1)),r=r.substr(1));var Q=r.match(/^([0-9]+|\$[a-z]\$|[a-z])\/([0-9]+)(\$[a-z]\$|[a-z])?$/);return Q[1]=Q[1].replace(/\$/g,""),e.push({type_:"frac",p1:Q[1],p2:Q[2]}),Q[3]&&(Q[3]=Q[3].replace(/\$/g,""),e.push({type_:"tex-math",p1:Q[3]})),e},"9,9":function(t,r){return V.go(r,"9,9")}},stateMachines:{tex:{transitions:x2({empty:{0:{action_:"copy"}},"\\ce{(...)}":{0:{action_:[{type_:"write",option:"{"},"ce",{type_:"write",option:"}"}]}},"\\pu{(...)}":{0:{action_:[{type_:"write",option:"{"},"pu",{type_:"write",option:"}"}]}},else:{0:{action_:"copy"}}}),actions:{}},ce:{transitions:x2({empty:{"*":{action_:"output"}},else:{"0|1|2":{action_:"beginsWithBond=false",revisit:!0,toContinue:!0}},oxidation$:{0:{action_:"oxidation-output"}},CMT:{r:{action_:"rdt=",nextState:"rt"},rd:{action_:"rqt=",nextState:"rdt"}},arrowUpDown:{"0|1|2|as":{action_:["sb=false","output","operator"],nextState:"1"}},uprightEntities:{"0|1|2":{action_:["o=","output"],nextState:"1"}},orbital:{"0|1|2|3":{action_:"o=",nextState:"o"}},"->":{"0|1|2|3":{action_:"r=",nextState:"r"},"a|as":{action_:["output","r="],nextState:"r"},"*":{action_:["output","r="],nextState:"r"}},"+":{o:{action_:"d= kv",nextState:"d"},"d|D":{action_:"d=",nextState:"d"},q:{action_:"d=",nextState:"qd"},"qd|qD":{action_:"d=",nextState:"qd"},dq:{action_:["output","d="],nextState:"d"},3:{action_:["sb=false","output","operator"],nextState:"0"}},amount:{"0|2":{action_:"a=",nextState:"a"}},"pm-operator":{"0|1|2|a|as":{action_:["sb=false","output",{type_:"operator",option:"\\pm"}],nextState:"0"}},operator:{"0|1|2|a|as":{action_:["sb=false","output","operator"],nextState:"0"}},"-$":{"o|q":{action_:["charge or bond","output"],nextState:"qd"},d:{action_:"d=",nextState:"d"},D:{action_:["output",{type_:"bond",option:"-"}],nextState:"3"},q:{action_:"d=",nextState:"qd"},qd:{action_:"d=",nextState:"qd"},"qD|dq":{action_:["output",{type_:"bond",option:"-"}],nextState:"3"}},"-9":{"3|o":{action_:["output",{type_:"insert",option:"hyphen"}],nextState:"3"}},"- orbital overlap":{o:{action_:["output",{type_:"insert",option:"hyphen"}],nextState:"2"},d:{action_:["output",{type_:"insert",option:"hyphen"}],nextState:"2"}},"-":{"0|1|2":{action_:[{type_:"output",option:1},"beginsWithBond=true",{type_:"bond",option:"-"}],nextState:"3"},3:{action_:{type_:"bond",option:"-"}},a:{action_:["output",{type_:"insert",option:"hyphen"}],nextState:"2"},as:{action_:[{type_:"output",option:2},{type_:"bond",option:"-"}],nextState:"3"},b:{action_:"b="},o:{action_:{type_:"- after o/d",option:!1},nextState:"2"},q:{action_:{type_:"- after o/d",option:!1},nextState:"2"},"d|qd|dq":{action_:{type_:"- after o/d",option:!0},nextState:"2"},"D|qD|p":{action_:["output",{type_:"bond",option:"-"}],nextState:"3"}},amount2:{"1|3":{action_:"a=",nextState:"a"}},letters:{"0|1|2|3|a|as|b|p|bp|o":{action_:"o=",nextState:"o"},"q|dq":{action_:["output","o="],nextState:"o"},"d|D|qd|qD":{action_:"o after d",nextState:"o"}},digits:{o:{action_:"q=",nextState:"q"},"d|D":{action_:"q=",nextState:"dq"},q:{action_:["output","o="],nextState:"o"},a:{action_:"o=",nextState:"o"}},"space A":{"b|p|bp":{action_:[]}},space:{a:{action_:[],nextState:"as"},0:{action_:"sb=false"},"1|2":{action_:"sb=true"},"r|rt|rd|rdt|rdq":{action_:"output",nextState:"0"},"*":{action_:["output","sb=true"],nextState:"1"}},"1st-level escape":{"1|2":{action_:["output",{type_:"insert+p1",option:"1st-level escape"}]},"*":{action_:["output",{type_:"insert+p1",option:"1st-level escape"}],nextState:"0"}},"[(...)]":{"r|rt":{action_:"rd=",nextState:"rd"},"rd|rdt":{action_:"rq=",nextState:"rdq"}},"...":{"o|d|D|dq|qd|qD":{action_:["output",{type_:"bond",option:"..."}],nextState:"3"},"*":{action_:[{type_:"output",option:1},{type_:"insert",option:"ellipsis"}],nextState:"1"}},". __* ":{"*":{action_:["output",{type_:"insert",option:"addition compound"}],nextState:"1"}},"state of aggregation $":{"*":{action_:["output","state of aggregation"],nextState:"1"}},"{[(":{"a|as|o":{action_:["o=","output","parenthesisLevel++"],nextState:"2"},"0|1|2|3":{action_:["o=","output","parenthesisLevel++"],nextState:"2"},"*":{action_:["output","o=","output","parenthesisLevel++"],nextState:"2"}},")]}":{"0|1|2|3|b|p|bp|o":{action_:["o=","parenthesisLevel--"],nextState:"o"},"a|as|d|D|q|qd|qD|dq":{action_:["output","o=","parenthesisLevel--"],nextState:"o"}},", ":{"*":{action_:["output","comma"],nextState:"0"}},"^_":{"*":{action_:[]}},"^{(...)}|^($...$)":{"0|1|2|as":{action_:"b=",nextState:"b"},p:{action_:"b=",nextState:"bp"},"3|o":{action_:"d= kv",nextState:"D"},q:{action_:"d=",nextState:"qD"},"d|D|qd|qD|dq":{action_:["output","d="],nextState:"D"}},"^a|^\\x{}{}|^\\x{}|^\\x|'":{"0|1|2|as":{action_:"b=",nextState:"b"},p:{action_:"b=",nextState:"bp"},"3|o":{action_:"d= kv",nextState:"d"},q:{action_:"d=",nextState:"qd"},"d|qd|D|qD":{action_:"d="},dq:{action_:["output","d="],nextState:"d"}},"_{(state of aggregation)}$":{"d|D|q|qd|qD|dq":{action_:["output","q="],nextState:"q"}},"_{(...)}|_($...$)|_9|_\\x{}{}|_\\x{}|_\\x":{"0|1|2|as":{action_:"p=",nextState:"p"},b:{action_:"p=",nextState:"bp"},"3|o":{action_:"q=",nextState:"q"},"d|D":{action_:"q=",nextState:"dq"},"q|qd|qD|dq":{action_:["output","q="],nextState:"q"}},"=<>":{"0|1|2|3|a|as|o|q|d|D|qd|qD|dq":{action_:[{type_:"output",option:2},"bond"],nextState:"3"}},"#":{"0|1|2|3|a|as|o":{action_:[{type_:"output",option:2},{type_:"bond",option:"#"}],nextState:"3"}},"{}^":{"*":{action_:[{type_:"output",option:1},{type_:"insert",option:"tinySkip"}],nextState:"1"}},"{}":{"*":{action_:{type_:"output",option:1},nextState:"1"}},"{...}":{"0|1|2|3|a|as|b|p|bp":{action_:"o=",nextState:"o"},"o|d|D|q|qd|qD|dq":{action_:["output","o="],nextState:"o"}},"$...$":{a:{action_:"a="},"0|1|2|3|as|b|p|bp|o":{action_:"o=",nextState:"o"},"as|o":{action_:"o="},"q|d|D|qd|qD|dq":{action_:["output","o="],nextState:"o"}},"\\bond{(...)}":{"*":{action_:[{type_:"output",option:2},"bond"],nextState:"3"}},"\\frac{(...)}":{"*":{action_:[{type_:"output",option:1},"frac-output"],nextState:"3"}},"\\overset{(...)}":{"*":{action_:[{type_:"output",option:2},"overset-output"],nextState:"3"}},"\\underset{(...)}":{"*":{action_:[{type_:"output",option:2},"underset-output"],nextState:"3"}},"\\underbrace{(...)}":{"*":{action_:[{type_:"output",option:2},"underbrace-output"],nextState:"3"}},"\\color{(...)}{(...)}":{"*":{action_:[{type_:"output",option:2},"color-output"],nextState:"3"}},"\\color{(...)}":{"*":{action_:[{type_:"output",option:2},"color0-output"]}},"\\ce{(...)}":{"*":{action_:[{type_:"output",option:2},"ce"],nextState:"3"}},"\\,":{"*":{action_:[{type_:"output",option:1},"copy"],nextState:"1"}},"\\pu{(...)}":{"*":{action_:["output",{type_:"write",option:"{"},"pu",{type_:"write",option:"}"}],nextState:"3"}},"\\x{}{}|\\x{}|\\x":{"0|1|2|3|a|as|b|p|bp|o|c0":{action_:["o=","output"],nextState:"3"},"*":{action_:["output","o=","output"],nextState:"3"}},others:{"*":{action_:[{type_:"output",option:1},"copy"],nextState:"3"}},else2:{a:{action_:"a to o",nextState:"o",revisit:!0},as:{action_:["output","sb=true"],nextState:"1",revisit:!0},"r|rt|rd|rdt|rdq":{action_:["output"],nextState:"0",revisit:!0},"*":{action_:["output","copy"],nextState:"3"}}}),actions:{"o after d":function(t,r){var e;if((t.d||"").match(/^[1-9][0-9]*$/)){var Q=t.d;t.d=void 0,e=this.output(t),e.push({type_:"tinySkip"}),t.b=Q}else e=this.output(t);return V.actions["o="](t,r),e},"d= kv":function(t,r){t.d=r,t.dType="kv"},"charge or bond":function(t,r){if(t.beginsWithBond){var e=[];return V.concatArray(e,this.output(t)),V.concatArray(e,V.actions.bond(t,r,"-")),e}else{t.d=r;return}},"- after o/d":function(t,r,e){var Q=V.patterns.match_("orbital",t.o||""),T=V.patterns.match_("one lowercase greek letter $",t.o||""),n=V.patterns.match_("one lowercase latin letter $",t.o||""),i=V.patterns.match_("$one lowercase latin letter$ $",t.o||""),a=r==="-"&&(Q&&Q.remainder===""||T||n||i);a&&!t.a&&!t.b&&!t.p&&!t.d&&!t.q&&!Q&&n&&(t.o="$"+t.o+"$");var o=[];return a?(V.concatArray(o,this.output(t)),o.push({type_:"hyphen"})):(Q=V.patterns.match_("digits",t.d||""),e&&Q&&Q.remainder===""?(V.concatArray(o,V.actions["d="](t,r)),V.concatArray(o,this.output(t))):(V.concatArray(o,this.output(t)),V.concatArray(o,V.actions.bond(t,r,"-")))),o},"a to o":function(t){t.o=t.a,t.a=void 0},"sb=true":function(t){t.sb=!0},"sb=false":function(t){t.sb=!1},"beginsWithBond=true":function(t){t.beginsWithBond=!0},"beginsWithBond=false":function(t){t.beginsWithBond=!1},"parenthesisLevel++":function(t){t.parenthesisLevel++},"parenthesisLevel--":function(t){t.parenthesisLevel--},"state of aggregation":function(t,r){return{type_:"state of aggregation",p1:V.go(r,"o")}},comma:function(t,r){var e=r.replace(/\s*$/,""),Q=e!==r;return Q&&t.parenthesisLevel===0?{type_:"comma enumeration L",p1:e}:{type_:"comma enumeration M",p1:e}},output:function(t,r,e){var Q;if(!t.r)Q=[],!t.a&&!t.b&&!t.p&&!t.o&&!t.q&&!t.d&&!e||(t.sb&&Q.push({type_:"entitySkip"}),!t.o&&!t.q&&!t.d&&!t.b&&!t.p&&e!==2?(t.o=t.a,t.a=void 0):!t.o&&!t.q&&!t.d&&(t.b||t.p)?(t.o=t.a,t.d=t.b,t.q=t.p,t.a=t.b=t.p=void 0):t.o&&t.dType==="kv"&&V.patterns.match_("d-oxidation$",t.d||"")?t.dType="oxidation":t.o&&t.dType==="kv"&&!t.q&&(t.dType=void 0),Q.push({type_:"chemfive",a:V.go(t.a,"a"),b:V.go(t.b,"bd"),p:V.go(t.p,"pq"),o:V.go(t.o,"o"),q:V.go(t.q,"pq"),d:V.go(t.d,t.dType==="oxidation"?"oxidation":"bd"),dType:t.dType}));else{var T=void 0;t.rdt==="M"?T=V.go(t.rd,"tex-math"):t.rdt==="T"?T=[{type_:"text",p1:t.rd||""}]:T=V.go(t.rd,"ce");var n=void 0;t.rqt==="M"?n=V.go(t.rq,"tex-math"):t.rqt==="T"?n=[{type_:"text",p1:t.rq||""}]:n=V.go(t.rq,"ce"),Q={type_:"arrow",r:t.r,rd:T,rq:n}}for(var i in t)i!=="parenthesisLevel"&&i!=="beginsWithBond"&&delete t[i];return Q},"oxidation-output":function(t,r){var e=["{"];return V.concatArray(e,V.go(r,"oxidation")),e.push("}"),e},"frac-output":function(t,r){return{type_:"frac-ce",p1:V.go(r[0],"ce"),p2:V.go(r[1],"ce")}},"overset-output":function(t,r){return{type_:"overset",p1:V.go(r[0],"ce"),p2:V.go(r[1],"ce")}},"underset-output":function(t,r){return{type_:"underset",p1:V.go(r[0],"ce"),p2:V.go(r[1],"ce")}},"underbrace-output":function(t,r){return{type_:"underbrace",p1:V.go(r[0],"ce"),p2:V.go(r[1],"ce")}},"color-output":function(t,r){return{type_:"color",color1:r[0],color2:V.go(r[1],"ce")}},"r=":function(t,r){t.r=r},"rdt=":function(t,r){t.rdt=r},"rd=":function(t,r){t.rd=r},"rqt=":function(t,r){t.rqt=r},"rq=":function(t,r){t.rq=r},operator:function(t,r,e){return{type_:"operator",kind_:e||r}}}},a:{transitions:x2({empty:{"*":{action_:[]}},"1/2$":{0:{action_:"1/2"}},else:{0:{action_:[],nextState:"1",revisit:!0}},"${(...)}$__$(...)$":{"*":{action_:"tex-math tight",nextState:"1"}},",":{"*":{action_:{type_:"insert",option:"commaDecimal"}}},else2:{"*":{action_:"copy"}}}),actions:{}},o:{transitions:x2({empty:{"*":{action_:[]}},"1/2$":{0:{action_:"1/2"}},else:{0:{action_:[],nextState:"1",revisit:!0}},letters:{"*":{action_:"rm"}},"\\ca":{"*":{action_:{type_:"insert",option:"circa"}}},"\\pu{(...)}":{"*":{action_:[{type_:"write",option:"{"},"pu",{type_:"write",option:"}"}]}},"\\x{}{}|\\x{}|\\x":{"*":{action_:"copy"}},"${(...)}$__$(...)$":{"*":{action_:"tex-math"}},"{(...)}":{"*":{action_:[{type_:"write",option:"{"},"text",{type_:"write",option:"}"}]}},else2:{"*":{action_:"copy"}}}),actions:{}},text:{transitions:x2({empty:{"*":{action_:"output"}},"{...}":{"*":{action_:"text="}},"${(...)}$__$(...)$":{"*":{action_:"tex-math"}},"\\greek":{"*":{action_:["output","rm"]}},"\\pu{(...)}":{"*":{action_:["output",{type_:"write",option:"{"},"pu",{type_:"write",option:"}"}]}},"\\,|\\x{}{}|\\x{}|\\x":{"*":{action_:["output","copy"]}},else:{"*":{action_:"text="}}}),actions:{output:function(t){if(t.text_){var r={type_:"text",p1:t.text_};for(var e in t)delete t[e];return r}}}},pq:{transitions:x2({empty:{"*":{action_:[]}},"state of aggregation $":{"*":{action_:"state of aggregation"}},i$:{0:{action_:[],nextState:"!f",revisit:!0}},"(KV letters),":{0:{action_:"rm",nextState:"0"}},formula$:{0:{action_:[],nextState:"f",revisit:!0}},"1/2$":{0:{action_:"1/2"}},else:{0:{action_:[],nextState:"!f",revisit:!0}},"${(...)}$__$(...)$":{"*":{action_:"tex-math"}},"{(...)}":{"*":{action_:"text"}},"a-z":{f:{action_:"tex-math"}},letters:{"*":{action_:"rm"}},"-9.,9":{"*":{action_:"9,9"}},",":{"*":{action_:{type_:"insert+p1",option:"comma enumeration S"}}},"\\color{(...)}{(...)}":{"*":{action_:"color-output"}},"\\color{(...)}":{"*":{action_:"color0-output"}},"\\ce{(...)}":{"*":{action_:"ce"}},"\\pu{(...)}":{"*":{action_:[{type_:"write",option:"{"},"pu",{type_:"write",option:"}"}]}},"\\,|\\x{}{}|\\x{}|\\x":{"*":{action_:"copy"}},else2:{"*":{action_:"copy"}}}),actions:{"state of aggregation":function(t,r){return{type_:"state of aggregation subscript",p1:V.go(r,"o")}},"color-output":function(t,r){return{type_:"color",color1:r[0],color2:V.go(r[1],"pq")}}}},bd:{transitions:x2({empty:{"*":{action_:[]}},x$:{0:{action_:[],nextState:"!f",revisit:!0}},formula$:{0:{action_:[],nextState:"f",revisit:!0}},else:{0:{action_:[],nextState:"!f",revisit:!0}},"-9.,9 no missing 0":{"*":{action_:"9,9"}},".":{"*":{action_:{type_:"insert",option:"electron dot"}}},"a-z":{f:{action_:"tex-math"}},x:{"*":{action_:{type_:"insert",option:"KV x"}}},letters:{"*":{action_:"rm"}},"'":{"*":{action_:{type_:"insert",option:"prime"}}},"${(...)}$__$(...)$":{"*":{action_:"tex-math"}},"{(...)}":{"*":{action_:"text"}},"\\color{(...)}{(...)}":{"*":{action_:"color-output"}},"\\color{(...)}":{"*":{action_:"color0-output"}},"\\ce{(...)}":{"*":{action_:"ce"}},"\\pu{(...)}":{"*":{action_:[{type_:"write",option:"{"},"pu",{type_:"write",option:"}"}]}},"\\,|\\x{}{}|\\x{}|\\x":{"*":{action_:"copy"}},else2:{"*":{action_:"copy"}}}),actions:{"color-output":function(t,r){return{type_:"color",color1:r[0],color2:V.go(r[1],"bd")}}}},oxidation:{transitions:x2({empty:{"*":{action_:"roman-numeral"}},"pm-operator":{"*":{action_:{type_:"o=+p1",option:"\\pm"}}},else:{"*":{action_:"o="}}}),actions:{"roman-numeral":function(t){return{type_:"roman numeral",p1:t.o||""}}}},"tex-math":{transitions:x2({empty:{"*":{action_:"output"}},"\\ce{(...)}":{"*":{action_:["output","ce"]}},"\\pu{(...)}":{"*":{action_:["output",{type_:"write",option:"{"},"pu",{type_:"write",option:"}"}]}},"{...}|\\,|\\x{}{}|\\x{}|\\x":{"*":{action_:"o="}},else:{"*":{action_:"o="}}}),actions:{output:function(t){if(t.o){var r={type_:"tex-math",p1:t.o};for(var e in t)delete t[e];return r}}}},"tex-math tight":{transitions:x2({empty:{"*":{action_:"output"}},"\\ce{(...)}":{"*":{action_:["output","ce"]}},"\\pu{(...)}":{"*":{action_:["output",{type_:"write",option:"{"},"pu",{type_:"write",option:"}"}]}},"{...}|\\,|\\x{}{}|\\x{}|\\x":{"*":{action_:"o="}},"-|+":{"*":{action_:"tight operator"}},else:{"*":{action_:"o="}}}),actions:{"tight operator":function(t,r){t.o=(t.o||"")+"{"+r+"}"},output:function(t){if(t.o){var r={type_:"tex-math",p1:t.o};for(var e in t)delete t[e];return r}}}},"9,9":{transitions:x2({empty:{"*":{action_:[]}},",":{"*":{action_:"comma"}},else:{"*":{action_:"copy"}}}),actions:{comma:function(){return{type_:"commaDecimal"}}}},pu:{transitions:x2({empty:{"*":{action_:"output"}},space$:{"*":{action_:["output","space"]}},"{[(|)]}":{"0|a":{action_:"copy"}},"(-)(9)^(-9)":{0:{action_:"number^",nextState:"a"}},"(-)(9.,9)(e)(99)":{0:{action_:"enumber",nextState:"a"}},space:{"0|a":{action_:[]}},"pm-operator":{"0|a":{action_:{type_:"operator",option:"\\pm"},nextState:"0"}},operator:{"0|a":{action_:"copy",nextState:"0"}},"//":{d:{action_:"o=",nextState:"/"}},"/":{d:{action_:"o=",nextState:"/"}},"{...}|else":{"0|d":{action_:"d=",nextState:"d"},a:{action_:["space","d="],nextState:"d"},"/|q":{action_:"q=",nextState:"q"}}}),actions:{enumber:function(t,r){var e=[];return r[0]==="+-"||r[0]==="+/-"?e.push("\\pm "):r[0]&&e.push(r[0]),r[1]&&(V.concatArray(e,V.go(r[1],"pu-9,9")),r[2]&&(r[2].match(/[,.]/)?V.concatArray(e,V.go(r[2],"pu-9,9")):e.push(r[2])),(r[3]||r[4])&&(r[3]==="e"||r[4]==="*"?e.push({type_:"cdot"}):e.push({type_:"times"}))),r[5]&&e.push("10^{"+r[5]+"}"),e},"number^":function(t,r){var e=[];return r[0]==="+-"||r[0]==="+/-"?e.push("\\pm "):r[0]&&e.push(r[0]),V.concatArray(e,V.go(r[1],"pu-9,9")),e.push("^{"+r[2]+"}"),e},operator:function(t,r,e){return{type_:"operator",kind_:e||r}},space:function(){return{type_:"pu-space-1"}},output:function(t){var r,e=V.patterns.match_("{(...)}",t.d||"");e&&e.remainder===""&&(t.d=e.match_);var Q=V.patterns.match_("{(...)}",t.q||"");if(Q&&Q.remainder===""&&(t.q=Q.match_),t.d&&(t.d=t.d.replace(/\u00B0C|\^oC|\^{o}C/g,"{}^{\\circ}C"),t.d=t.d.replace(/\u00B0F|\^oF|\^{o}F/g,"{}^{\\circ}F")),t.q){t.q=t.q.replace(/\u00B0C|\^oC|\^{o}C/g,"{}^{\\circ}C"),t.q=t.q.replace(/\u00B0F|\^oF|\^{o}F/g,"{}^{\\circ}F");var T={d:V.go(t.d,"pu"),q:V.go(t.q,"pu")};t.o==="//"?r={type_:"pu-frac",p1:T.d,p2:T.q}:(r=T.d,T.d.length>1||T.q.length>1?r.push({type_:" / "}):r.push({type_:"/"}),V.concatArray(r,T.q))}else r=V.go(t.d,"pu-2");for(var n in t)delete t[n];return r}}},"pu-2":{transitions:x2({empty:{"*":{action_:"output"}},"*":{"*":{action_:["output","cdot"],nextState:"0"}},"\\x":{"*":{action_:"rm="}},space:{"*":{action_:["output","space"],nextState:"0"}},"^{(...)}|^(-1)":{1:{action_:"^(-1)"}},"-9.,9":{0:{action_:"rm=",nextState:"0"},1:{action_:"^(-1)",nextState:"0"}},"{...}|else":{"*":{action_:"rm=",nextState:"1"}}}),actions:{cdot:function(){return{type_:"tight cdot"}},"^(-1)":function(t,r){t.rm+="^{"+r+"}"},space:function(){return{type_:"pu-space-2"}},output:function(t){var r=[];if(t.rm){var e=V.patterns.match_("{(...)}",t.rm||"");e&&e.remainder===""?r=V.go(e.match_,"pu"):r={type_:"rm",p1:t.rm}}for(var Q in t)delete t[Q];return r}}},"pu-9,9":{transitions:x2({empty:{0:{action_:"output-0"},o:{action_:"output-o"}},",":{0:{action_:["output-0","comma"],nextState:"o"}},".":{0:{action_:["output-0","copy"],nextState:"o"}},else:{"*":{action_:"text="}}}),actions:{comma:function(){return{type_:"commaDecimal"}},"output-0":function(t){var r=[];if(t.text_=t.text_||"",t.text_.length>4){var e=t.text_.length%3;e===0&&(e=3);for(var Q=t.text_.length-3;Q>0;Q-=3)r.push(t.text_.substr(Q,3)),r.push({type_:"1000 separator"});r.push(t.text_.substr(0,e)),r.reverse()}else r.push(t.text_);for(var T in t)delete t[T];return r},"output-o":function(t){var r=[];if(t.text_=t.text_||"",t.text_.length>4){var e=t.text_.length-3,Q=void 0;for(Q=0;Q<e;Q+=3)r.push(t.text_.substr(Q,3)),r.push({type_:"1000 separator"});r.push(t.text_.substr(Q))}else r.push(t.text_);for(var T in t)delete t[T];return r}}}}},v1={go:function(t,r){if(!t)return"";for(var e="",Q=!1,T=0;T<t.length;T++){var n=t[T];typeof n=="string"?e+=n:(e+=v1._go2(n),n.type_==="1st-level escape"&&(Q=!0))}return r&&!Q&&e&&(e="{"+e+"}"),e},_goInner:function(t){return v1.go(t,!1)},_go2:function(t){var r;switch(t.type_){case"chemfive":r="";var e={a:v1._goInner(t.a),b:v1._goInner(t.b),p:v1._goInner(t.p),o:v1._goInner(t.o),q:v1._goInner(t.q),d:v1._goInner(t.d)};e.a&&(e.a.match(/^[+\-]/)&&(e.a="{"+e.a+"}"),r+=e.a+"\\,"),(e.b||e.p)&&(r+="{\\vphantom{A}}",r+="^{\\hphantom{"+(e.b||"")+"}}_{\\hphantom{"+(e.p||"")+"}}",r+="\\mkern-1.5mu",r+="{\\vphantom{A}}",r+="^{\\smash[t]{\\vphantom{2}}\\llap{"+(e.b||"")+"}}",r+="_{\\vphantom{2}\\llap{\\smash[t]{"+(e.p||"")+"}}}"),e.o&&(e.o.match(/^[+\-]/)&&(e.o="{"+e.o+"}"),r+=e.o),t.dType==="kv"?((e.d||e.q)&&(r+="{\\vphantom{A}}"),e.d&&(r+="^{"+e.d+"}"),e.q&&(r+="_{\\smash[t]{"+e.q+"}}")):t.dType==="oxidation"?(e.d&&(r+="{\\vphantom{A}}",r+="^{"+e.d+"}"),e.q&&(r+="{\\vphantom{A}}",r+="_{\\smash[t]{"+e.q+"}}")):(e.q&&(r+="{\\vphantom{A}}",r+="_{\\smash[t]{"+e.q+"}}"),e.d&&(r+="{\\vphantom{A}}",r+="^{"+e.d+"}"));break;case"rm":r="\\mathrm{"+t.p1+"}";break;case"text":t.p1.match(/[\^_]/)?(t.p1=t.p1.replace(" ","~").replace("-","\\text{-}"),r="\\mathrm{"+t.p1+"}"):r="\\text{"+t.p1+"}";break;case"roman numeral":r="\\mathrm{"+t.p1+"}";break;case"state of aggregation":r="\\mskip2mu "+v1._goInner(t.p1);break;case"state of aggregation subscript":r="\\mskip1mu "+v1._goInner(t.p1);break;case"bond":if(r=v1._getBond(t.kind_),!r)throw["MhchemErrorBond","mhchem Error. Unknown bond type ("+t.kind_+")"];break;case"frac":var Q="\\frac{"+t.p1+"}{"+t.p2+"}";r="\\mathchoice{\\textstyle"+Q+"}{"+Q+"}{"+Q+"}{"+Q+"}";break;case"pu-frac":var T="\\frac{"+v1._goInner(t.p1)+"}{"+v1._goInner(t.p2)+"}";r="\\mathchoice{\\textstyle"+T+"}{"+T+"}{"+T+"}{"+T+"}";break;case"tex-math":r=t.p1+" ";break;case"frac-ce":r="\\frac{"+v1._goInner(t.p1)+"}{"+v1._goInner(t.p2)+"}";break;case"overset":r="\\overset{"+v1._goInner(t.p1)+"}{"+v1._goInner(t.p2)+"}";break;case"underset":r="\\underset{"+v1._goInner(t.p1)+"}{"+v1._goInner(t.p2)+"}";break;case"underbrace":r="\\underbrace{"+v1._goInner(t.p1)+"}_{"+v1._goInner(t.p2)+"}";break;case"color":r="{\\color{"+t.color1+"}{"+v1._goInner(t.color2)+"}}";break;case"color0":r="\\color{"+t.color+"}";break;case"arrow":var n={rd:v1._goInner(t.rd),rq:v1._goInner(t.rq)},i=v1._getArrow(t.r);n.rd||n.rq?t.r==="<=>"||t.r==="<=>>"||t.r==="<<=>"||t.r==="<-->"?(i="\\long"+i,n.rd&&(i="\\overset{"+n.rd+"}{"+i+"}"),n.rq&&(t.r==="<-->"?i="\\underset{\\lower2mu{"+n.rq+"}}{"+i+"}":i="\\underset{\\lower6mu{"+n.rq+"}}{"+i+"}"),i=" {}\\mathrel{"+i+"}{} "):(n.rq&&(i+="[{"+n.rq+"}]"),i+="{"+n.rd+"}",i=" {}\\mathrel{\\x"+i+"}{} "):i=" {}\\mathrel{\\long"+i+"}{} ",r=i;break;case"operator":r=v1._getOperator(t.kind_);break;case"1st-level escape":r=t.p1+" ";break;case"space":r=" ";break;case"tinySkip":r="\\mkern2mu";break;case"entitySkip":r="~";break;case"pu-space-1":r="~";break;case"pu-space-2":r="\\mkern3mu ";break;case"1000 separator":r="\\mkern2mu ";break;case"commaDecimal":r="{,}";break;case"comma enumeration L":r="{"+t.p1+"}\\mkern6mu ";break;case"comma enumeration M":r="{"+t.p1+"}\\mkern3mu ";break;case"comma enumeration S":r="{"+t.p1+"}\\mkern1mu ";break;case"hyphen":r="\\text{-}";break;case"addition compound":r="\\,{\\cdot}\\,";break;case"electron dot":r="\\mkern1mu \\bullet\\mkern1mu ";break;case"KV x":r="{\\times}";break;case"prime":r="\\prime ";break;case"cdot":r="\\cdot ";break;case"tight cdot":r="\\mkern1mu{\\cdot}\\mkern1mu ";break;case"times":r="\\times ";break;case"circa":r="{\\sim}";break;case"^":r="uparrow";break;case"v":r="downarrow";break;case"ellipsis":r="\\ldots ";break;case"/":r="/";break;case" / ":r="\\,/\\,";break;default:throw["MhchemBugT","mhchem bug T. Please report."]}return r},_getArrow:function(t){switch(t){case"->":return"rightarrow";case"\u2192":return"rightarrow";case"\u27F6":return"rightarrow";case"<-":return"leftarrow";case"<->":return"leftrightarrow";case"<-->":return"leftrightarrows";case"<=>":return"rightleftharpoons";case"\u21CC":return"rightleftharpoons";case"<=>>":return"Rightleftharpoons";case"<<=>":return"Leftrightharpoons";default:throw["MhchemBugT","mhchem bug T. Please report."]}},_getBond:function(t){switch(t){case"-":return"{-}";case"1":return"{-}";case"=":return"{=}";case"2":return"{=}";case"#":return"{\\equiv}";case"3":return"{\\equiv}";case"~":return"{\\tripledash}";case"~-":return"{\\rlap{\\lower.1em{-}}\\raise.1em{\\tripledash}}";case"~=":return"{\\rlap{\\lower.2em{-}}\\rlap{\\raise.2em{\\tripledash}}-}";case"~--":return"{\\rlap{\\lower.2em{-}}\\rlap{\\raise.2em{\\tripledash}}-}";case"-~-":return"{\\rlap{\\lower.2em{-}}\\rlap{\\raise.2em{-}}\\tripledash}";case"...":return"{{\\cdot}{\\cdot}{\\cdot}}";case"....":return"{{\\cdot}{\\cdot}{\\cdot}{\\cdot}}";case"->":return"{\\rightarrow}";case"<-":return"{\\leftarrow}";case"<":return"{<}";case">":return"{>}";default:throw["MhchemBugT","mhchem bug T. Please report."]}},_getOperator:function(t){switch(t){case"+":return" {}+{} ";case"-":return" {}-{} ";case"=":return" {}={} ";case"<":return" {}<{} ";case">":return" {}>{} ";case"<<":return" {}\\ll{} ";case">>":return" {}\\gg{} ";case"\\pm":return" {}\\pm{} ";case"\\approx":return" {}\\approx{} ";case"$\\approx$":return" {}\\approx{} ";case"v":return" \\downarrow{} ";case"(v)":return" \\downarrow{} ";case"^":return" \\uparrow{} ";case"(^)":return" \\uparrow{} ";default:throw["MhchemBugT","mhchem bug T. Please report."]}}}});var Uc=p(H0=>{"use strict";var zc=H0&&H0.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(H0,"__esModule",{value:!0});H0.MhchemConfiguration=void 0;var QD=i1(),TD=T1(),nD=zc(l1()),iD=zc(M2()),aD=h0(),oD=Xc(),mt={};mt.Macro=iD.default.Macro;mt.xArrow=aD.AmsMethods.xArrow;mt.Machine=function(t,r,e){var Q=t.GetArgument(r),T;try{T=oD.mhchemParser.toTex(Q,e)}catch(n){throw new nD.default(n[0],n[1])}t.string=T+t.string.substr(t.i),t.i=0};new TD.CommandMap("mhchem",{ce:["Machine","ce"],pu:["Machine","pu"],longrightleftharpoons:["Macro","\\stackrel{\\textstyle{-}\\!\\!{\\rightharpoonup}}{\\smash{{\\leftharpoondown}\\!\\!{-}}}"],longRightleftharpoons:["Macro","\\stackrel{\\textstyle{-}\\!\\!{\\rightharpoonup}}{\\smash{\\leftharpoondown}}"],longLeftrightharpoons:["Macro","\\stackrel{\\textstyle\\vphantom{{-}}{\\rightharpoonup}}{\\smash{{\\leftharpoondown}\\!\\!{-}}}"],longleftrightarrows:["Macro","\\stackrel{\\longrightarrow}{\\smash{\\longleftarrow}\\Rule{0px}{.25em}{0px}}"],tripledash:["Macro","\\vphantom{-}\\raise2mu{\\kern2mu\\tiny\\text{-}\\kern1mu\\text{-}\\kern1mu\\text{-}\\kern2mu}"],xleftrightarrow:["xArrow",8596,6,6],xrightleftharpoons:["xArrow",8652,5,7],xRightleftharpoons:["xArrow",8652,5,7],xLeftrightharpoons:["xArrow",8652,5,7]},mt);H0.MhchemConfiguration=QD.Configuration.create("mhchem",{handler:{macro:["mhchem"]}})});var $c=p(gt=>{"use strict";Object.defineProperty(gt,"__esModule",{value:!0});gt.NoErrorsConfiguration=void 0;var sD=i1();function uD(t,r,e,Q){var T=t.create("token","mtext",{},Q.replace(/\n/g," ")),n=t.create("node","merror",[T],{"data-mjx-error":r,title:r});return n}gt.NoErrorsConfiguration=sD.Configuration.create("noerrors",{nodes:{error:uD}})});var Kc=p(y0=>{"use strict";var lD=y0&&y0.__values||function(t){var r=typeof Symbol=="function"&&Symbol.iterator,e=r&&t[r],Q=0;if(e)return e.call(t);if(t&&typeof t.length=="number")return{next:function(){return t&&Q>=t.length&&(t=void 0),{value:t&&t[Q++],done:!t}}};throw new TypeError(r?"Object is not iterable.":"Symbol.iterator is not defined.")};Object.defineProperty(y0,"__esModule",{value:!0});y0.NoUndefinedConfiguration=void 0;var cD=i1();function fD(t,r){var e,Q,T=t.create("text","\\"+r),n=t.options.noundefined||{},i={};try{for(var a=lD(["color","background","size"]),o=a.next();!o.done;o=a.next()){var u=o.value;n[u]&&(i["math"+u]=n[u])}}catch(l){e={error:l}}finally{try{o&&!o.done&&(Q=a.return)&&Q.call(a)}finally{if(e)throw e.error}}t.Push(t.create("node","mtext",[],i,T))}y0.NoUndefinedConfiguration=cD.Configuration.create("noundefined",{fallback:{macro:fD},options:{noundefined:{color:"red",background:"",size:""}},priority:3})});var Yc=p(m6=>{"use strict";var hD=m6&&m6.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})(),dT=m6&&m6.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(m6,"__esModule",{value:!0});m6.AutoOpen=void 0;var Jc=D6(),dD=dT(h1()),LD=dT(H1()),pD=dT(W1()),vD=(function(t){hD(r,t);function r(){var e=t!==null&&t.apply(this,arguments)||this;return e.openCount=0,e}return Object.defineProperty(r.prototype,"kind",{get:function(){return"auto open"},enumerable:!1,configurable:!0}),Object.defineProperty(r.prototype,"isOpen",{get:function(){return!0},enumerable:!1,configurable:!0}),r.prototype.toMml=function(){var e=this.factory.configuration.parser,Q=this.getProperty("right");if(this.getProperty("smash")){var T=t.prototype.toMml.call(this),n=e.create("node","mpadded",[T],{height:0,depth:0});this.Clear(),this.Push(e.create("node","TeXAtom",[n]))}Q&&this.Push(new pD.default(Q,e.stack.env,e.configuration).mml());var i=dD.default.fenced(this.factory.configuration,this.getProperty("open"),t.prototype.toMml.call(this),this.getProperty("close"),this.getProperty("big"));return LD.default.removeProperties(i,"open","close","texClass"),i},r.prototype.checkItem=function(e){if(e.isKind("mml")&&e.Size()===1){var Q=e.toMml();Q.isKind("mo")&&Q.getText()===this.getProperty("open")&&this.openCount++}var T=e.getProperty("autoclose");return T&&T===this.getProperty("close")&&!this.openCount--?this.getProperty("ignore")?(this.Clear(),[[],!0]):[[this.toMml()],!0]:t.prototype.checkItem.call(this,e)},r.errors=Object.assign(Object.create(Jc.BaseItem.errors),{stop:["ExtraOrMissingDelims","Extra open or missing close delimiter"]}),r})(Jc.BaseItem);m6.AutoOpen=vD});var Tf=p(X6=>{"use strict";var xD=X6&&X6.__read||function(t,r){var e=typeof Symbol=="function"&&t[Symbol.iterator];if(!e)return t;var Q=e.call(t),T,n=[],i;try{for(;(r===void 0||r-- >0)&&!(T=Q.next()).done;)n.push(T.value)}catch(a){i={error:a}}finally{try{T&&!T.done&&(e=Q.return)&&e.call(Q)}finally{if(i)throw i.error}}return n},L7=X6&&X6.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(X6,"__esModule",{value:!0});var pT=L7(M2()),Z1=L7(W1()),m0=L7(l1()),vT=R(),d7=L7(h1()),LT=L7(H1()),HD=D7(),c1={},tf={"(":")","[":"]","{":"}","|":"|"},ef=/^(b|B)i(g{1,2})$/;c1.Quantity=function(t,r,e,Q,T,n,i){e===void 0&&(e="("),Q===void 0&&(Q=")"),T===void 0&&(T=!1),n===void 0&&(n=""),i===void 0&&(i="");var a=T?t.GetStar():!1,o=t.GetNext(),u=t.i,l=null;if(o==="\\"){if(t.i++,l=t.GetCS(),!l.match(ef)){var c=t.create("node","mrow");t.Push(d7.default.fenced(t.configuration,e,c,Q)),t.i=u;return}o=t.GetNext()}var f=tf[o];if(T&&o!=="{")throw new m0.default("MissingArgFor","Missing argument for %1",t.currentCS);if(!f){var c=t.create("node","mrow");t.Push(d7.default.fenced(t.configuration,e,c,Q)),t.i=u;return}if(n){var h=t.create("token","mi",{texClass:vT.TEXCLASS.OP},n);i&&LT.default.setAttribute(h,"mathvariant",i),t.Push(t.itemFactory.create("fn",h))}if(o==="{"){var d=t.GetArgument(r);o=T?e:"\\{",f=T?Q:"\\}",d=a?o+" "+d+" "+f:l?"\\"+l+"l"+o+" "+d+" \\"+l+"r"+f:"\\left"+o+" "+d+" \\right"+f,t.Push(new Z1.default(d,t.stack.env,t.configuration).mml());return}T&&(o=e,f=Q),t.i++,t.Push(t.itemFactory.create("auto open").setProperties({open:o,close:f,big:l}))};c1.Eval=function(t,r){var e=t.GetStar(),Q=t.GetNext();if(Q==="{"){var T=t.GetArgument(r),n="\\left. "+(e?"\\smash{"+T+"}":T)+" \\vphantom{\\int}\\right|";t.string=t.string.slice(0,t.i)+n+t.string.slice(t.i);return}if(Q==="("||Q==="["){t.i++,t.Push(t.itemFactory.create("auto open").setProperties({open:Q,close:"|",smash:e,right:"\\vphantom{\\int}"}));return}throw new m0.default("MissingArgFor","Missing argument for %1",t.currentCS)};c1.Commutator=function(t,r,e,Q){e===void 0&&(e="["),Q===void 0&&(Q="]");var T=t.GetStar(),n=t.GetNext(),i=null;if(n==="\\"){if(t.i++,i=t.GetCS(),!i.match(ef))throw new m0.default("MissingArgFor","Missing argument for %1",t.currentCS);n=t.GetNext()}if(n!=="{")throw new m0.default("MissingArgFor","Missing argument for %1",t.currentCS);var a=t.GetArgument(r),o=t.GetArgument(r),u=a+","+o;u=T?e+" "+u+" "+Q:i?"\\"+i+"l"+e+" "+u+" \\"+i+"r"+Q:"\\left"+e+" "+u+" \\right"+Q,t.Push(new Z1.default(u,t.stack.env,t.configuration).mml())};var yD=[65,90],mD=[97,122],gD=[913,937],MD=[945,969],VD=[48,57];function h7(t,r){return t>=r[0]&&t<=r[1]}function _D(t,r,e,Q){var T=t.configuration.parser,n=HD.NodeFactory.createToken(t,r,e,Q),i=Q.codePointAt(0);return Q.length===1&&!T.stack.env.font&&T.stack.env.vectorFont&&(h7(i,yD)||h7(i,mD)||h7(i,gD)||h7(i,VD)||h7(i,MD)&&T.stack.env.vectorStar||LT.default.getAttribute(n,"accent"))&&LT.default.setAttribute(n,"mathvariant",T.stack.env.vectorFont),n}c1.VectorBold=function(t,r){var e=t.GetStar(),Q=t.GetArgument(r),T=t.configuration.nodeFactory.get("token"),n=t.stack.env.font;delete t.stack.env.font,t.configuration.nodeFactory.set("token",_D),t.stack.env.vectorFont=e?"bold-italic":"bold",t.stack.env.vectorStar=e;var i=new Z1.default(Q,t.stack.env,t.configuration).mml();n&&(t.stack.env.font=n),delete t.stack.env.vectorFont,delete t.stack.env.vectorStar,t.configuration.nodeFactory.set("token",T),t.Push(i)};c1.StarMacro=function(t,r,e){for(var Q=[],T=3;T<arguments.length;T++)Q[T-3]=arguments[T];var n=t.GetStar(),i=[];if(e)for(var a=i.length;a<e;a++)i.push(t.GetArgument(r));var o=Q.join(n?"*":"");o=d7.default.substituteArgs(t,i,o),t.string=d7.default.addArgs(t,o,t.string.slice(t.i)),t.i=0,d7.default.checkMaxMacros(t)};var rf=function(t,r,e,Q,T){var n=new Z1.default(Q,t.stack.env,t.configuration).mml();t.Push(t.itemFactory.create(r,n));var i=t.GetNext(),a=tf[i];if(a){var o="",u="",l="",c=T.indexOf(i)!==-1;if(i==="{"){l=t.GetArgument(e),o=c?"\\left\\{":"",u=c?"\\right\\}":"";var f=o+" "+l+" "+u;t.string=f+t.string.slice(t.i),t.i=0;return}c&&(t.i++,t.Push(t.itemFactory.create("auto open").setProperties({open:i,close:a})))}};c1.OperatorApplication=function(t,r,e){for(var Q=[],T=3;T<arguments.length;T++)Q[T-3]=arguments[T];rf(t,"fn",r,e,Q)};c1.VectorOperator=function(t,r,e){for(var Q=[],T=3;T<arguments.length;T++)Q[T-3]=arguments[T];rf(t,"mml",r,e,Q)};c1.Expression=function(t,r,e,Q){e===void 0&&(e=!0),Q===void 0&&(Q=""),Q=Q||r.slice(1);var T=e?t.GetBrackets(r):null,n=t.create("token","mi",{texClass:vT.TEXCLASS.OP},Q);if(T){var i=new Z1.default(T,t.stack.env,t.configuration).mml();n=t.create("node","msup",[n,i])}t.Push(t.itemFactory.create("fn",n)),t.GetNext()==="("&&(t.i++,t.Push(t.itemFactory.create("auto open").setProperties({open:"(",close:")"})))};c1.Qqtext=function(t,r,e){var Q=t.GetStar(),T=e||t.GetArgument(r),n=(Q?"":"\\quad")+"\\text{"+T+"}\\quad ";t.string=t.string.slice(0,t.i)+n+t.string.slice(t.i)};c1.Differential=function(t,r,e){var Q=t.GetBrackets(r),T=Q!=null?"^{"+Q+"}":" ",n=t.GetNext()==="(",i=t.GetNext()==="{",a=e+T;if(!(n||i)){a+=t.GetArgument(r,!0)||"";var o=new Z1.default(a,t.stack.env,t.configuration).mml();t.Push(o);return}if(i){a+=t.GetArgument(r);var o=new Z1.default(a,t.stack.env,t.configuration).mml();t.Push(t.create("node","TeXAtom",[o],{texClass:vT.TEXCLASS.OP}));return}t.Push(new Z1.default(a,t.stack.env,t.configuration).mml()),t.i++,t.Push(t.itemFactory.create("auto open").setProperties({open:"(",close:")"}))};c1.Derivative=function(t,r,e,Q){var T=t.GetStar(),n=t.GetBrackets(r),i=1,a=[];for(a.push(t.GetArgument(r));t.GetNext()==="{"&&i<e;)a.push(t.GetArgument(r)),i++;var o=!1,u=" ",l=" ";e>2&&a.length>2?(u="^{"+(a.length-1)+"}",o=!0):n!=null&&(e>2&&a.length>1&&(o=!0),u="^{"+n+"}",l=u);for(var c=T?"\\flatfrac":"\\frac",f=a.length>1?a[0]:"",h=a.length>1?a[1]:a[0],d="",L=2,v=void 0;v=a[L];L++)d+=Q+" "+v;var x=c+"{"+Q+u+f+"}{"+Q+" "+h+l+" "+d+"}";t.Push(new Z1.default(x,t.stack.env,t.configuration).mml()),t.GetNext()==="("&&(t.i++,t.Push(t.itemFactory.create("auto open").setProperties({open:"(",close:")",ignore:o})))};c1.Bra=function(t,r){var e=t.GetStar(),Q=t.GetArgument(r),T="",n=!1,i=!1;if(t.GetNext()==="\\"){var a=t.i;t.i++;var o=t.GetCS(),u=t.lookup("macro",o);u&&u.symbol==="ket"?(n=!0,a=t.i,i=t.GetStar(),t.GetNext()==="{"?T=t.GetArgument(o,!0):(t.i=a,i=!1)):t.i=a}var l="";n?l=e||i?"\\langle{".concat(Q,"}\\vert{").concat(T,"}\\rangle"):"\\left\\langle{".concat(Q,"}\\middle\\vert{").concat(T,"}\\right\\rangle"):l=e||i?"\\langle{".concat(Q,"}\\vert"):"\\left\\langle{".concat(Q,"}\\right\\vert{").concat(T,"}"),t.Push(new Z1.default(l,t.stack.env,t.configuration).mml())};c1.Ket=function(t,r){var e=t.GetStar(),Q=t.GetArgument(r),T=e?"\\vert{".concat(Q,"}\\rangle"):"\\left\\vert{".concat(Q,"}\\right\\rangle");t.Push(new Z1.default(T,t.stack.env,t.configuration).mml())};c1.BraKet=function(t,r){var e=t.GetStar(),Q=t.GetArgument(r),T=null;t.GetNext()==="{"&&(T=t.GetArgument(r,!0));var n="";T==null?n=e?"\\langle{".concat(Q,"}\\vert{").concat(Q,"}\\rangle"):"\\left\\langle{".concat(Q,"}\\middle\\vert{").concat(Q,"}\\right\\rangle"):n=e?"\\langle{".concat(Q,"}\\vert{").concat(T,"}\\rangle"):"\\left\\langle{".concat(Q,"}\\middle\\vert{").concat(T,"}\\right\\rangle"),t.Push(new Z1.default(n,t.stack.env,t.configuration).mml())};c1.KetBra=function(t,r){var e=t.GetStar(),Q=t.GetArgument(r),T=null;t.GetNext()==="{"&&(T=t.GetArgument(r,!0));var n="";T==null?n=e?"\\vert{".concat(Q,"}\\rangle\\!\\langle{").concat(Q,"}\\vert"):"\\left\\vert{".concat(Q,"}\\middle\\rangle\\!\\middle\\langle{").concat(Q,"}\\right\\vert"):n=e?"\\vert{".concat(Q,"}\\rangle\\!\\langle{").concat(T,"}\\vert"):"\\left\\vert{".concat(Q,"}\\middle\\rangle\\!\\middle\\langle{").concat(T,"}\\right\\vert"),t.Push(new Z1.default(n,t.stack.env,t.configuration).mml())};function Qf(t,r,e){var Q=xD(t,3),T=Q[0],n=Q[1],i=Q[2];return r&&e?"\\left\\langle{".concat(T,"}\\middle\\vert{").concat(n,"}\\middle\\vert{").concat(i,"}\\right\\rangle"):r?"\\langle{".concat(T,"}\\vert{").concat(n,"}\\vert{").concat(i,"}\\rangle"):"\\left\\langle{".concat(T,"}\\right\\vert{").concat(n,"}\\left\\vert{").concat(i,"}\\right\\rangle")}c1.Expectation=function(t,r){var e=t.GetStar(),Q=e&&t.GetStar(),T=t.GetArgument(r),n=null;t.GetNext()==="{"&&(n=t.GetArgument(r,!0));var i=T&&n?Qf([n,T,n],e,Q):e?"\\langle {".concat(T,"} \\rangle"):"\\left\\langle {".concat(T,"} \\right\\rangle");t.Push(new Z1.default(i,t.stack.env,t.configuration).mml())};c1.MatrixElement=function(t,r){var e=t.GetStar(),Q=e&&t.GetStar(),T=t.GetArgument(r),n=t.GetArgument(r),i=t.GetArgument(r),a=Qf([T,n,i],e,Q);t.Push(new Z1.default(a,t.stack.env,t.configuration).mml())};c1.MatrixQuantity=function(t,r,e){var Q=t.GetStar(),T=t.GetNext(),n=e?"smallmatrix":"array",i="",a="",o="";switch(T){case"{":i=t.GetArgument(r);break;case"(":t.i++,a=Q?"\\lgroup":"(",o=Q?"\\rgroup":")",i=t.GetUpTo(r,")");break;case"[":t.i++,a="[",o="]",i=t.GetUpTo(r,"]");break;case"|":t.i++,a="|",o="|",i=t.GetUpTo(r,"|");break;default:a="(",o=")";break}var u=(a?"\\left":"")+a+"\\begin{"+n+"}{} "+i+"\\end{"+n+"}"+(a?"\\right":"")+o;t.Push(new Z1.default(u,t.stack.env,t.configuration).mml())};c1.IdentityMatrix=function(t,r){var e=t.GetArgument(r),Q=parseInt(e,10);if(isNaN(Q))throw new m0.default("InvalidNumber","Invalid number");if(Q<=1){t.string="1"+t.string.slice(t.i),t.i=0;return}for(var T=Array(Q).fill("0"),n=[],i=0;i<Q;i++){var a=T.slice();a[i]="1",n.push(a.join(" & "))}t.string=n.join("\\\\ ")+t.string.slice(t.i),t.i=0};c1.XMatrix=function(t,r){var e=t.GetStar(),Q=t.GetArgument(r),T=t.GetArgument(r),n=t.GetArgument(r),i=parseInt(T,10),a=parseInt(n,10);if(isNaN(i)||isNaN(a)||a.toString()!==n||i.toString()!==T)throw new m0.default("InvalidNumber","Invalid number");if(i=i<1?1:i,a=a<1?1:a,!e){var o=Array(a).fill(Q).join(" & "),u=Array(i).fill(o).join("\\\\ ");t.string=u+t.string.slice(t.i),t.i=0;return}var l="";if(i===1&&a===1)l=Q;else if(i===1){for(var o=[],c=1;c<=a;c++)o.push("".concat(Q,"_{").concat(c,"}"));l=o.join(" & ")}else if(a===1){for(var o=[],c=1;c<=i;c++)o.push("".concat(Q,"_{").concat(c,"}"));l=o.join("\\\\ ")}else{for(var f=[],c=1;c<=i;c++){for(var o=[],h=1;h<=a;h++)o.push("".concat(Q,"_{{").concat(c,"}{").concat(h,"}}"));f.push(o.join(" & "))}l=f.join("\\\\ ")}t.string=l+t.string.slice(t.i),t.i=0};c1.PauliMatrix=function(t,r){var e=t.GetArgument(r),Q=e.slice(1);switch(e[0]){case"0":Q+=" 1 & 0\\\\ 0 & 1";break;case"1":case"x":Q+=" 0 & 1\\\\ 1 & 0";break;case"2":case"y":Q+=" 0 & -i\\\\ i & 0";break;case"3":case"z":Q+=" 1 & 0\\\\ 0 & -1";break;default:}t.string=Q+t.string.slice(t.i),t.i=0};c1.DiagonalMatrix=function(t,r,e){if(t.GetNext()==="{"){var Q=t.i;t.GetArgument(r);var T=t.i;t.i=Q+1;for(var n=[],i="",a=t.i;a<T;){try{i=t.GetUpTo(r,",")}catch{t.i=T,n.push(t.string.slice(a,T-1));break}if(t.i>=T){n.push(t.string.slice(a,T));break}a=t.i,n.push(i)}t.string=AD(n,e)+t.string.slice(T),t.i=0}};function AD(t,r){for(var e=t.length,Q=[],T=0;T<e;T++)Q.push(Array(r?e-T:T+1).join("&")+"\\mqty{"+t[T]+"}");return Q.join("\\\\ ")}c1.AutoClose=function(t,r,e){var Q=t.create("token","mo",{stretchy:!1},r),T=t.itemFactory.create("mml",Q).setProperties({autoclose:r});t.Push(T)};c1.Vnabla=function(t,r){var e=t.options.physics.arrowdel?"\\vec{\\gradientnabla}":"{\\gradientnabla}";return t.Push(new Z1.default(e,t.stack.env,t.configuration).mml())};c1.DiffD=function(t,r){var e=t.options.physics.italicdiff?"d":"{\\rm d}";return t.Push(new Z1.default(e,t.stack.env,t.configuration).mml())};c1.Macro=pT.default.Macro;c1.NamedFn=pT.default.NamedFn;c1.Array=pT.default.Array;X6.default=c1});var af=p(Mt=>{"use strict";var nf=Mt&&Mt.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(Mt,"__esModule",{value:!0});var m3=T1(),m4=nf(Tf()),g0=X1(),xT=nf(O3()),OD=R();new m3.CommandMap("Physics-automatic-bracing-macros",{quantity:"Quantity",qty:"Quantity",pqty:["Quantity","(",")",!0],bqty:["Quantity","[","]",!0],vqty:["Quantity","|","|",!0],Bqty:["Quantity","\\{","\\}",!0],absolutevalue:["Quantity","|","|",!0],abs:["Quantity","|","|",!0],norm:["Quantity","\\|","\\|",!0],evaluated:"Eval",eval:"Eval",order:["Quantity","(",")",!0,"O",g0.TexConstant.Variant.CALLIGRAPHIC],commutator:"Commutator",comm:"Commutator",anticommutator:["Commutator","\\{","\\}"],acomm:["Commutator","\\{","\\}"],poissonbracket:["Commutator","\\{","\\}"],pb:["Commutator","\\{","\\}"]},m4.default);new m3.CharacterMap("Physics-vector-mo",xT.default.mathchar0mo,{dotproduct:["\u22C5",{mathvariant:g0.TexConstant.Variant.BOLD}],vdot:["\u22C5",{mathvariant:g0.TexConstant.Variant.BOLD}],crossproduct:"\xD7",cross:"\xD7",cp:"\xD7",gradientnabla:["\u2207",{mathvariant:g0.TexConstant.Variant.BOLD}]});new m3.CharacterMap("Physics-vector-mi",xT.default.mathchar0mi,{real:["\u211C",{mathvariant:g0.TexConstant.Variant.NORMAL}],imaginary:["\u2111",{mathvariant:g0.TexConstant.Variant.NORMAL}]});new m3.CommandMap("Physics-vector-macros",{vnabla:"Vnabla",vectorbold:"VectorBold",vb:"VectorBold",vectorarrow:["StarMacro",1,"\\vec{\\vb","{#1}}"],va:["StarMacro",1,"\\vec{\\vb","{#1}}"],vectorunit:["StarMacro",1,"\\hat{\\vb","{#1}}"],vu:["StarMacro",1,"\\hat{\\vb","{#1}}"],gradient:["OperatorApplication","\\vnabla","(","["],grad:["OperatorApplication","\\vnabla","(","["],divergence:["VectorOperator","\\vnabla\\vdot","(","["],div:["VectorOperator","\\vnabla\\vdot","(","["],curl:["VectorOperator","\\vnabla\\crossproduct","(","["],laplacian:["OperatorApplication","\\nabla^2","(","["]},m4.default);new m3.CommandMap("Physics-expressions-macros",{sin:"Expression",sinh:"Expression",arcsin:"Expression",asin:"Expression",cos:"Expression",cosh:"Expression",arccos:"Expression",acos:"Expression",tan:"Expression",tanh:"Expression",arctan:"Expression",atan:"Expression",csc:"Expression",csch:"Expression",arccsc:"Expression",acsc:"Expression",sec:"Expression",sech:"Expression",arcsec:"Expression",asec:"Expression",cot:"Expression",coth:"Expression",arccot:"Expression",acot:"Expression",exp:["Expression",!1],log:"Expression",ln:"Expression",det:["Expression",!1],Pr:["Expression",!1],tr:["Expression",!1],trace:["Expression",!1,"tr"],Tr:["Expression",!1],Trace:["Expression",!1,"Tr"],rank:"NamedFn",erf:["Expression",!1],Residue:["Macro","\\mathrm{Res}"],Res:["OperatorApplication","\\Residue","(","[","{"],principalvalue:["OperatorApplication","{\\cal P}"],pv:["OperatorApplication","{\\cal P}"],PV:["OperatorApplication","{\\rm P.V.}"],Re:["OperatorApplication","\\mathrm{Re}","{"],Im:["OperatorApplication","\\mathrm{Im}","{"],sine:["NamedFn","sin"],hypsine:["NamedFn","sinh"],arcsine:["NamedFn","arcsin"],asine:["NamedFn","asin"],cosine:["NamedFn","cos"],hypcosine:["NamedFn","cosh"],arccosine:["NamedFn","arccos"],acosine:["NamedFn","acos"],tangent:["NamedFn","tan"],hyptangent:["NamedFn","tanh"],arctangent:["NamedFn","arctan"],atangent:["NamedFn","atan"],cosecant:["NamedFn","csc"],hypcosecant:["NamedFn","csch"],arccosecant:["NamedFn","arccsc"],acosecant:["NamedFn","acsc"],secant:["NamedFn","sec"],hypsecant:["NamedFn","sech"],arcsecant:["NamedFn","arcsec"],asecant:["NamedFn","asec"],cotangent:["NamedFn","cot"],hypcotangent:["NamedFn","coth"],arccotangent:["NamedFn","arccot"],acotangent:["NamedFn","acot"],exponential:["NamedFn","exp"],logarithm:["NamedFn","log"],naturallogarithm:["NamedFn","ln"],determinant:["NamedFn","det"],Probability:["NamedFn","Pr"]},m4.default);new m3.CommandMap("Physics-quick-quad-macros",{qqtext:"Qqtext",qq:"Qqtext",qcomma:["Macro","\\qqtext*{,}"],qc:["Macro","\\qqtext*{,}"],qcc:["Qqtext","c.c."],qif:["Qqtext","if"],qthen:["Qqtext","then"],qelse:["Qqtext","else"],qotherwise:["Qqtext","otherwise"],qunless:["Qqtext","unless"],qgiven:["Qqtext","given"],qusing:["Qqtext","using"],qassume:["Qqtext","assume"],qsince:["Qqtext","since"],qlet:["Qqtext","let"],qfor:["Qqtext","for"],qall:["Qqtext","all"],qeven:["Qqtext","even"],qodd:["Qqtext","odd"],qinteger:["Qqtext","integer"],qand:["Qqtext","and"],qor:["Qqtext","or"],qas:["Qqtext","as"],qin:["Qqtext","in"]},m4.default);new m3.CommandMap("Physics-derivative-macros",{diffd:"DiffD",flatfrac:["Macro","\\left.#1\\middle/#2\\right.",2],differential:["Differential","\\diffd"],dd:["Differential","\\diffd"],variation:["Differential","\\delta"],var:["Differential","\\delta"],derivative:["Derivative",2,"\\diffd"],dv:["Derivative",2,"\\diffd"],partialderivative:["Derivative",3,"\\partial"],pderivative:["Derivative",3,"\\partial"],pdv:["Derivative",3,"\\partial"],functionalderivative:["Derivative",2,"\\delta"],fderivative:["Derivative",2,"\\delta"],fdv:["Derivative",2,"\\delta"]},m4.default);new m3.CommandMap("Physics-bra-ket-macros",{bra:"Bra",ket:"Ket",innerproduct:"BraKet",ip:"BraKet",braket:"BraKet",outerproduct:"KetBra",dyad:"KetBra",ketbra:"KetBra",op:"KetBra",expectationvalue:"Expectation",expval:"Expectation",ev:"Expectation",matrixelement:"MatrixElement",matrixel:"MatrixElement",mel:"MatrixElement"},m4.default);new m3.CommandMap("Physics-matrix-macros",{matrixquantity:"MatrixQuantity",mqty:"MatrixQuantity",pmqty:["Macro","\\mqty(#1)",1],Pmqty:["Macro","\\mqty*(#1)",1],bmqty:["Macro","\\mqty[#1]",1],vmqty:["Macro","\\mqty|#1|",1],smallmatrixquantity:["MatrixQuantity",!0],smqty:["MatrixQuantity",!0],spmqty:["Macro","\\smqty(#1)",1],sPmqty:["Macro","\\smqty*(#1)",1],sbmqty:["Macro","\\smqty[#1]",1],svmqty:["Macro","\\smqty|#1|",1],matrixdeterminant:["Macro","\\vmqty{#1}",1],mdet:["Macro","\\vmqty{#1}",1],smdet:["Macro","\\svmqty{#1}",1],identitymatrix:"IdentityMatrix",imat:"IdentityMatrix",xmatrix:"XMatrix",xmat:"XMatrix",zeromatrix:["Macro","\\xmat{0}{#1}{#2}",2],zmat:["Macro","\\xmat{0}{#1}{#2}",2],paulimatrix:"PauliMatrix",pmat:"PauliMatrix",diagonalmatrix:"DiagonalMatrix",dmat:"DiagonalMatrix",antidiagonalmatrix:["DiagonalMatrix",!0],admat:["DiagonalMatrix",!0]},m4.default);new m3.EnvironmentMap("Physics-aux-envs",xT.default.environment,{smallmatrix:["Array",null,null,null,"c","0.333em",".2em","S",1]},m4.default);new m3.MacroMap("Physics-characters",{"|":["AutoClose",OD.TEXCLASS.ORD],")":"AutoClose","]":"AutoClose"},m4.default)});var sf=p(Vt=>{"use strict";var HT;Object.defineProperty(Vt,"__esModule",{value:!0});Vt.PhysicsConfiguration=void 0;var CD=i1(),of=Yc();af();Vt.PhysicsConfiguration=CD.Configuration.create("physics",{handler:{macro:["Physics-automatic-bracing-macros","Physics-vector-macros","Physics-vector-mo","Physics-vector-mi","Physics-derivative-macros","Physics-expressions-macros","Physics-quick-quad-macros","Physics-bra-ket-macros","Physics-matrix-macros"],character:["Physics-characters"],environment:["Physics-aux-envs"]},items:(HT={},HT[of.AutoOpen.prototype.kind]=of.AutoOpen,HT),options:{physics:{italicdiff:!1,arrowdel:!1}}})});var hf=p(O2=>{"use strict";var DD=O2&&O2.__values||function(t){var r=typeof Symbol=="function"&&Symbol.iterator,e=r&&t[r],Q=0;if(e)return e.call(t);if(t&&typeof t.length=="number")return{next:function(){return t&&Q>=t.length&&(t=void 0),{value:t&&t[Q++],done:!t}}};throw new TypeError(r?"Object is not iterable.":"Symbol.iterator is not defined.")},yT=O2&&O2.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(O2,"__esModule",{value:!0});O2.SetOptionsConfiguration=O2.SetOptionsUtil=void 0;var cf=i1(),ED=T1(),p7=yT(l1()),bD=yT(h1()),uf=b6(),SD=yT(M2()),ff=B1();O2.SetOptionsUtil={filterPackage:function(t,r){if(r!=="tex"&&!cf.ConfigurationHandler.get(r))throw new p7.default("NotAPackage","Not a defined package: %1",r);var e=t.options.setoptions,Q=e.allowOptions[r];if(Q===void 0&&!e.allowPackageDefault||Q===!1)throw new p7.default("PackageNotSettable",`Options can't be set for package "%1"`,r);return!0},filterOption:function(t,r,e){var Q,T=t.options.setoptions,n=T.allowOptions[r]||{},i=n.hasOwnProperty(e)&&!(0,ff.isObject)(n[e])?n[e]:null;if(i===!1||i===null&&!T.allowOptionsDefault)throw new p7.default("OptionNotSettable",'Option "%1" is not allowed to be set',e);if(!(!((Q=r==="tex"?t.options:t.options[r])===null||Q===void 0)&&Q.hasOwnProperty(e)))throw r==="tex"?new p7.default("InvalidTexOption",'Invalid TeX option "%1"',e):new p7.default("InvalidOptionKey",'Invalid option "%1" for package "%2"',e,r);return!0},filterValue:function(t,r,e,Q){return Q}};var lf=new ED.CommandMap("setoptions",{setOptions:"SetOptions"},{SetOptions:function(t,r){var e,Q,T=t.GetBrackets(r)||"tex",n=bD.default.keyvalOptions(t.GetArgument(r)),i=t.options.setoptions;if(i.filterPackage(t,T))try{for(var a=DD(Object.keys(n)),o=a.next();!o.done;o=a.next()){var u=o.value;i.filterOption(t,T,u)&&((T==="tex"?t.options:t.options[T])[u]=i.filterValue(t,T,u,n[u]))}}catch(l){e={error:l}}finally{try{o&&!o.done&&(Q=a.return)&&Q.call(a)}finally{if(e)throw e.error}}}});function wD(t,r){var e=r.parseOptions.handlers.get("macro").lookup("require");e&&(lf.add("Require",new uf.Macro("Require",e._func)),lf.add("require",new uf.Macro("require",SD.default.Macro,["\\Require{#2}\\setOptions[#2]{#1}",2,""])))}O2.SetOptionsConfiguration=cf.Configuration.create("setoptions",{handler:{macro:["setoptions"]},config:wD,priority:3,options:{setoptions:{filterPackage:O2.SetOptionsUtil.filterPackage,filterOption:O2.SetOptionsUtil.filterOption,filterValue:O2.SetOptionsUtil.filterValue,allowPackageDefault:!0,allowOptionsDefault:!0,allowOptions:(0,ff.expandable)({tex:{FindTeX:!1,formatError:!1,package:!1,baseURL:!1,tags:!1,maxBuffer:!1,maxMaxros:!1,macros:!1,environments:!1},setoptions:!1,autoload:!1,require:!1,configmacros:!1,tagformat:!1})}}})});var pf=p(g6=>{"use strict";var BD=g6&&g6.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})();Object.defineProperty(g6,"__esModule",{value:!0});g6.TagFormatConfiguration=g6.tagformatConfig=void 0;var PD=i1(),mT=s4(),df=0;function Lf(t,r){var e=r.parseOptions.options.tags;e!=="base"&&t.tags.hasOwnProperty(e)&&mT.TagsFactory.add(e,t.tags[e]);var Q=mT.TagsFactory.create(r.parseOptions.options.tags).constructor,T=(function(i){BD(a,i);function a(){return i!==null&&i.apply(this,arguments)||this}return a.prototype.formatNumber=function(o){return r.parseOptions.options.tagformat.number(o)},a.prototype.formatTag=function(o){return r.parseOptions.options.tagformat.tag(o)},a.prototype.formatId=function(o){return r.parseOptions.options.tagformat.id(o)},a.prototype.formatUrl=function(o,u){return r.parseOptions.options.tagformat.url(o,u)},a})(Q);df++;var n="configTags-"+df;mT.TagsFactory.add(n,T),r.parseOptions.options.tags=n}g6.tagformatConfig=Lf;g6.TagFormatConfiguration=PD.Configuration.create("tagformat",{config:[Lf,10],options:{tagformat:{number:function(t){return t.toString()},tag:function(t){return"("+t+")"},id:function(t){return"mjx-eqn:"+t.replace(/\s/g,"_")},url:function(t,r){return r+"#"+encodeURIComponent(t)}}}})});var _t=p(V0=>{"use strict";var vf=V0&&V0.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(V0,"__esModule",{value:!0});V0.TextMacrosMethods=void 0;var jD=vf(W1()),ND=x7(),M0=vf(M2());V0.TextMacrosMethods={Comment:function(t,r){for(;t.i<t.string.length&&t.string.charAt(t.i)!==`
-`;)t.i++;t.i++},Math:function(t,r){t.saveText();for(var e=t.i,Q,T,n=0;T=t.GetNext();)switch(Q=t.i++,T){case"\\":var i=t.GetCS();i===")"&&(T="\\(");case"$":if(n===0&&r===T){var a=t.texParser.configuration,o=new jD.default(t.string.substr(e,Q-e),t.stack.env,a).mml();t.PushMath(o);return}break;case"{":n++;break;case"}":n===0&&t.Error("ExtraCloseMissingOpen","Extra close brace or missing open brace"),n--;break}t.Error("MathNotTerminated","Math-mode is not properly terminated")},MathModeOnly:function(t,r){t.Error("MathModeOnly","'%1' allowed only in math mode",r)},Misplaced:function(t,r){t.Error("Misplaced","'%1' can not be used here",r)},OpenBrace:function(t,r){var e=t.stack.env;t.envStack.push(e),t.stack.env=Object.assign({},e)},CloseBrace:function(t,r){t.envStack.length?(t.saveText(),t.stack.env=t.envStack.pop()):t.Error("ExtraCloseMissingOpen","Extra close brace or missing open brace")},OpenQuote:function(t,r){t.string.charAt(t.i)===r?(t.text+="\u201C",t.i++):t.text+="\u2018"},CloseQuote:function(t,r){t.string.charAt(t.i)===r?(t.text+="\u201D",t.i++):t.text+="\u2019"},Tilde:function(t,r){t.text+="\xA0"},Space:function(t,r){for(t.text+=" ";t.GetNext().match(/\s/);)t.i++},SelfQuote:function(t,r){t.text+=r.substr(1)},Insert:function(t,r,e){t.text+=e},Accent:function(t,r,e){var Q=t.ParseArg(r),T=t.create("token","mo",{},e);t.addAttributes(T),t.Push(t.create("node","mover",[Q,T]))},Emph:function(t,r){var e=t.stack.env.mathvariant==="-tex-mathit"?"normal":"-tex-mathit";t.Push(t.ParseTextArg(r,{mathvariant:e}))},SetFont:function(t,r,e){t.saveText(),t.stack.env.mathvariant=e},SetSize:function(t,r,e){t.saveText(),t.stack.env.mathsize=e},CheckAutoload:function(t,r){var e=t.configuration.packageData.get("autoload"),Q=t.texParser;r=r.slice(1);var T=Q.lookup("macro",r);if(!T||e&&T._func===e.Autoload){if(Q.parse("macro",[Q,r]),!T)return;(0,ND.retryAfter)(Promise.resolve())}Q.parse("macro",[t,r])},Macro:M0.default.Macro,Spacer:M0.default.Spacer,Hskip:M0.default.Hskip,rule:M0.default.rule,Rule:M0.default.Rule,HandleRef:M0.default.HandleRef}});var gT=p(H2=>{"use strict";var kD=H2&&H2.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})(),xf=H2&&H2.__values||function(t){var r=typeof Symbol=="function"&&Symbol.iterator,e=r&&t[r],Q=0;if(e)return e.call(t);if(t&&typeof t.length=="number")return{next:function(){return t&&Q>=t.length&&(t=void 0),{value:t&&t[Q++],done:!t}}};throw new TypeError(r?"Object is not iterable.":"Symbol.iterator is not defined.")},ID=H2&&H2.__read||function(t,r){var e=typeof Symbol=="function"&&t[Symbol.iterator];if(!e)return t;var Q=e.call(t),T,n=[],i;try{for(;(r===void 0||r-- >0)&&!(T=Q.next()).done;)n.push(T.value)}catch(a){i={error:a}}finally{try{T&&!T.done&&(e=Q.return)&&e.call(Q)}finally{if(i)throw i.error}}return n},FD=H2&&H2.__spreadArray||function(t,r,e){if(e||arguments.length===2)for(var Q=0,T=r.length,n;Q<T;Q++)(n||!(Q in r))&&(n||(n=Array.prototype.slice.call(r,0,Q)),n[Q]=r[Q]);return t.concat(n||Array.prototype.slice.call(r))},At=H2&&H2.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(H2,"__esModule",{value:!0});H2.TextParser=void 0;var RD=At(W1()),Hf=At(l1()),qD=At(h1()),ZD=R(),yf=At(H1()),mf=_3(),GD=(function(t){kD(r,t);function r(e,Q,T,n){var i=t.call(this,e,Q,T)||this;return i.level=n,i}return Object.defineProperty(r.prototype,"texParser",{get:function(){return this.configuration.packageData.get("textmacros").texParser},enumerable:!1,configurable:!0}),Object.defineProperty(r.prototype,"tags",{get:function(){return this.texParser.tags},enumerable:!1,configurable:!0}),r.prototype.mml=function(){return this.level!=null?this.create("node","mstyle",this.nodes,{displaystyle:!1,scriptlevel:this.level}):this.nodes.length===1?this.nodes[0]:this.create("node","mrow",this.nodes)},r.prototype.Parse=function(){this.text="",this.nodes=[],this.envStack=[],t.prototype.Parse.call(this)},r.prototype.saveText=function(){if(this.text){var e=this.stack.env.mathvariant,Q=qD.default.internalText(this,this.text,e?{mathvariant:e}:{});this.text="",this.Push(Q)}},r.prototype.Push=function(e){if(this.text&&this.saveText(),e instanceof mf.StopItem)return t.prototype.Push.call(this,e);if(e instanceof mf.StyleItem){this.stack.env.mathcolor=this.stack.env.color;return}e instanceof ZD.AbstractMmlNode&&(this.addAttributes(e),this.nodes.push(e))},r.prototype.PushMath=function(e){var Q,T,n=this.stack.env;e.isKind("TeXAtom")||(e=this.create("node","TeXAtom",[e]));try{for(var i=xf(["mathsize","mathcolor"]),a=i.next();!a.done;a=i.next()){var o=a.value;n[o]&&!e.attributes.getExplicit(o)&&(!e.isToken&&!e.isKind("mstyle")&&(e=this.create("node","mstyle",[e])),yf.default.setAttribute(e,o,n[o]))}}catch(u){Q={error:u}}finally{try{a&&!a.done&&(T=i.return)&&T.call(i)}finally{if(Q)throw Q.error}}e.isInferred&&(e=this.create("node","mrow",e.childNodes)),this.nodes.push(e)},r.prototype.addAttributes=function(e){var Q,T,n=this.stack.env;if(e.isToken)try{for(var i=xf(["mathsize","mathcolor","mathvariant"]),a=i.next();!a.done;a=i.next()){var o=a.value;n[o]&&!e.attributes.getExplicit(o)&&yf.default.setAttribute(e,o,n[o])}}catch(u){Q={error:u}}finally{try{a&&!a.done&&(T=i.return)&&T.call(i)}finally{if(Q)throw Q.error}}},r.prototype.ParseTextArg=function(e,Q){var T=this.GetArgument(e);return Q=Object.assign(Object.assign({},this.stack.env),Q),new r(T,Q,this.configuration).mml()},r.prototype.ParseArg=function(e){return new r(this.GetArgument(e),this.stack.env,this.configuration).mml()},r.prototype.Error=function(e,Q){for(var T=[],n=2;n<arguments.length;n++)T[n-2]=arguments[n];throw new(Hf.default.bind.apply(Hf.default,FD([void 0,e,Q],ID(T),!1)))},r})(RD.default);H2.TextParser=GD});var gf=p(Ot=>{"use strict";var WD=Ot&&Ot.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(Ot,"__esModule",{value:!0});var XD=T1(),o3=X1(),zD=_t(),UD=WD(h1()),$D=gT();new XD.CommandMap("textcomp-macros",{textasciicircum:["Insert","^"],textasciitilde:["Insert","~"],textasteriskcentered:["Insert","*"],textbackslash:["Insert","\\"],textbar:["Insert","|"],textbraceleft:["Insert","{"],textbraceright:["Insert","}"],textbullet:["Insert","\u2022"],textdagger:["Insert","\u2020"],textdaggerdbl:["Insert","\u2021"],textellipsis:["Insert","\u2026"],textemdash:["Insert","\u2014"],textendash:["Insert","\u2013"],textexclamdown:["Insert","\xA1"],textgreater:["Insert",">"],textless:["Insert","<"],textordfeminine:["Insert","\xAA"],textordmasculine:["Insert","\xBA"],textparagraph:["Insert","\xB6"],textperiodcentered:["Insert","\xB7"],textquestiondown:["Insert","\xBF"],textquotedblleft:["Insert","\u201C"],textquotedblright:["Insert","\u201D"],textquoteleft:["Insert","\u2018"],textquoteright:["Insert","\u2019"],textsection:["Insert","\xA7"],textunderscore:["Insert","_"],textvisiblespace:["Insert","\u2423"],textacutedbl:["Insert","\u02DD"],textasciiacute:["Insert","\xB4"],textasciibreve:["Insert","\u02D8"],textasciicaron:["Insert","\u02C7"],textasciidieresis:["Insert","\xA8"],textasciimacron:["Insert","\xAF"],textgravedbl:["Insert","\u02F5"],texttildelow:["Insert","\u02F7"],textbaht:["Insert","\u0E3F"],textcent:["Insert","\xA2"],textcolonmonetary:["Insert","\u20A1"],textcurrency:["Insert","\xA4"],textdollar:["Insert","$"],textdong:["Insert","\u20AB"],texteuro:["Insert","\u20AC"],textflorin:["Insert","\u0192"],textguarani:["Insert","\u20B2"],textlira:["Insert","\u20A4"],textnaira:["Insert","\u20A6"],textpeso:["Insert","\u20B1"],textsterling:["Insert","\xA3"],textwon:["Insert","\u20A9"],textyen:["Insert","\xA5"],textcircledP:["Insert","\u2117"],textcompwordmark:["Insert","\u200C"],textcopyleft:["Insert","\u{1F12F}"],textcopyright:["Insert","\xA9"],textregistered:["Insert","\xAE"],textservicemark:["Insert","\u2120"],texttrademark:["Insert","\u2122"],textbardbl:["Insert","\u2016"],textbigcircle:["Insert","\u25EF"],textblank:["Insert","\u2422"],textbrokenbar:["Insert","\xA6"],textdiscount:["Insert","\u2052"],textestimated:["Insert","\u212E"],textinterrobang:["Insert","\u203D"],textinterrobangdown:["Insert","\u2E18"],textmusicalnote:["Insert","\u266A"],textnumero:["Insert","\u2116"],textopenbullet:["Insert","\u25E6"],textpertenthousand:["Insert","\u2031"],textperthousand:["Insert","\u2030"],textrecipe:["Insert","\u211E"],textreferencemark:["Insert","\u203B"],textlangle:["Insert","\u2329"],textrangle:["Insert","\u232A"],textlbrackdbl:["Insert","\u27E6"],textrbrackdbl:["Insert","\u27E7"],textlquill:["Insert","\u2045"],textrquill:["Insert","\u2046"],textcelsius:["Insert","\u2103"],textdegree:["Insert","\xB0"],textdiv:["Insert","\xF7"],textdownarrow:["Insert","\u2193"],textfractionsolidus:["Insert","\u2044"],textleftarrow:["Insert","\u2190"],textlnot:["Insert","\xAC"],textmho:["Insert","\u2127"],textminus:["Insert","\u2212"],textmu:["Insert","\xB5"],textohm:["Insert","\u2126"],textonehalf:["Insert","\xBD"],textonequarter:["Insert","\xBC"],textonesuperior:["Insert","\xB9"],textpm:["Insert","\xB1"],textrightarrow:["Insert","\u2192"],textsurd:["Insert","\u221A"],textthreequarters:["Insert","\xBE"],textthreesuperior:["Insert","\xB3"],texttimes:["Insert","\xD7"],texttwosuperior:["Insert","\xB2"],textuparrow:["Insert","\u2191"],textborn:["Insert","*"],textdied:["Insert","\u2020"],textdivorced:["Insert","\u26AE"],textmarried:["Insert","\u26AD"],textcentoldstyle:["Insert","\xA2",o3.TexConstant.Variant.OLDSTYLE],textdollaroldstyle:["Insert","$",o3.TexConstant.Variant.OLDSTYLE],textzerooldstyle:["Insert","0",o3.TexConstant.Variant.OLDSTYLE],textoneoldstyle:["Insert","1",o3.TexConstant.Variant.OLDSTYLE],texttwooldstyle:["Insert","2",o3.TexConstant.Variant.OLDSTYLE],textthreeoldstyle:["Insert","3",o3.TexConstant.Variant.OLDSTYLE],textfouroldstyle:["Insert","4",o3.TexConstant.Variant.OLDSTYLE],textfiveoldstyle:["Insert","5",o3.TexConstant.Variant.OLDSTYLE],textsixoldstyle:["Insert","6",o3.TexConstant.Variant.OLDSTYLE],textsevenoldstyle:["Insert","7",o3.TexConstant.Variant.OLDSTYLE],texteightoldstyle:["Insert","8",o3.TexConstant.Variant.OLDSTYLE],textnineoldstyle:["Insert","9",o3.TexConstant.Variant.OLDSTYLE]},{Insert:function(t,r,e,Q){if(t instanceof $D.TextParser){if(!Q){zD.TextMacrosMethods.Insert(t,r,e);return}t.saveText()}t.Push(UD.default.internalText(t,e,Q?{mathvariant:Q}:{}))}})});var Mf=p(Ct=>{"use strict";Object.defineProperty(Ct,"__esModule",{value:!0});Ct.TextcompConfiguration=void 0;var KD=i1();gf();Ct.TextcompConfiguration=KD.Configuration.create("textcomp",{handler:{macro:["textcomp-macros"]}})});var Of=p(Af=>{"use strict";Object.defineProperty(Af,"__esModule",{value:!0});var Vf=T1(),r4=X1(),_f=_t(),z6=A3();new Vf.MacroMap("text-special",{$:"Math","%":"Comment","^":"MathModeOnly",_:"MathModeOnly","&":"Misplaced","#":"Misplaced","~":"Tilde"," ":"Space","	":"Space","\r":"Space","\n":"Space","\xA0":"Tilde","{":"OpenBrace","}":"CloseBrace","`":"OpenQuote","'":"CloseQuote"},_f.TextMacrosMethods);new Vf.CommandMap("text-macros",{"(":"Math",$:"SelfQuote",_:"SelfQuote","%":"SelfQuote","{":"SelfQuote","}":"SelfQuote"," ":"SelfQuote","&":"SelfQuote","#":"SelfQuote","\\":"SelfQuote","'":["Accent","\xB4"],"\u2019":["Accent","\xB4"],"`":["Accent","`"],"\u2018":["Accent","`"],"^":["Accent","^"],'"':["Accent","\xA8"],"~":["Accent","~"],"=":["Accent","\xAF"],".":["Accent","\u02D9"],u:["Accent","\u02D8"],v:["Accent","\u02C7"],emph:"Emph",rm:["SetFont",r4.TexConstant.Variant.NORMAL],mit:["SetFont",r4.TexConstant.Variant.ITALIC],oldstyle:["SetFont",r4.TexConstant.Variant.OLDSTYLE],cal:["SetFont",r4.TexConstant.Variant.CALLIGRAPHIC],it:["SetFont","-tex-mathit"],bf:["SetFont",r4.TexConstant.Variant.BOLD],bbFont:["SetFont",r4.TexConstant.Variant.DOUBLESTRUCK],scr:["SetFont",r4.TexConstant.Variant.SCRIPT],frak:["SetFont",r4.TexConstant.Variant.FRAKTUR],sf:["SetFont",r4.TexConstant.Variant.SANSSERIF],tt:["SetFont",r4.TexConstant.Variant.MONOSPACE],tiny:["SetSize",.5],Tiny:["SetSize",.6],scriptsize:["SetSize",.7],small:["SetSize",.85],normalsize:["SetSize",1],large:["SetSize",1.2],Large:["SetSize",1.44],LARGE:["SetSize",1.73],huge:["SetSize",2.07],Huge:["SetSize",2.49],Bbb:["Macro","{\\bbFont #1}",1],textnormal:["Macro","{\\rm #1}",1],textup:["Macro","{\\rm #1}",1],textrm:["Macro","{\\rm #1}",1],textit:["Macro","{\\it #1}",1],textbf:["Macro","{\\bf #1}",1],textsf:["Macro","{\\sf #1}",1],texttt:["Macro","{\\tt #1}",1],dagger:["Insert","\u2020"],ddagger:["Insert","\u2021"],S:["Insert","\xA7"],",":["Spacer",z6.MATHSPACE.thinmathspace],":":["Spacer",z6.MATHSPACE.mediummathspace],">":["Spacer",z6.MATHSPACE.mediummathspace],";":["Spacer",z6.MATHSPACE.thickmathspace],"!":["Spacer",z6.MATHSPACE.negativethinmathspace],enspace:["Spacer",.5],quad:["Spacer",1],qquad:["Spacer",2],thinspace:["Spacer",z6.MATHSPACE.thinmathspace],negthinspace:["Spacer",z6.MATHSPACE.negativethinmathspace],hskip:"Hskip",hspace:"Hskip",kern:"Hskip",mskip:"Hskip",mspace:"Hskip",mkern:"Hskip",rule:"rule",Rule:["Rule"],Space:["Rule","blank"],color:"CheckAutoload",textcolor:"CheckAutoload",colorbox:"CheckAutoload",fcolorbox:"CheckAutoload",href:"CheckAutoload",style:"CheckAutoload",class:"CheckAutoload",cssId:"CheckAutoload",unicode:"CheckAutoload",ref:["HandleRef",!1],eqref:["HandleRef",!0]},_f.TextMacrosMethods)});var Ef=p(V6=>{"use strict";var JD=V6&&V6.__importDefault||function(t){return t&&t.__esModule?t:{default:t}},_0;Object.defineProperty(V6,"__esModule",{value:!0});V6.TextMacrosConfiguration=V6.TextBaseConfiguration=void 0;var MT=i1(),YD=JD(ee()),Cf=s4(),M6=_3(),Df=gT(),tE=_t();Of();V6.TextBaseConfiguration=MT.Configuration.create("text-base",{parser:"text",handler:{character:["command","text-special"],macro:["text-macros"]},fallback:{character:function(t,r){t.text+=r},macro:function(t,r){var e=t.texParser,Q=e.lookup("macro",r);Q&&Q._func!==tE.TextMacrosMethods.Macro&&t.Error("MathMacro","%1 is only supported in math mode","\\"+r),e.parse("macro",[t,r])}},items:(_0={},_0[M6.StartItem.prototype.kind]=M6.StartItem,_0[M6.StopItem.prototype.kind]=M6.StopItem,_0[M6.MmlItem.prototype.kind]=M6.MmlItem,_0[M6.StyleItem.prototype.kind]=M6.StyleItem,_0)});function eE(t,r,e,Q){var T=t.configuration.packageData.get("textmacros");return t instanceof Df.TextParser||(T.texParser=t),[new Df.TextParser(r,Q?{mathvariant:Q}:{},T.parseOptions,e).mml()]}V6.TextMacrosConfiguration=MT.Configuration.create("textmacros",{config:function(t,r){var e=new MT.ParserConfiguration(r.parseOptions.options.textmacros.packages,["tex","text"]);e.init();var Q=new YD.default(e,[]);Q.options=r.parseOptions.options,e.config(r),Cf.TagsFactory.addTags(e.tags),Q.tags=Cf.TagsFactory.getDefault(),Q.tags.configuration=Q,Q.packageData=r.parseOptions.packageData,Q.packageData.set("textmacros",{parseOptions:Q,jax:r,texParser:null}),Q.options.internalMath=eE},preprocessors:[function(t){var r=t.data.packageData.get("textmacros");r.parseOptions.nodeFactory.setMmlFactory(r.jax.mmlFactory)}],options:{textmacros:{packages:["text-base"]}}})});var bf=p(Dt=>{"use strict";Object.defineProperty(Dt,"__esModule",{value:!0});Dt.UpgreekConfiguration=void 0;var rE=i1(),QE=T1(),TE=X1();function nE(t,r){var e=r.attributes||{};e.mathvariant=TE.TexConstant.Variant.NORMAL;var Q=t.create("token","mi",e,r.char);t.Push(Q)}new QE.CharacterMap("upgreek",nE,{upalpha:"\u03B1",upbeta:"\u03B2",upgamma:"\u03B3",updelta:"\u03B4",upepsilon:"\u03F5",upzeta:"\u03B6",upeta:"\u03B7",uptheta:"\u03B8",upiota:"\u03B9",upkappa:"\u03BA",uplambda:"\u03BB",upmu:"\u03BC",upnu:"\u03BD",upxi:"\u03BE",upomicron:"\u03BF",uppi:"\u03C0",uprho:"\u03C1",upsigma:"\u03C3",uptau:"\u03C4",upupsilon:"\u03C5",upphi:"\u03D5",upchi:"\u03C7",uppsi:"\u03C8",upomega:"\u03C9",upvarepsilon:"\u03B5",upvartheta:"\u03D1",upvarpi:"\u03D6",upvarrho:"\u03F1",upvarsigma:"\u03C2",upvarphi:"\u03C6",Upgamma:"\u0393",Updelta:"\u0394",Uptheta:"\u0398",Uplambda:"\u039B",Upxi:"\u039E",Uppi:"\u03A0",Upsigma:"\u03A3",Upupsilon:"\u03A5",Upphi:"\u03A6",Uppsi:"\u03A8",Upomega:"\u03A9"});Dt.UpgreekConfiguration=rE.Configuration.create("upgreek",{handler:{macro:["upgreek"]}})});var Sf=p(Q4=>{"use strict";var VT=Q4&&Q4.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(Q4,"__esModule",{value:!0});Q4.UnicodeConfiguration=Q4.UnicodeMethods=void 0;var iE=i1(),aE=VT(l1()),oE=T1(),sE=VT(h1()),uE=VT(H1()),lE=Q5();Q4.UnicodeMethods={};var A0={};Q4.UnicodeMethods.Unicode=function(t,r){var e=t.GetBrackets(r),Q=null,T=null;e&&(e.replace(/ /g,"").match(/^(\d+(\.\d*)?|\.\d+),(\d+(\.\d*)?|\.\d+)$/)?(Q=e.replace(/ /g,"").split(/,/),T=t.GetBrackets(r)):T=e);var n=sE.default.trimSpaces(t.GetArgument(r)).replace(/^0x/,"x");if(!n.match(/^(x[0-9A-Fa-f]+|[0-9]+)$/))throw new aE.default("BadUnicode","Argument to \\unicode must be a number");var i=parseInt(n.match(/^x/)?"0"+n:n);A0[i]?T||(T=A0[i][2]):A0[i]=[800,200,T,i],Q&&(A0[i][0]=Math.floor(parseFloat(Q[0])*1e3),A0[i][1]=Math.floor(parseFloat(Q[1])*1e3));var a=t.stack.env.font,o={};T?(A0[i][2]=o.fontfamily=T.replace(/'/g,"'"),a&&(a.match(/bold/)&&(o.fontweight="bold"),a.match(/italic|-mathit/)&&(o.fontstyle="italic"))):a&&(o.mathvariant=a);var u=t.create("token","mtext",o,(0,lE.numeric)(n));uE.default.setProperty(u,"unicode",!0),t.Push(u)};new oE.CommandMap("unicode",{unicode:"Unicode"},Q4.UnicodeMethods);Q4.UnicodeConfiguration=iE.Configuration.create("unicode",{handler:{macro:["unicode"]}})});var Bf=p(T4=>{"use strict";var cE=T4&&T4.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(T4,"__esModule",{value:!0});T4.VerbConfiguration=T4.VerbMethods=void 0;var fE=i1(),hE=X1(),dE=T1(),wf=cE(l1());T4.VerbMethods={};T4.VerbMethods.Verb=function(t,r){var e=t.GetNext(),Q=++t.i;if(e==="")throw new wf.default("MissingArgFor","Missing argument for %1",r);for(;t.i<t.string.length&&t.string.charAt(t.i)!==e;)t.i++;if(t.i===t.string.length)throw new wf.default("NoClosingDelim","Can't find closing delimiter for %1",t.currentCS);var T=t.string.slice(Q,t.i).replace(/ /g,"\xA0");t.i++,t.Push(t.create("token","mtext",{mathvariant:hE.TexConstant.Variant.MONOSPACE},T))};new dE.CommandMap("verb",{verb:"Verb"},T4.VerbMethods);T4.VerbConfiguration=fE.Configuration.create("verb",{handler:{macro:["verb"]}})});var Pf=p(Et=>{"use strict";Object.defineProperty(Et,"__esModule",{value:!0});Et.AllPackages=void 0;B7();ju();mQ();Yu();tl();Ql();cl();Bl();Nl();Wl();$l();ic();ac();sc();vc();yc();PQ();Oc();Cc();Ec();oT();Uc();QT();$c();Kc();sf();hf();pf();Mf();Ef();bf();Sf();Bf();typeof MathJax<"u"&&MathJax.loader&&MathJax.loader.preLoad("[tex]/action","[tex]/ams","[tex]/amscd","[tex]/bbox","[tex]/boldsymbol","[tex]/braket","[tex]/bussproofs","[tex]/cancel","[tex]/cases","[tex]/centernot","[tex]/color","[tex]/colorv2","[tex]/colortbl","[tex]/empheq","[tex]/enclose","[tex]/extpfeil","[tex]/gensymb","[tex]/html","[tex]/mathtools","[tex]/mhchem","[tex]/newcommand","[tex]/noerrors","[tex]/noundefined","[tex]/physics","[tex]/upgreek","[tex]/unicode","[tex]/verb","[tex]/configmacros","[tex]/tagformat","[tex]/textcomp","[tex]/textmacros","[tex]/setoptions");Et.AllPackages=["base","action","ams","amscd","bbox","boldsymbol","braket","bussproofs","cancel","cases","centernot","color","colortbl","empheq","enclose","extpfeil","gensymb","html","mathtools","mhchem","newcommand","noerrors","noundefined","upgreek","unicode","verb","configmacros","tagformat","textcomp","textmacros"]});var VE=p(()=>{var{mathjax:LE}=H7(),{TeX:pE}=Fn(),{SVG:vE}=Ve(),{liteAdaptor:xE}=Is(),{RegisterHTMLHandler:HE}=Bu(),{AllPackages:yE}=Pf(),jf=xE();HE(jf);var mE=new pE({packages:yE}),gE=new vE({fontCache:"none"}),ME=LE.document("",{InputJax:mE,OutputJax:gE});globalThis.convertLatexToSvg=function(t,r){try{let e=ME.convert(t,{display:r}),Q=jf.outerHTML(e),T=Q.match(/<svg[^>]*>[\s\S]*<\/svg>/);if(T){let n=T[0];return n.includes("xmlns=")||(n=n.replace("<svg ",'<svg xmlns="http://www.w3.org/2000/svg" ')),n=n.replace(/fill="currentColor"/g,'fill="black"'),n=n.replace(/stroke="currentColor"/g,'stroke="black"'),JSON.stringify({success:!0,svg:n})}return JSON.stringify({success:!0,svg:Q})}catch(e){return JSON.stringify({success:!1,error:e.message||String(e)})}}});VE();})();
+`;)t.i++;t.i++},Math:function(t,r){t.saveText();for(var e=t.i,Q,T,n=0;T=t.GetNext();)switch(Q=t.i++,T){case"\\":var i=t.GetCS();i===")"&&(T="\\(");case"$":if(n===0&&r===T){var a=t.texParser.configuration,o=new jD.default(t.string.substr(e,Q-e),t.stack.env,a).mml();t.PushMath(o);return}break;case"{":n++;break;case"}":n===0&&t.Error("ExtraCloseMissingOpen","Extra close brace or missing open brace"),n--;break}t.Error("MathNotTerminated","Math-mode is not properly terminated")},MathModeOnly:function(t,r){t.Error("MathModeOnly","'%1' allowed only in math mode",r)},Misplaced:function(t,r){t.Error("Misplaced","'%1' can not be used here",r)},OpenBrace:function(t,r){var e=t.stack.env;t.envStack.push(e),t.stack.env=Object.assign({},e)},CloseBrace:function(t,r){t.envStack.length?(t.saveText(),t.stack.env=t.envStack.pop()):t.Error("ExtraCloseMissingOpen","Extra close brace or missing open brace")},OpenQuote:function(t,r){t.string.charAt(t.i)===r?(t.text+="\u201C",t.i++):t.text+="\u2018"},CloseQuote:function(t,r){t.string.charAt(t.i)===r?(t.text+="\u201D",t.i++):t.text+="\u2019"},Tilde:function(t,r){t.text+="\xA0"},Space:function(t,r){for(t.text+=" ";t.GetNext().match(/\s/);)t.i++},SelfQuote:function(t,r){t.text+=r.substr(1)},Insert:function(t,r,e){t.text+=e},Accent:function(t,r,e){var Q=t.ParseArg(r),T=t.create("token","mo",{},e);t.addAttributes(T),t.Push(t.create("node","mover",[Q,T]))},Emph:function(t,r){var e=t.stack.env.mathvariant==="-tex-mathit"?"normal":"-tex-mathit";t.Push(t.ParseTextArg(r,{mathvariant:e}))},SetFont:function(t,r,e){t.saveText(),t.stack.env.mathvariant=e},SetSize:function(t,r,e){t.saveText(),t.stack.env.mathsize=e},CheckAutoload:function(t,r){var e=t.configuration.packageData.get("autoload"),Q=t.texParser;r=r.slice(1);var T=Q.lookup("macro",r);if(!T||e&&T._func===e.Autoload){if(Q.parse("macro",[Q,r]),!T)return;(0,ND.retryAfter)(Promise.resolve())}Q.parse("macro",[t,r])},Macro:M0.default.Macro,Spacer:M0.default.Spacer,Hskip:M0.default.Hskip,rule:M0.default.rule,Rule:M0.default.Rule,HandleRef:M0.default.HandleRef}});var gT=p(H2=>{"use strict";var kD=H2&&H2.__extends||(function(){var t=function(r,e){return t=Object.setPrototypeOf||{__proto__:[]}instanceof Array&&function(Q,T){Q.__proto__=T}||function(Q,T){for(var n in T)Object.prototype.hasOwnProperty.call(T,n)&&(Q[n]=T[n])},t(r,e)};return function(r,e){if(typeof e!="function"&&e!==null)throw new TypeError("Class extends value "+String(e)+" is not a constructor or null");t(r,e);function Q(){this.constructor=r}r.prototype=e===null?Object.create(e):(Q.prototype=e.prototype,new Q)}})(),xf=H2&&H2.__values||function(t){var r=typeof Symbol=="function"&&Symbol.iterator,e=r&&t[r],Q=0;if(e)return e.call(t);if(t&&typeof t.length=="number")return{next:function(){return t&&Q>=t.length&&(t=void 0),{value:t&&t[Q++],done:!t}}};throw new TypeError(r?"Object is not iterable.":"Symbol.iterator is not defined.")},ID=H2&&H2.__read||function(t,r){var e=typeof Symbol=="function"&&t[Symbol.iterator];if(!e)return t;var Q=e.call(t),T,n=[],i;try{for(;(r===void 0||r-- >0)&&!(T=Q.next()).done;)n.push(T.value)}catch(a){i={error:a}}finally{try{T&&!T.done&&(e=Q.return)&&e.call(Q)}finally{if(i)throw i.error}}return n},FD=H2&&H2.__spreadArray||function(t,r,e){if(e||arguments.length===2)for(var Q=0,T=r.length,n;Q<T;Q++)(n||!(Q in r))&&(n||(n=Array.prototype.slice.call(r,0,Q)),n[Q]=r[Q]);return t.concat(n||Array.prototype.slice.call(r))},At=H2&&H2.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(H2,"__esModule",{value:!0});H2.TextParser=void 0;var RD=At(W1()),Hf=At(l1()),qD=At(h1()),ZD=R(),yf=At(H1()),mf=_3(),GD=(function(t){kD(r,t);function r(e,Q,T,n){var i=t.call(this,e,Q,T)||this;return i.level=n,i}return Object.defineProperty(r.prototype,"texParser",{get:function(){return this.configuration.packageData.get("textmacros").texParser},enumerable:!1,configurable:!0}),Object.defineProperty(r.prototype,"tags",{get:function(){return this.texParser.tags},enumerable:!1,configurable:!0}),r.prototype.mml=function(){return this.level!=null?this.create("node","mstyle",this.nodes,{displaystyle:!1,scriptlevel:this.level}):this.nodes.length===1?this.nodes[0]:this.create("node","mrow",this.nodes)},r.prototype.Parse=function(){this.text="",this.nodes=[],this.envStack=[],t.prototype.Parse.call(this)},r.prototype.saveText=function(){if(this.text){var e=this.stack.env.mathvariant,Q=qD.default.internalText(this,this.text,e?{mathvariant:e}:{});this.text="",this.Push(Q)}},r.prototype.Push=function(e){if(this.text&&this.saveText(),e instanceof mf.StopItem)return t.prototype.Push.call(this,e);if(e instanceof mf.StyleItem){this.stack.env.mathcolor=this.stack.env.color;return}e instanceof ZD.AbstractMmlNode&&(this.addAttributes(e),this.nodes.push(e))},r.prototype.PushMath=function(e){var Q,T,n=this.stack.env;e.isKind("TeXAtom")||(e=this.create("node","TeXAtom",[e]));try{for(var i=xf(["mathsize","mathcolor"]),a=i.next();!a.done;a=i.next()){var o=a.value;n[o]&&!e.attributes.getExplicit(o)&&(!e.isToken&&!e.isKind("mstyle")&&(e=this.create("node","mstyle",[e])),yf.default.setAttribute(e,o,n[o]))}}catch(u){Q={error:u}}finally{try{a&&!a.done&&(T=i.return)&&T.call(i)}finally{if(Q)throw Q.error}}e.isInferred&&(e=this.create("node","mrow",e.childNodes)),this.nodes.push(e)},r.prototype.addAttributes=function(e){var Q,T,n=this.stack.env;if(e.isToken)try{for(var i=xf(["mathsize","mathcolor","mathvariant"]),a=i.next();!a.done;a=i.next()){var o=a.value;n[o]&&!e.attributes.getExplicit(o)&&yf.default.setAttribute(e,o,n[o])}}catch(u){Q={error:u}}finally{try{a&&!a.done&&(T=i.return)&&T.call(i)}finally{if(Q)throw Q.error}}},r.prototype.ParseTextArg=function(e,Q){var T=this.GetArgument(e);return Q=Object.assign(Object.assign({},this.stack.env),Q),new r(T,Q,this.configuration).mml()},r.prototype.ParseArg=function(e){return new r(this.GetArgument(e),this.stack.env,this.configuration).mml()},r.prototype.Error=function(e,Q){for(var T=[],n=2;n<arguments.length;n++)T[n-2]=arguments[n];throw new(Hf.default.bind.apply(Hf.default,FD([void 0,e,Q],ID(T),!1)))},r})(RD.default);H2.TextParser=GD});var gf=p(Ot=>{"use strict";var WD=Ot&&Ot.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(Ot,"__esModule",{value:!0});var XD=T1(),o3=X1(),zD=_t(),UD=WD(h1()),$D=gT();new XD.CommandMap("textcomp-macros",{textasciicircum:["Insert","^"],textasciitilde:["Insert","~"],textasteriskcentered:["Insert","*"],textbackslash:["Insert","\\"],textbar:["Insert","|"],textbraceleft:["Insert","{"],textbraceright:["Insert","}"],textbullet:["Insert","\u2022"],textdagger:["Insert","\u2020"],textdaggerdbl:["Insert","\u2021"],textellipsis:["Insert","\u2026"],textemdash:["Insert","\u2014"],textendash:["Insert","\u2013"],textexclamdown:["Insert","\xA1"],textgreater:["Insert",">"],textless:["Insert","<"],textordfeminine:["Insert","\xAA"],textordmasculine:["Insert","\xBA"],textparagraph:["Insert","\xB6"],textperiodcentered:["Insert","\xB7"],textquestiondown:["Insert","\xBF"],textquotedblleft:["Insert","\u201C"],textquotedblright:["Insert","\u201D"],textquoteleft:["Insert","\u2018"],textquoteright:["Insert","\u2019"],textsection:["Insert","\xA7"],textunderscore:["Insert","_"],textvisiblespace:["Insert","\u2423"],textacutedbl:["Insert","\u02DD"],textasciiacute:["Insert","\xB4"],textasciibreve:["Insert","\u02D8"],textasciicaron:["Insert","\u02C7"],textasciidieresis:["Insert","\xA8"],textasciimacron:["Insert","\xAF"],textgravedbl:["Insert","\u02F5"],texttildelow:["Insert","\u02F7"],textbaht:["Insert","\u0E3F"],textcent:["Insert","\xA2"],textcolonmonetary:["Insert","\u20A1"],textcurrency:["Insert","\xA4"],textdollar:["Insert","$"],textdong:["Insert","\u20AB"],texteuro:["Insert","\u20AC"],textflorin:["Insert","\u0192"],textguarani:["Insert","\u20B2"],textlira:["Insert","\u20A4"],textnaira:["Insert","\u20A6"],textpeso:["Insert","\u20B1"],textsterling:["Insert","\xA3"],textwon:["Insert","\u20A9"],textyen:["Insert","\xA5"],textcircledP:["Insert","\u2117"],textcompwordmark:["Insert","\u200C"],textcopyleft:["Insert","\u{1F12F}"],textcopyright:["Insert","\xA9"],textregistered:["Insert","\xAE"],textservicemark:["Insert","\u2120"],texttrademark:["Insert","\u2122"],textbardbl:["Insert","\u2016"],textbigcircle:["Insert","\u25EF"],textblank:["Insert","\u2422"],textbrokenbar:["Insert","\xA6"],textdiscount:["Insert","\u2052"],textestimated:["Insert","\u212E"],textinterrobang:["Insert","\u203D"],textinterrobangdown:["Insert","\u2E18"],textmusicalnote:["Insert","\u266A"],textnumero:["Insert","\u2116"],textopenbullet:["Insert","\u25E6"],textpertenthousand:["Insert","\u2031"],textperthousand:["Insert","\u2030"],textrecipe:["Insert","\u211E"],textreferencemark:["Insert","\u203B"],textlangle:["Insert","\u2329"],textrangle:["Insert","\u232A"],textlbrackdbl:["Insert","\u27E6"],textrbrackdbl:["Insert","\u27E7"],textlquill:["Insert","\u2045"],textrquill:["Insert","\u2046"],textcelsius:["Insert","\u2103"],textdegree:["Insert","\xB0"],textdiv:["Insert","\xF7"],textdownarrow:["Insert","\u2193"],textfractionsolidus:["Insert","\u2044"],textleftarrow:["Insert","\u2190"],textlnot:["Insert","\xAC"],textmho:["Insert","\u2127"],textminus:["Insert","\u2212"],textmu:["Insert","\xB5"],textohm:["Insert","\u2126"],textonehalf:["Insert","\xBD"],textonequarter:["Insert","\xBC"],textonesuperior:["Insert","\xB9"],textpm:["Insert","\xB1"],textrightarrow:["Insert","\u2192"],textsurd:["Insert","\u221A"],textthreequarters:["Insert","\xBE"],textthreesuperior:["Insert","\xB3"],texttimes:["Insert","\xD7"],texttwosuperior:["Insert","\xB2"],textuparrow:["Insert","\u2191"],textborn:["Insert","*"],textdied:["Insert","\u2020"],textdivorced:["Insert","\u26AE"],textmarried:["Insert","\u26AD"],textcentoldstyle:["Insert","\xA2",o3.TexConstant.Variant.OLDSTYLE],textdollaroldstyle:["Insert","$",o3.TexConstant.Variant.OLDSTYLE],textzerooldstyle:["Insert","0",o3.TexConstant.Variant.OLDSTYLE],textoneoldstyle:["Insert","1",o3.TexConstant.Variant.OLDSTYLE],texttwooldstyle:["Insert","2",o3.TexConstant.Variant.OLDSTYLE],textthreeoldstyle:["Insert","3",o3.TexConstant.Variant.OLDSTYLE],textfouroldstyle:["Insert","4",o3.TexConstant.Variant.OLDSTYLE],textfiveoldstyle:["Insert","5",o3.TexConstant.Variant.OLDSTYLE],textsixoldstyle:["Insert","6",o3.TexConstant.Variant.OLDSTYLE],textsevenoldstyle:["Insert","7",o3.TexConstant.Variant.OLDSTYLE],texteightoldstyle:["Insert","8",o3.TexConstant.Variant.OLDSTYLE],textnineoldstyle:["Insert","9",o3.TexConstant.Variant.OLDSTYLE]},{Insert:function(t,r,e,Q){if(t instanceof $D.TextParser){if(!Q){zD.TextMacrosMethods.Insert(t,r,e);return}t.saveText()}t.Push(UD.default.internalText(t,e,Q?{mathvariant:Q}:{}))}})});var Mf=p(Ct=>{"use strict";Object.defineProperty(Ct,"__esModule",{value:!0});Ct.TextcompConfiguration=void 0;var KD=i1();gf();Ct.TextcompConfiguration=KD.Configuration.create("textcomp",{handler:{macro:["textcomp-macros"]}})});var Of=p(Af=>{"use strict";Object.defineProperty(Af,"__esModule",{value:!0});var Vf=T1(),r4=X1(),_f=_t(),z6=A3();new Vf.MacroMap("text-special",{$:"Math","%":"Comment","^":"MathModeOnly",_:"MathModeOnly","&":"Misplaced","#":"Misplaced","~":"Tilde"," ":"Space","	":"Space","\r":"Space","\n":"Space","\xA0":"Tilde","{":"OpenBrace","}":"CloseBrace","`":"OpenQuote","'":"CloseQuote"},_f.TextMacrosMethods);new Vf.CommandMap("text-macros",{"(":"Math",$:"SelfQuote",_:"SelfQuote","%":"SelfQuote","{":"SelfQuote","}":"SelfQuote"," ":"SelfQuote","&":"SelfQuote","#":"SelfQuote","\\":"SelfQuote","'":["Accent","\xB4"],"\u2019":["Accent","\xB4"],"`":["Accent","`"],"\u2018":["Accent","`"],"^":["Accent","^"],'"':["Accent","\xA8"],"~":["Accent","~"],"=":["Accent","\xAF"],".":["Accent","\u02D9"],u:["Accent","\u02D8"],v:["Accent","\u02C7"],emph:"Emph",rm:["SetFont",r4.TexConstant.Variant.NORMAL],mit:["SetFont",r4.TexConstant.Variant.ITALIC],oldstyle:["SetFont",r4.TexConstant.Variant.OLDSTYLE],cal:["SetFont",r4.TexConstant.Variant.CALLIGRAPHIC],it:["SetFont","-tex-mathit"],bf:["SetFont",r4.TexConstant.Variant.BOLD],bbFont:["SetFont",r4.TexConstant.Variant.DOUBLESTRUCK],scr:["SetFont",r4.TexConstant.Variant.SCRIPT],frak:["SetFont",r4.TexConstant.Variant.FRAKTUR],sf:["SetFont",r4.TexConstant.Variant.SANSSERIF],tt:["SetFont",r4.TexConstant.Variant.MONOSPACE],tiny:["SetSize",.5],Tiny:["SetSize",.6],scriptsize:["SetSize",.7],small:["SetSize",.85],normalsize:["SetSize",1],large:["SetSize",1.2],Large:["SetSize",1.44],LARGE:["SetSize",1.73],huge:["SetSize",2.07],Huge:["SetSize",2.49],Bbb:["Macro","{\\bbFont #1}",1],textnormal:["Macro","{\\rm #1}",1],textup:["Macro","{\\rm #1}",1],textrm:["Macro","{\\rm #1}",1],textit:["Macro","{\\it #1}",1],textbf:["Macro","{\\bf #1}",1],textsf:["Macro","{\\sf #1}",1],texttt:["Macro","{\\tt #1}",1],dagger:["Insert","\u2020"],ddagger:["Insert","\u2021"],S:["Insert","\xA7"],",":["Spacer",z6.MATHSPACE.thinmathspace],":":["Spacer",z6.MATHSPACE.mediummathspace],">":["Spacer",z6.MATHSPACE.mediummathspace],";":["Spacer",z6.MATHSPACE.thickmathspace],"!":["Spacer",z6.MATHSPACE.negativethinmathspace],enspace:["Spacer",.5],quad:["Spacer",1],qquad:["Spacer",2],thinspace:["Spacer",z6.MATHSPACE.thinmathspace],negthinspace:["Spacer",z6.MATHSPACE.negativethinmathspace],hskip:"Hskip",hspace:"Hskip",kern:"Hskip",mskip:"Hskip",mspace:"Hskip",mkern:"Hskip",rule:"rule",Rule:["Rule"],Space:["Rule","blank"],color:"CheckAutoload",textcolor:"CheckAutoload",colorbox:"CheckAutoload",fcolorbox:"CheckAutoload",href:"CheckAutoload",style:"CheckAutoload",class:"CheckAutoload",cssId:"CheckAutoload",unicode:"CheckAutoload",ref:["HandleRef",!1],eqref:["HandleRef",!0]},_f.TextMacrosMethods)});var Ef=p(V6=>{"use strict";var JD=V6&&V6.__importDefault||function(t){return t&&t.__esModule?t:{default:t}},_0;Object.defineProperty(V6,"__esModule",{value:!0});V6.TextMacrosConfiguration=V6.TextBaseConfiguration=void 0;var MT=i1(),YD=JD(ee()),Cf=s4(),M6=_3(),Df=gT(),tE=_t();Of();V6.TextBaseConfiguration=MT.Configuration.create("text-base",{parser:"text",handler:{character:["command","text-special"],macro:["text-macros"]},fallback:{character:function(t,r){t.text+=r},macro:function(t,r){var e=t.texParser,Q=e.lookup("macro",r);Q&&Q._func!==tE.TextMacrosMethods.Macro&&t.Error("MathMacro","%1 is only supported in math mode","\\"+r),e.parse("macro",[t,r])}},items:(_0={},_0[M6.StartItem.prototype.kind]=M6.StartItem,_0[M6.StopItem.prototype.kind]=M6.StopItem,_0[M6.MmlItem.prototype.kind]=M6.MmlItem,_0[M6.StyleItem.prototype.kind]=M6.StyleItem,_0)});function eE(t,r,e,Q){var T=t.configuration.packageData.get("textmacros");return t instanceof Df.TextParser||(T.texParser=t),[new Df.TextParser(r,Q?{mathvariant:Q}:{},T.parseOptions,e).mml()]}V6.TextMacrosConfiguration=MT.Configuration.create("textmacros",{config:function(t,r){var e=new MT.ParserConfiguration(r.parseOptions.options.textmacros.packages,["tex","text"]);e.init();var Q=new YD.default(e,[]);Q.options=r.parseOptions.options,e.config(r),Cf.TagsFactory.addTags(e.tags),Q.tags=Cf.TagsFactory.getDefault(),Q.tags.configuration=Q,Q.packageData=r.parseOptions.packageData,Q.packageData.set("textmacros",{parseOptions:Q,jax:r,texParser:null}),Q.options.internalMath=eE},preprocessors:[function(t){var r=t.data.packageData.get("textmacros");r.parseOptions.nodeFactory.setMmlFactory(r.jax.mmlFactory)}],options:{textmacros:{packages:["text-base"]}}})});var bf=p(Dt=>{"use strict";Object.defineProperty(Dt,"__esModule",{value:!0});Dt.UpgreekConfiguration=void 0;var rE=i1(),QE=T1(),TE=X1();function nE(t,r){var e=r.attributes||{};e.mathvariant=TE.TexConstant.Variant.NORMAL;var Q=t.create("token","mi",e,r.char);t.Push(Q)}new QE.CharacterMap("upgreek",nE,{upalpha:"\u03B1",upbeta:"\u03B2",upgamma:"\u03B3",updelta:"\u03B4",upepsilon:"\u03F5",upzeta:"\u03B6",upeta:"\u03B7",uptheta:"\u03B8",upiota:"\u03B9",upkappa:"\u03BA",uplambda:"\u03BB",upmu:"\u03BC",upnu:"\u03BD",upxi:"\u03BE",upomicron:"\u03BF",uppi:"\u03C0",uprho:"\u03C1",upsigma:"\u03C3",uptau:"\u03C4",upupsilon:"\u03C5",upphi:"\u03D5",upchi:"\u03C7",uppsi:"\u03C8",upomega:"\u03C9",upvarepsilon:"\u03B5",upvartheta:"\u03D1",upvarpi:"\u03D6",upvarrho:"\u03F1",upvarsigma:"\u03C2",upvarphi:"\u03C6",Upgamma:"\u0393",Updelta:"\u0394",Uptheta:"\u0398",Uplambda:"\u039B",Upxi:"\u039E",Uppi:"\u03A0",Upsigma:"\u03A3",Upupsilon:"\u03A5",Upphi:"\u03A6",Uppsi:"\u03A8",Upomega:"\u03A9"});Dt.UpgreekConfiguration=rE.Configuration.create("upgreek",{handler:{macro:["upgreek"]}})});var Sf=p(Q4=>{"use strict";var VT=Q4&&Q4.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(Q4,"__esModule",{value:!0});Q4.UnicodeConfiguration=Q4.UnicodeMethods=void 0;var iE=i1(),aE=VT(l1()),oE=T1(),sE=VT(h1()),uE=VT(H1()),lE=Q5();Q4.UnicodeMethods={};var A0={};Q4.UnicodeMethods.Unicode=function(t,r){var e=t.GetBrackets(r),Q=null,T=null;e&&(e.replace(/ /g,"").match(/^(\d+(\.\d*)?|\.\d+),(\d+(\.\d*)?|\.\d+)$/)?(Q=e.replace(/ /g,"").split(/,/),T=t.GetBrackets(r)):T=e);var n=sE.default.trimSpaces(t.GetArgument(r)).replace(/^0x/,"x");if(!n.match(/^(x[0-9A-Fa-f]+|[0-9]+)$/))throw new aE.default("BadUnicode","Argument to \\unicode must be a number");var i=parseInt(n.match(/^x/)?"0"+n:n);A0[i]?T||(T=A0[i][2]):A0[i]=[800,200,T,i],Q&&(A0[i][0]=Math.floor(parseFloat(Q[0])*1e3),A0[i][1]=Math.floor(parseFloat(Q[1])*1e3));var a=t.stack.env.font,o={};T?(A0[i][2]=o.fontfamily=T.replace(/'/g,"'"),a&&(a.match(/bold/)&&(o.fontweight="bold"),a.match(/italic|-mathit/)&&(o.fontstyle="italic"))):a&&(o.mathvariant=a);var u=t.create("token","mtext",o,(0,lE.numeric)(n));uE.default.setProperty(u,"unicode",!0),t.Push(u)};new oE.CommandMap("unicode",{unicode:"Unicode"},Q4.UnicodeMethods);Q4.UnicodeConfiguration=iE.Configuration.create("unicode",{handler:{macro:["unicode"]}})});var Bf=p(T4=>{"use strict";var cE=T4&&T4.__importDefault||function(t){return t&&t.__esModule?t:{default:t}};Object.defineProperty(T4,"__esModule",{value:!0});T4.VerbConfiguration=T4.VerbMethods=void 0;var fE=i1(),hE=X1(),dE=T1(),wf=cE(l1());T4.VerbMethods={};T4.VerbMethods.Verb=function(t,r){var e=t.GetNext(),Q=++t.i;if(e==="")throw new wf.default("MissingArgFor","Missing argument for %1",r);for(;t.i<t.string.length&&t.string.charAt(t.i)!==e;)t.i++;if(t.i===t.string.length)throw new wf.default("NoClosingDelim","Can't find closing delimiter for %1",t.currentCS);var T=t.string.slice(Q,t.i).replace(/ /g,"\xA0");t.i++,t.Push(t.create("token","mtext",{mathvariant:hE.TexConstant.Variant.MONOSPACE},T))};new dE.CommandMap("verb",{verb:"Verb"},T4.VerbMethods);T4.VerbConfiguration=fE.Configuration.create("verb",{handler:{macro:["verb"]}})});var Pf=p(Et=>{"use strict";Object.defineProperty(Et,"__esModule",{value:!0});Et.AllPackages=void 0;B7();ju();mQ();Yu();tl();Ql();cl();Bl();Nl();Wl();$l();ic();ac();sc();vc();yc();PQ();Oc();Cc();Ec();oT();Uc();QT();$c();Kc();sf();hf();pf();Mf();Ef();bf();Sf();Bf();typeof MathJax<"u"&&MathJax.loader&&MathJax.loader.preLoad("[tex]/action","[tex]/ams","[tex]/amscd","[tex]/bbox","[tex]/boldsymbol","[tex]/braket","[tex]/bussproofs","[tex]/cancel","[tex]/cases","[tex]/centernot","[tex]/color","[tex]/colorv2","[tex]/colortbl","[tex]/empheq","[tex]/enclose","[tex]/extpfeil","[tex]/gensymb","[tex]/html","[tex]/mathtools","[tex]/mhchem","[tex]/newcommand","[tex]/noerrors","[tex]/noundefined","[tex]/physics","[tex]/upgreek","[tex]/unicode","[tex]/verb","[tex]/configmacros","[tex]/tagformat","[tex]/textcomp","[tex]/textmacros","[tex]/setoptions");Et.AllPackages=["base","action","ams","amscd","bbox","boldsymbol","braket","bussproofs","cancel","cases","centernot","color","colortbl","empheq","enclose","extpfeil","gensymb","html","mathtools","mhchem","newcommand","noerrors","noundefined","upgreek","unicode","verb","configmacros","tagformat","textcomp","textmacros"]});var VE=p(()=>{var{mathjax:LE}=H7(),{TeX:pE}=Fn(),{SVG:vE}=Ve(),{liteAdaptor:xE}=Is(),{RegisterHTMLHandler:HE}=Bu(),{AllPackages:yE}=Pf(),jf=xE();HE(jf);var mE=new pE({packages:yE}),gE=new vE({fontCache:"none"}),ME=LE.document("",{InputJax:mE,OutputJax:gE});var RE=["new-Command","new-Environment","new-Delimiter"],ka=new Map;function SE(){return RE.map(t=>[t,mE.parseOptions.handlers.retrieve(t)]).filter(([,t])=>t)}function qE(){for(let[t,r]of SE())r.map=new Map(ka.get(t)||[])}function ZE(t,r){let e=t.match(r);return e?parseFloat(e[1]):0}globalThis.convertLatexToSvg=function(t,r){try{qE();let e=ME.convert(t,{display:r}),Q=jf.outerHTML(e),T=Q.match(/<svg[^>]*>[\s\S]*<\/svg>/);if(T){let n=T[0];return n.includes("xmlns=")||(n=n.replace("<svg ",'<svg xmlns="http://www.w3.org/2000/svg" ')),n=n.replace(/fill="currentColor"/g,'fill="black"'),n=n.replace(/stroke="currentColor"/g,'stroke="black"'),{output:n,width:ZE(n,/^<svg[^>]* width="([-\d.]+)ex"/),height:ZE(n,/^<svg[^>]* height="([-\d.]+)ex"/),verticalAlign:ZE(n,/^<svg[^>]*vertical-align:\s*([-\d.]+)ex/)}}return{output:Q}}catch(e){return{error:e.message||String(e)}}};function wE(t){return String(t).replace(/&/g,"&amp;").replace(/</g,"&lt;").replace(/>/g,"&gt;").replace(/"/g,"&quot;")}function kE(t){if(t.isKind("text"))return wE(t.getText());let r=t.childNodes.map(n=>n?kE(n):"").join("");if(t.isInferred)return r;let e=t.isKind("TeXAtom")?"mrow":t.kind,Q=t.attributes.getAllAttributes(),T=Object.keys(Q).map(n=>` ${n}="${wE(Q[n])}"`).join("");return`<${e}${T}>${r}</${e}>`}globalThis.convertLatexToMathml=function(t,r){try{qE();let e=ME.convert(t,{display:r,end:100}),Q=kE(e).replace(/^<math/,`<math xmlns="http://www.w3.org/1998/Math/MathML" alttext="${wE(t)}"`);return{output:Q}}catch(e){return{error:e.message||String(e)}}};globalThis.setMathJaxPreamble=function(t){ka=new Map;let r=globalThis.convertLatexToSvg(t,!1);return ka=new Map(SE().map(([e,Q])=>[e,new Map(Q.map)])),r}});VE();})();
 /*! Bundled license information:
 
 mhchemparser/dist/mhchemParser.js:
@@ -1,5 +1,12 @@
+use rquickjs::{Context, Module, Runtime};
+use std::env;
+use std::fs;
+use std::path::Path;
 use std::process::Command;
 
+/// Module name the MathJax bytecode is declared under
+const MATHJAX_MODULE: &str = "mathjax-bundle";
+
 fn main() {
     // Get version from git: tag if on a tag, otherwise commit hash
     let version = Command::new("git")
@@ -15,4 +22,94 @@ fn main() {
     // Rerun if git state changes
     println!("cargo:rerun-if-changed=.git/HEAD");
     println!("cargo:rerun-if-changed=.git/refs/tags");
+
+    renderer_versions();
+    compile_mathjax();
+}
+
+/// Exposes the locked versions of the diagram renderers, which are part of
+/// their cache keys. Without a lockfile, as when md2cb is a dependency, they
+/// are `unknown`.
+fn renderer_versions() {
+    println!("cargo:rerun-if-changed=Cargo.lock");
+    let lock = fs::read_to_string("Cargo.lock").unwrap_or_default();
+    for (name, var) in [
+        ("mermaid-rs-renderer", "MERMAID_RS_RENDERER_VERSION"),
+        ("layout-rs", "LAYOUT_RS_VERSION"),
+    ] {
+        let version = locked_version(&lock, name).unwrap_or_else(|| "unknown".to_string());
+        println!("cargo:rustc-env={var}={version}");
+    }
+}
+
+/// Version of package `name` in a Cargo.lock, with the commit of a git
+/// dependency, which may change without a version bump.
+fn locked_version(lock: &str, name: &str) -> Option<String> {
+    let name_line = format!("name = \"{name}\"");
+    let package = lock
+        .split("[[package]]")
+        .find(|package| package.lines().any(|line| line.trim() == name_line))?;
+    let field = |key: &str| {
+        package.lines().find_map(|line| {
+            let value = line
+                .trim()
+                .strip_prefix(key)?
+                .trim_start()
+                .strip_prefix('=')?;
+            value.trim().strip_prefix('"')?.strip_suffix('"')
+        })
+    };
+    let version = field("version")?;
+    Some(
+        match field("source").and_then(|source| source.split_once('#')) {
+            Some((_, commit)) => format!("{version}#{commit}"),
+            None => version.to_string(),
+        },
+    )
+}
+
+/// Compiles the MathJax bundle to QuickJS bytecode, so the runtime doesn't
+/// parse 1.8 MB of JavaScript on every start. On failure an empty file is
+/// written and the runtime evaluates the source instead.
+fn compile_mathjax() {
+    let source_path = "assets/mathjax-bundle.js";
+    println!("cargo:rerun-if-changed={source_path}");
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    let out_path = Path::new(&out_dir).join("mathjax-bundle.qjsbc");
+
+    let bytecode = fs::read_to_string(source_path)
+        .map_err(|e| format!("Failed to read {source_path}: {e}"))
+        .and_then(|source| compile_module(MATHJAX_MODULE, &source))
+        .unwrap_or_else(|e| {
+            println!("cargo:warning=MathJax bytecode not embedded, falling back to source: {e}");
+            Vec::new()
+        });
+
+    fs::write(&out_path, bytecode).expect("Failed to write MathJax bytecode");
+}
+
+/// Compiles `source` as a module without evaluating it, returning bytecode in
+/// the target's byte order.
+fn compile_module(name: &str, source: &str) -> Result<Vec<u8>, String> {
+    let runtime = Runtime::new().map_err(|e| format!("Failed to create JS runtime: {e}"))?;
+    runtime.set_max_stack_size(8 * 1024 * 1024);
+    let context =
+        Context::full(&runtime).map_err(|e| format!("Failed to create JS context: {e}"))?;
+
+    // Bytecode is read back in the byte order of the machine running md2cb
+    let swap_endianness = env::var("CARGO_CFG_TARGET_ENDIAN").is_ok_and(|endian| {
+        endian
+            != if cfg!(target_endian = "big") {
+                "big"
+            } else {
+                "little"
+            }
+    });
+
+    context.with(|ctx| {
+        Module::declare(ctx, name, source)
+            .and_then(|module| module.write(swap_endianness))
+            .map_err(|e| format!("Failed to compile {name}: {e}"))
+    })
 }
@@ -7,6 +7,7 @@ const { SVG } = require('mathjax-full/js/output/svg.js');
 const { liteAdaptor } = require('mathjax-full/js/adaptors/liteAdaptor.js');
 const { RegisterHTMLHandler } = require('mathjax-full/js/handlers/html.js');
 const { AllPackages } = require('mathjax-full/js/input/tex/AllPackages.js');
+const { STATE } = require('mathjax-full/js/core/MathItem.js');
 
 // Initialize MathJax with liteAdaptor (no DOM needed)
 const adaptor = liteAdaptor();
@@ -16,9 +17,36 @@ const tex = new TeX({ packages: AllPackages });
 const svg = new SVG({ fontCache: 'none' });  // No font caching, self-contained SVG
 const html = mathjax.document('', { InputJax: tex, OutputJax: svg });
 
-// Expose global function for Rust to call
+// Where \newcommand, \def, \let and \newenvironment put what they define
+const MACRO_MAPS = ['new-Command', 'new-Environment', 'new-Delimiter'];
+
+// Macros defined by the preamble. Every conversion starts from these, so an
+// expression can't change how the next one renders.
+let savedMacros = new Map();
+
+function macroMaps() {
+    return MACRO_MAPS
+        .map(name => [name, tex.parseOptions.handlers.retrieve(name)])
+        .filter(([, map]) => map);
+}
+
+function restoreMacros() {
+    for (const [name, map] of macroMaps()) {
+        map.map = new Map(savedMacros.get(name) || []);
+    }
+}
+
+// Reads a length MathJax puts on the <svg> element, in ex
+function exLength(svg, pattern) {
+    const match = svg.match(pattern);
+    return match ? parseFloat(match[1]) : 0;
+}
+
+// Expose global functions for Rust to call. Each returns `{ output }`, or
+// `{ error }` when the expression can't be converted.
 globalThis.convertLatexToSvg = function(latex, display) {
     try {
+        restoreMacros();
         const node = html.convert(latex, { display: display });
         let svgHtml = adaptor.outerHTML(node);
 
@@ -36,12 +64,70 @@ globalThis.convertLatexToSvg = function(latex, display) {
             svgStr = svgStr.replace(/fill="currentColor"/g, 'fill="black"');
             svgStr = svgStr.replace(/stroke="currentColor"/g, 'stroke="black"');
 
-            return JSON.stringify({ success: true, svg: svgStr });
+            // Size and depth below the baseline, to size and align the image
+            return {
+                output: svgStr,
+                width: exLength(svgStr, /^<svg[^>]* width="([-\d.]+)ex"/),
+                height: exLength(svgStr, /^<svg[^>]* height="([-\d.]+)ex"/),
+                verticalAlign: exLength(svgStr, /^<svg[^>]*vertical-align:\s*([-\d.]+)ex/),
+            };
         }
 
         // Fallback: return the full output
-        return JSON.stringify({ success: true, svg: svgHtml });
+        return { output: svgHtml };
     } catch (e) {
-        return JSON.stringify({ success: false, error: e.message || String(e) });
+        return { error: e.message || String(e) };
     }
 };
+
+function escapeXml(value) {
+    return String(value)
+        .replace(/&/g, '&amp;')
+        .replace(/</g, '&lt;')
+        .replace(/>/g, '&gt;')
+        .replace(/"/g, '&quot;');
+}
+
+// Serialize MathJax's internal MathML tree. Kept minimal instead of pulling in
+// SerializedMmlVisitor: only explicit attributes are written out.
+function serializeMml(node) {
+    if (node.isKind('text')) {
+        return escapeXml(node.getText());
+    }
+    const children = node.childNodes.map(child => (child ? serializeMml(child) : '')).join('');
+    // Inferred rows only exist in the internal tree
+    if (node.isInferred) {
+        return children;
+    }
+    const kind = node.isKind('TeXAtom') ? 'mrow' : node.kind;
+    const attributes = node.attributes.getAllAttributes();
+    const attrs = Object.keys(attributes)
+        .map(name => ` ${name}="${escapeXml(attributes[name])}"`)
+        .join('');
+    return `<${kind}${attrs}>${children}</${kind}>`;
+}
+
+// Expose MathML output for editors that turn it into native equations.
+// The TeX source goes into alttext so the equation can be converted back.
+globalThis.convertLatexToMathml = function(latex, display) {
+    try {
+        restoreMacros();
+        const node = html.convert(latex, { display: display, end: STATE.CONVERT });
+        const mathml = serializeMml(node).replace(
+            /^<math/,
+            `<math xmlns="http://www.w3.org/1998/Math/MathML" alttext="${escapeXml(latex)}"`
+        );
+        return { output: mathml };
+    } catch (e) {
+        return { error: e.message || String(e) };
+    }
+};
+
+// Replaces the preamble's macros: evaluates `preamble` from a state without
+// macros and keeps what it defines for the following conversions.
+globalThis.setMathJaxPreamble = function(preamble) {
+    savedMacros = new Map();
+    const result = globalThis.convertLatexToSvg(preamble, false);
+    savedMacros = new Map(macroMaps().map(([name, map]) => [name, new Map(map.map)]));
+    return result;
+};
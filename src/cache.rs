@@ -0,0 +1,301 @@
+//! Persistent on-disk cache for rendered math and diagrams.
+//!
+//! Rendering an equation means starting MathJax and rasterizing its SVG, which
+//! dominates the run time of math-heavy documents. Finished renders are stored
+//! under `$XDG_CACHE_HOME/md2cb` (falling back to `~/.cache/md2cb`), one JSON
+//! file per render, named after a SHA-256 digest of everything that affects
+//! the output, so names stay the same across builds and Rust versions.
+//!
+//! The cache is best-effort: unreadable or unwritable entries are treated as
+//! misses and never fail a conversion.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Default cap on the total size of cached renders.
+pub const DEFAULT_MAX_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Extension of cache entry files.
+const ENTRY_EXTENSION: &str = "json";
+
+/// Everything that determines a rendered result.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CacheKey {
+    /// What was rendered and how, e.g. `math-png` or `mermaid`.
+    pub kind: String,
+    /// md2cb and rendering engine versions.
+    pub engine: String,
+    pub source: String,
+    pub display: bool,
+    pub scale: f32,
+    /// Macro definitions the source was rendered with.
+    pub preamble: String,
+}
+
+/// A cached render, stored with its full key to rule out hash collisions.
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    key: CacheKey,
+    value: String,
+}
+
+/// Directory of cached renders, capped at `max_bytes`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cache {
+    dir: PathBuf,
+    max_bytes: u64,
+}
+
+/// What [`Cache::trim`] or [`Cache::clear`] removed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Removed {
+    pub entries: usize,
+    pub bytes: u64,
+}
+
+impl CacheKey {
+    /// Key for content rendered by `engine`, tagged with the md2cb build's
+    /// `git describe`, as the package version is never bumped.
+    pub fn new(kind: &str, engine: &str, source: &str, display: bool) -> Self {
+        Self {
+            kind: kind.to_string(),
+            engine: format!("md2cb {} / {engine}", env!("GIT_VERSION")),
+            source: source.to_string(),
+            display,
+            scale: 1.0,
+            preamble: String::new(),
+        }
+    }
+
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    pub fn preamble(mut self, preamble: &str) -> Self {
+        self.preamble = preamble.to_string();
+        self
+    }
+
+    /// Hex digest used as the entry's file name.
+    fn digest(&self) -> String {
+        // The JSON form is as stable as the entry files themselves
+        let json = serde_json::to_vec(self).unwrap_or_default();
+        Sha256::digest(json)
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>, max_bytes: u64) -> Self {
+        Self {
+            dir: dir.into(),
+            max_bytes,
+        }
+    }
+
+    /// `$XDG_CACHE_HOME/md2cb`, or `~/.cache/md2cb`.
+    pub fn default_dir() -> Option<PathBuf> {
+        let cache_dir = env::var_os("XDG_CACHE_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))?;
+        Some(cache_dir.join("md2cb"))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the cached render for `key`, or runs `render` and stores its
+    /// result. Failures are not cached.
+    pub fn get_or_render(
+        &self,
+        key: &CacheKey,
+        render: impl FnOnce() -> Result<String, String>,
+    ) -> Result<String, String> {
+        let path = self.entry_path(key);
+        if let Some(value) = read_entry(&path, key) {
+            // Mark as recently used, so trimming drops it last
+            if let Ok(file) = fs::File::options().append(true).open(&path) {
+                let _ = file.set_modified(SystemTime::now());
+            }
+            return Ok(value);
+        }
+
+        let value = render()?;
+        let entry = Entry {
+            key: key.clone(),
+            value,
+        };
+        if let Ok(json) = serde_json::to_string(&entry) {
+            let _ = fs::create_dir_all(&self.dir).and_then(|()| fs::write(&path, json));
+        }
+        Ok(entry.value)
+    }
+
+    /// Deletes the least recently used entries until the cache fits in its cap.
+    pub fn trim(&self) -> io::Result<Removed> {
+        let mut entries = self.entries()?;
+        let mut total: u64 = entries.iter().map(|(_, len, _)| len).sum();
+        entries.sort_by_key(|(_, _, used)| *used);
+
+        let mut removed = Removed::default();
+        for (path, len, _) in entries {
+            if total <= self.max_bytes {
+                break;
+            }
+            fs::remove_file(&path)?;
+            total -= len;
+            removed.entries += 1;
+            removed.bytes += len;
+        }
+        Ok(removed)
+    }
+
+    /// Deletes every entry.
+    pub fn clear(&self) -> io::Result<Removed> {
+        let mut removed = Removed::default();
+        for (path, len, _) in self.entries()? {
+            fs::remove_file(&path)?;
+            removed.entries += 1;
+            removed.bytes += len;
+        }
+        Ok(removed)
+    }
+
+    fn entry_path(&self, key: &CacheKey) -> PathBuf {
+        self.dir.join(key.digest()).with_extension(ENTRY_EXTENSION)
+    }
+
+    /// Path, size and last use of every entry. A missing directory is empty.
+    fn entries(&self) -> io::Result<Vec<(PathBuf, u64, SystemTime)>> {
+        let dir = match fs::read_dir(&self.dir) {
+            Ok(dir) => dir,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let mut entries = Vec::new();
+        for item in dir {
+            let path = item?.path();
+            if path.extension().is_none_or(|ext| ext != ENTRY_EXTENSION) {
+                continue;
+            }
+            let metadata = fs::metadata(&path)?;
+            let used = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            entries.push((path, metadata.len(), used));
+        }
+        Ok(entries)
+    }
+}
+
+/// Reads the entry at `path` if it was stored for `key`.
+fn read_entry(path: &Path, key: &CacheKey) -> Option<String> {
+    let json = fs::read_to_string(path).ok()?;
+    let entry: Entry = serde_json::from_str(&json).ok()?;
+    (entry.key == *key).then_some(entry.value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn temp_cache(name: &str, max_bytes: u64) -> Cache {
+        let dir = env::temp_dir().join(format!("md2cb-cache-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        Cache::new(dir, max_bytes)
+    }
+
+    #[test]
+    fn test_render_once_then_hit() {
+        let cache = temp_cache("hit", DEFAULT_MAX_BYTES);
+        let key = CacheKey::new("math-png", "MathJax 3.2.1", "x^2", false);
+        let calls = Cell::new(0);
+        let render = || {
+            calls.set(calls.get() + 1);
+            Ok("<img>".to_string())
+        };
+
+        assert_eq!(cache.get_or_render(&key, render), Ok("<img>".to_string()));
+        assert_eq!(cache.get_or_render(&key, render), Ok("<img>".to_string()));
+        assert_eq!(calls.get(), 1);
+
+        // Any part of the key changes the entry
+        let scaled = key.clone().scale(2.0);
+        cache.get_or_render(&scaled, render).unwrap();
+        assert_eq!(calls.get(), 2);
+
+        fs::remove_dir_all(cache.dir()).unwrap();
+    }
+
+    #[test]
+    fn test_digest_is_stable() {
+        // Entry names must not change between builds
+        let key = CacheKey {
+            kind: "math-png".to_string(),
+            engine: "md2cb 1.0.0 / MathJax 3.2.1".to_string(),
+            source: "x^2".to_string(),
+            display: false,
+            scale: 1.0,
+            preamble: String::new(),
+        };
+        assert_eq!(
+            key.digest(),
+            "ab8ee68fab1b55c0aa514ba763eb8916c5d8bf64671ed209b64854ae18a0acf1"
+        );
+    }
+
+    #[test]
+    fn test_key_tagged_with_build() {
+        let key = CacheKey::new("mermaid", "mermaid-rs-renderer 0.1.0", "graph", true);
+        assert_eq!(
+            key.engine,
+            format!("md2cb {} / mermaid-rs-renderer 0.1.0", env!("GIT_VERSION"))
+        );
+    }
+
+    #[test]
+    fn test_failures_not_cached() {
+        let cache = temp_cache("fail", DEFAULT_MAX_BYTES);
+        let key = CacheKey::new("math-png", "MathJax 3.2.1", "x^", false);
+        assert!(
+            cache
+                .get_or_render(&key, || Err("bad".to_string()))
+                .is_err()
+        );
+        assert_eq!(cache.clear().unwrap(), Removed::default());
+    }
+
+    #[test]
+    fn test_trim_drops_least_recently_used() {
+        let cache = temp_cache("trim", 0);
+        let old = CacheKey::new("mermaid", "mermaid-rs", "graph A", true);
+        let new = CacheKey::new("mermaid", "mermaid-rs", "graph B", true);
+        cache.get_or_render(&old, || Ok("a".repeat(100))).unwrap();
+        let old_path = cache.entry_path(&old);
+        fs::File::options()
+            .append(true)
+            .open(&old_path)
+            .unwrap()
+            .set_modified(SystemTime::UNIX_EPOCH)
+            .unwrap();
+        cache.get_or_render(&new, || Ok("b".repeat(100))).unwrap();
+
+        let len = fs::metadata(cache.entry_path(&new)).unwrap().len();
+        let cache = Cache::new(cache.dir(), len);
+        assert_eq!(cache.trim().unwrap().entries, 1);
+        assert!(!old_path.exists());
+        assert!(cache.entry_path(&new).exists());
+
+        assert_eq!(cache.clear().unwrap().entries, 1);
+        fs::remove_dir_all(cache.dir()).unwrap();
+    }
+}
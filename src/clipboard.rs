@@ -10,8 +10,9 @@ use arboard::SetExtLinux;
 /// Copies HTML content to the clipboard.
 ///
 /// On all platforms, this sets the HTML MIME type so rich text editors
-/// can paste the formatted content.
-pub fn copy_html(html: &str) -> Result<(), Box<dyn Error>> {
+/// can paste the formatted content. `alt_text` is registered as the plain
+/// text flavor for apps that don't accept HTML.
+pub fn copy_html(html: &str, alt_text: &str) -> Result<(), Box<dyn Error>> {
     let mut clipboard = Clipboard::new()?;
 
     // On Linux, we need to fork to keep clipboard content available after process exits
@@ -20,7 +21,10 @@ pub fn copy_html(html: &str) -> Result<(), Box<dyn Error>> {
         not(any(target_os = "macos", target_os = "android", target_os = "emscripten"))
     ))]
     {
-        clipboard.set().wait().html(html.to_string(), None)?;
+        clipboard
+            .set()
+            .wait()
+            .html(html.to_string(), Some(alt_text.to_string()))?;
     }
 
     // On macOS and Windows, simple set_html works
@@ -31,8 +35,62 @@ pub fn copy_html(html: &str) -> Result<(), Box<dyn Error>> {
         target_os = "emscripten"
     ))]
     {
-        clipboard.set_html(html, None)?;
+        clipboard.set_html(html, Some(alt_text))?;
     }
 
     Ok(())
 }
+
+/// Clipboard handle kept open across several copies, for `--watch`.
+///
+/// The process stays alive, so the content is served without forking or
+/// waiting for another app to take ownership of the clipboard.
+pub struct ClipboardSession {
+    clipboard: Clipboard,
+}
+
+impl ClipboardSession {
+    pub fn new() -> Result<Self, Box<dyn Error>> {
+        Ok(Self {
+            clipboard: Clipboard::new()?,
+        })
+    }
+
+    /// Replaces the clipboard contents with HTML and its plain text flavor.
+    pub fn copy_html(&mut self, html: &str, alt_text: &str) -> Result<(), Box<dyn Error>> {
+        self.clipboard.set_html(html, Some(alt_text))?;
+        Ok(())
+    }
+}
+
+/// Copies plain text to the clipboard.
+pub fn copy_text(text: &str) -> Result<(), Box<dyn Error>> {
+    let mut clipboard = Clipboard::new()?;
+
+    // On Linux, we need to fork to keep clipboard content available after process exits
+    #[cfg(all(
+        unix,
+        not(any(target_os = "macos", target_os = "android", target_os = "emscripten"))
+    ))]
+    {
+        clipboard.set().wait().text(text.to_string())?;
+    }
+
+    #[cfg(any(
+        target_os = "macos",
+        target_os = "windows",
+        target_os = "android",
+        target_os = "emscripten"
+    ))]
+    {
+        clipboard.set_text(text)?;
+    }
+
+    Ok(())
+}
+
+/// Reads the HTML flavor from the clipboard.
+pub fn read_html() -> Result<String, Box<dyn Error>> {
+    let mut clipboard = Clipboard::new()?;
+    Ok(clipboard.get().html()?)
+}
@@ -0,0 +1,554 @@
+//! CSS inlining - resolves a stylesheet into per-element `style` attributes.
+//!
+//! Google Docs, Gmail, Outlook and Teams drop `<style>` blocks and classes on
+//! paste, so the GitHub stylesheet only survives if every element carries its
+//! computed declarations inline.
+
+use kuchikiki::traits::*;
+use kuchikiki::{ElementData, NodeDataRef, NodeRef, Selector, Selectors, Specificity};
+use std::collections::HashMap;
+
+/// Class of the container element the stylesheet's selectors are scoped to.
+const ROOT_CLASS: &str = "markdown-body";
+
+/// Pseudo-classes that depend on the reader's interaction, which inlining
+/// can't capture.
+const DYNAMIC_PSEUDO_CLASSES: [&str; 7] = [
+    ":hover",
+    ":active",
+    ":focus",
+    ":visited",
+    ":link",
+    ":any-link",
+    ":target",
+];
+
+/// Maximum nesting of `var()` references before a value is considered invalid.
+const MAX_VAR_DEPTH: usize = 16;
+
+/// A single `property: value` declaration.
+#[derive(Debug, Clone, PartialEq)]
+struct Declaration {
+    property: String,
+    value: String,
+    important: bool,
+}
+
+/// A compiled selector with the declarations of the rule it came from.
+struct StyleRule {
+    selector: Selector,
+    specificity: Specificity,
+    /// Source order of the rule, used as the cascade tie-breaker.
+    /// Also indexes `Stylesheet::declarations`.
+    index: usize,
+}
+
+/// A parsed stylesheet, ready to be matched against elements.
+struct Stylesheet {
+    rules: Vec<StyleRule>,
+    declarations: Vec<Vec<Declaration>>,
+}
+
+/// Content with the stylesheet resolved into `style` attributes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inlined {
+    pub html: String,
+    /// Declarations of the `markdown-body` container, for whichever element
+    /// holds the content.
+    pub container_style: String,
+}
+
+/// Writes the stylesheet's computed declarations into the `style` attribute
+/// of each element of `html`, as if it sat in a `markdown-body` container.
+///
+/// Custom properties are resolved, since pasted content can't rely on them.
+/// Existing `style` attributes (e.g. from syntax highlighting) take precedence
+/// over the stylesheet unless a declaration is `!important`.
+pub fn inline_css(html: &str, css: &str) -> Inlined {
+    let stylesheet = Stylesheet::parse(css);
+    let document =
+        kuchikiki::parse_html().one(format!(r#"<div class="{ROOT_CLASS}">{html}</div>"#));
+    let Ok(root) = document.select_first(&format!("div.{ROOT_CLASS}")) else {
+        return Inlined {
+            html: html.to_string(),
+            container_style: String::new(),
+        };
+    };
+
+    apply_styles(root.as_node(), &stylesheet, &HashMap::new());
+    let container_style = root
+        .attributes
+        .borrow()
+        .get("style")
+        .unwrap_or_default()
+        .to_string();
+    Inlined {
+        html: root
+            .as_node()
+            .children()
+            .map(|child| child.to_string())
+            .collect(),
+        container_style,
+    }
+}
+
+/// Wraps inlined content in a `markdown-body` container carrying its style.
+pub fn wrap(inlined: &Inlined) -> String {
+    format!(
+        r#"<div class="{ROOT_CLASS}" style="{}">{}</div>"#,
+        inlined.container_style, inlined.html
+    )
+}
+
+/// What's left of `css` once [`inline_css`] has applied it: at-rules other
+/// than the light color scheme's `@media`, and rules for pseudo-elements and
+/// interactive pseudo-classes such as `:hover`, which no `style` attribute
+/// can express. Custom properties are kept too, for those rules to use.
+pub fn non_inlinable_rules(css: &str) -> String {
+    let mut out = String::new();
+    collect_non_inlinable(&strip_comments(css), &mut out);
+    out
+}
+
+fn collect_non_inlinable(css: &str, out: &mut String) {
+    let mut rest = css;
+    while let Some(open) = find_top_level(rest, &['{', ';']) {
+        let prelude = rest[..open].trim();
+        if rest.as_bytes()[open] == b';' {
+            out.push_str(&format!("{prelude};\n"));
+            rest = &rest[open + 1..];
+            continue;
+        }
+        let Some(close) = matching_brace(rest, open) else {
+            break;
+        };
+        let block = rest[open + 1..close].trim();
+        rest = &rest[close + 1..];
+
+        match prelude.strip_prefix("@media") {
+            Some(query) if media_matches(query) => {
+                let mut inner = String::new();
+                collect_non_inlinable(block, &mut inner);
+                if !inner.is_empty() {
+                    out.push_str(&format!("{prelude}{{\n{inner}}}\n"));
+                }
+            }
+            _ if prelude.starts_with('@') => out.push_str(&format!("{prelude}{{{block}}}\n")),
+            _ => {
+                let (kept, inlined): (Vec<_>, Vec<_>) = split_top_level(prelude, ',')
+                    .into_iter()
+                    .map(str::trim)
+                    .partition(|selector| !is_inlinable(selector));
+                if !kept.is_empty() {
+                    out.push_str(&format!("{}{{{block}}}\n", kept.join(",")));
+                }
+                let custom: Vec<_> = parse_declarations(block)
+                    .into_iter()
+                    .filter(|decl| decl.property.starts_with("--"))
+                    .map(|decl| format!("{}:{}", decl.property, decl.value))
+                    .collect();
+                if !custom.is_empty() && !inlined.is_empty() {
+                    out.push_str(&format!("{}{{{}}}\n", inlined.join(","), custom.join(";")));
+                }
+            }
+        }
+    }
+}
+
+/// Whether inlining applies `selector` fully: it compiles, and doesn't
+/// depend on the reader's interaction.
+fn is_inlinable(selector: &str) -> bool {
+    Selectors::compile(selector).is_ok()
+        && !DYNAMIC_PSEUDO_CLASSES
+            .iter()
+            .any(|pseudo| selector.contains(pseudo))
+}
+
+/// Computes and writes the style of `node` and its descendants.
+fn apply_styles(node: &NodeRef, stylesheet: &Stylesheet, inherited: &HashMap<String, String>) {
+    let Some(element) = node.clone().into_element_ref() else {
+        return;
+    };
+
+    let declarations = stylesheet.cascade(&element);
+
+    // Custom properties inherit, so children see this element's definitions
+    let mut vars = inherited.clone();
+    for decl in &declarations {
+        if decl.property.starts_with("--") {
+            vars.insert(decl.property.clone(), decl.value.clone());
+        }
+    }
+
+    let style: Vec<String> = declarations
+        .iter()
+        .filter(|d| !d.property.starts_with("--") && !is_vendor_prefixed(&d.property))
+        .filter_map(|d| {
+            let value = resolve_vars(&d.value, &vars, 0)?;
+            // Keep the attribute free of double quotes (font-family names)
+            Some(format!("{}:{}", d.property, value.replace('"', "'")))
+        })
+        .collect();
+
+    if !style.is_empty() {
+        element
+            .attributes
+            .borrow_mut()
+            .insert("style", style.join(";"));
+    }
+
+    for child in node.children() {
+        apply_styles(&child, stylesheet, &vars);
+    }
+}
+
+impl Stylesheet {
+    fn parse(css: &str) -> Self {
+        let mut stylesheet = Stylesheet {
+            rules: Vec::new(),
+            declarations: Vec::new(),
+        };
+        stylesheet.parse_rules(&strip_comments(css));
+        stylesheet
+    }
+
+    /// Parses a list of rules, descending into matching `@media` blocks.
+    fn parse_rules(&mut self, css: &str) {
+        let mut rest = css;
+        while let Some(open) = find_top_level(rest, &['{', ';']) {
+            let prelude = rest[..open].trim();
+            if rest.as_bytes()[open] == b';' {
+                // Statement at-rule such as @import or @charset
+                rest = &rest[open + 1..];
+                continue;
+            }
+            let Some(close) = matching_brace(rest, open) else {
+                break;
+            };
+            let block = &rest[open + 1..close];
+            rest = &rest[close + 1..];
+
+            if let Some(query) = prelude.strip_prefix("@media") {
+                if media_matches(query) {
+                    self.parse_rules(block);
+                }
+            } else if !prelude.starts_with('@') {
+                self.add_rule(prelude, block);
+            }
+        }
+    }
+
+    fn add_rule(&mut self, prelude: &str, block: &str) {
+        let declarations = parse_declarations(block);
+        if declarations.is_empty() {
+            return;
+        }
+        let index = self.declarations.len();
+        self.declarations.push(declarations);
+
+        // Compile selectors one by one so an unsupported one (e.g. `::before`)
+        // doesn't discard the rest of the list
+        for selector in split_top_level(prelude, ',') {
+            let Ok(Selectors(compiled)) = Selectors::compile(selector.trim()) else {
+                continue;
+            };
+            for selector in compiled {
+                self.rules.push(StyleRule {
+                    specificity: selector.specificity(),
+                    selector,
+                    index,
+                });
+            }
+        }
+    }
+
+    /// Returns the declarations that apply to `element` in cascade order,
+    /// with later entries overriding earlier ones for the same property.
+    fn cascade(&self, element: &NodeDataRef<ElementData>) -> Vec<Declaration> {
+        let mut matched: Vec<&StyleRule> = self
+            .rules
+            .iter()
+            .filter(|rule| rule.selector.matches(element))
+            .collect();
+        matched.sort_by_key(|rule| (rule.specificity, rule.index));
+
+        let mut normal = Vec::new();
+        let mut important = Vec::new();
+        for rule in matched {
+            for decl in &self.declarations[rule.index] {
+                if decl.important {
+                    important.push(decl.clone());
+                } else {
+                    normal.push(decl.clone());
+                }
+            }
+        }
+
+        let inline = element
+            .attributes
+            .borrow()
+            .get("style")
+            .map(parse_declarations)
+            .unwrap_or_default();
+
+        let mut result: Vec<Declaration> = Vec::new();
+        for decl in normal.into_iter().chain(inline).chain(important) {
+            match result.iter_mut().find(|d| d.property == decl.property) {
+                Some(existing) => *existing = decl,
+                None => result.push(decl),
+            }
+        }
+        result
+    }
+}
+
+/// Only the light color scheme is inlined; other media queries never match.
+fn media_matches(query: &str) -> bool {
+    let query: String = query.chars().filter(|c| !c.is_whitespace()).collect();
+    query == "(prefers-color-scheme:light)"
+}
+
+fn is_vendor_prefixed(property: &str) -> bool {
+    ["-webkit-", "-moz-", "-ms-"]
+        .iter()
+        .any(|prefix| property.starts_with(prefix))
+}
+
+/// Parses the body of a rule (or a `style` attribute) into declarations.
+fn parse_declarations(block: &str) -> Vec<Declaration> {
+    split_top_level(block, ';')
+        .into_iter()
+        .filter_map(|decl| {
+            let (property, value) = decl.split_once(':')?;
+            let property = property.trim();
+            let value = value.trim();
+            let (value, important) = match value.strip_suffix("!important") {
+                Some(v) => (v.trim_end(), true),
+                None => (value, false),
+            };
+            if property.is_empty() || value.is_empty() {
+                return None;
+            }
+            // Custom property names are case-sensitive
+            let property = if property.starts_with("--") {
+                property.to_string()
+            } else {
+                property.to_ascii_lowercase()
+            };
+            Some(Declaration {
+                property,
+                value: value.to_string(),
+                important,
+            })
+        })
+        .collect()
+}
+
+/// Substitutes `var(--name, fallback)` references.
+/// Returns `None` if a variable is undefined and has no fallback.
+fn resolve_vars(value: &str, vars: &HashMap<String, String>, depth: usize) -> Option<String> {
+    if depth > MAX_VAR_DEPTH {
+        return None;
+    }
+    let Some(start) = value.find("var(") else {
+        return Some(value.to_string());
+    };
+    let open = start + "var".len();
+    let close = matching_paren(value, open)?;
+    let inner = &value[open + 1..close];
+    let (name, fallback) = match inner.split_once(',') {
+        Some((name, fallback)) => (name.trim(), Some(fallback.trim())),
+        None => (inner.trim(), None),
+    };
+
+    let replacement = match vars.get(name) {
+        Some(v) => resolve_vars(v, vars, depth + 1)?,
+        None => resolve_vars(fallback?, vars, depth + 1)?,
+    };
+    let rest = resolve_vars(&value[close + 1..], vars, depth)?;
+    Some(format!("{}{replacement}{rest}", &value[..start]))
+}
+
+/// Removes `/* ... */` comments.
+fn strip_comments(css: &str) -> String {
+    let mut out = String::with_capacity(css.len());
+    let mut rest = css;
+    while let Some(start) = rest.find("/*") {
+        out.push_str(&rest[..start]);
+        match rest[start + 2..].find("*/") {
+            Some(end) => rest = &rest[start + 2 + end + 2..],
+            None => return out,
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Finds the first of `targets` outside quotes, parentheses and brackets.
+fn find_top_level(s: &str, targets: &[char]) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(' | '[') => depth += 1,
+            (None, ')' | ']') => depth = depth.saturating_sub(1),
+            (None, c) if depth == 0 && targets.contains(&c) => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Splits on `separator` outside quotes, parentheses and brackets.
+fn split_top_level(s: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut rest = s;
+    while let Some(i) = find_top_level(rest, &[separator]) {
+        parts.push(&rest[..i]);
+        rest = &rest[i + 1..];
+    }
+    parts.push(rest);
+    parts
+}
+
+/// Returns the index of the `}` closing the `{` at `open`.
+fn matching_brace(s: &str, open: usize) -> Option<usize> {
+    matching_delimiter(s, open, '{', '}')
+}
+
+/// Returns the index of the `)` closing the `(` at `open`.
+fn matching_paren(s: &str, open: usize) -> Option<usize> {
+    matching_delimiter(s, open, '(', ')')
+}
+
+fn matching_delimiter(s: &str, open: usize, left: char, right: char) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut quote: Option<char> = None;
+    for (i, c) in s[open..].char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, c) if c == left => depth += 1,
+            (None, c) if c == right => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::target::GITHUB_CSS;
+
+    #[test]
+    fn test_inline_simple_rule() {
+        let result = inline_css(
+            "<h1>Title</h1>",
+            ".markdown-body { margin: 0; } .markdown-body h1 { color: red; }",
+        );
+        assert_eq!(result.html, r#"<h1 style="color:red">Title</h1>"#);
+        assert_eq!(result.container_style, "margin:0");
+        assert_eq!(
+            wrap(&result),
+            r#"<div class="markdown-body" style="margin:0"><h1 style="color:red">Title</h1></div>"#
+        );
+    }
+
+    #[test]
+    fn test_specificity_and_order() {
+        let css = "p { color: red; } .markdown-body p { color: blue; } p { color: green; }";
+        let result = inline_css("<p>x</p>", css).html;
+        assert!(result.contains(r#"<p style="color:blue">"#));
+    }
+
+    #[test]
+    fn test_existing_style_wins_unless_important() {
+        let css = "pre { color: red; background: gray !important; }";
+        let result = inline_css(r#"<pre style="color:black;background:white">x</pre>"#, css).html;
+        assert!(result.contains(r#"style="color:black;background:gray""#));
+    }
+
+    #[test]
+    fn test_custom_properties_resolved() {
+        let css = ".markdown-body { --fg: #123456; } a { color: var(--fg); border: var(--missing, 1px solid) }";
+        let result = inline_css(r#"<a href="x">link</a>"#, css).html;
+        assert!(result.contains(r#"style="color:#123456;border:1px solid""#));
+        // Custom properties themselves are not emitted
+        assert!(!result.contains("--fg"));
+    }
+
+    #[test]
+    fn test_undefined_var_drops_declaration() {
+        let result = inline_css("<p>x</p>", "p { color: var(--nope); margin: 0; }").html;
+        assert!(result.contains(r#"<p style="margin:0">"#));
+    }
+
+    #[test]
+    fn test_media_queries() {
+        let css = "@media (prefers-color-scheme: dark) { p { color: white; } }
+                   @media (prefers-color-scheme: light) { p { color: black; } }";
+        let result = inline_css("<p>x</p>", css).html;
+        assert!(result.contains(r#"<p style="color:black">"#));
+    }
+
+    #[test]
+    fn test_unsupported_selector_keeps_others() {
+        let css = "p::before, em { font-style: normal; }";
+        let result = inline_css("<p><em>x</em></p>", css).html;
+        assert!(result.contains(r#"<em style="font-style:normal">"#));
+        assert!(result.contains("<p>"));
+    }
+
+    #[test]
+    fn test_non_inlinable_rules() {
+        let css = "@import url(x.css);
+                   .markdown-body { --fg: red; margin: 0; }
+                   p, a:hover { color: var(--fg); }
+                   p::before { content: 'x'; }
+                   @media (prefers-color-scheme: light) { p { color: black; } a:focus { outline: 0; } }
+                   @media (prefers-color-scheme: dark) { p { color: white; } }";
+        assert_eq!(
+            non_inlinable_rules(css),
+            "@import url(x.css);\n\
+             .markdown-body{--fg:red}\n\
+             a:hover{color: var(--fg);}\n\
+             p::before{content: 'x';}\n\
+             @media (prefers-color-scheme: light){\na:focus{outline: 0;}\n}\n\
+             @media (prefers-color-scheme: dark){p { color: white; }}\n"
+        );
+        assert_eq!(
+            non_inlinable_rules("p, .markdown-body h1 { color: red; }"),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_strip_comments() {
+        assert_eq!(strip_comments("a /* b */ c /* d"), "a  c ");
+    }
+
+    #[test]
+    fn test_github_stylesheet() {
+        let html = "<h1>Title</h1><table><thead><tr><th>A</th></tr></thead>\
+                    <tbody><tr><td>1</td></tr></tbody></table><blockquote><p>q</p></blockquote>";
+        let result = inline_css(html, GITHUB_CSS);
+        assert!(result.html.starts_with("<h1 style=\""));
+        assert!(result.container_style.contains("font-family:"));
+        let result = result.html;
+        assert!(result.contains("<h1 style=\""));
+        assert!(result.contains("border-bottom:"));
+        assert!(result.contains("<td style=\""));
+        assert!(result.contains("<blockquote style=\""));
+        assert!(!result.contains("var("));
+    }
+}
@@ -0,0 +1,235 @@
+//! ```` ```csv ```` and ```` ```tsv ```` blocks rendered as tables.
+//!
+//! Fields are split as RFC 4180 describes: a field in double quotes may hold
+//! delimiters, line breaks and doubled `""` quotes, and keeps its spaces.
+//! Spaces around unquoted fields, as in `a, b`, are dropped. The info string
+//! can set `header=false` when the first record is data, and `delimiter=;`
+//! for anything but commas or tabs.
+//!
+//! The table is emitted the way comrak emits GFM tables, so it gets the same
+//! styling. Columns holding only numbers are right-aligned.
+
+use crate::parser::{html_escape, info_attributes};
+
+/// Renders a CSV block as an HTML table. `language` is `csv` or `tsv`, and
+/// `info` the whole info string with its attributes.
+///
+/// Fails on invalid attributes and unterminated quotes, for the block to be
+/// left as code.
+pub fn to_html(language: &str, info: &str, source: &str) -> Result<String, String> {
+    let attributes = info_attributes(info);
+    let mut delimiter = if language == "tsv" { '\t' } else { ',' };
+    if let Some(value) = attributes.get("delimiter") {
+        let mut chars = value.chars();
+        delimiter = match (chars.next(), chars.next()) {
+            (Some(c), None) if c != '"' && c != '\n' => c,
+            _ if value == "tab" => '\t',
+            _ => return Err(format!("invalid delimiter '{value}'")),
+        };
+    }
+    let header = match attributes.get("header").map(String::as_str) {
+        None | Some("true") => true,
+        Some("false") => false,
+        Some(value) => return Err(format!("invalid header '{value}'")),
+    };
+
+    let mut records = parse(source, delimiter)?;
+    let columns = records.iter().map(Vec::len).max().unwrap_or(0);
+    if columns == 0 {
+        return Err("no records".to_string());
+    }
+    for record in &mut records {
+        record.resize(columns, String::new());
+    }
+    let (head, body) = if header {
+        records.split_at(1)
+    } else {
+        records.split_at(0)
+    };
+    let numeric: Vec<bool> = (0..columns)
+        .map(|column| {
+            let mut cells = body
+                .iter()
+                .map(|r| r[column].trim())
+                .filter(|c| !c.is_empty());
+            let first = cells.next();
+            first.is_some_and(is_number) && cells.all(is_number)
+        })
+        .collect();
+
+    let mut html = String::from("<table>\n");
+    if let Some(record) = head.first() {
+        html.push_str("<thead>\n");
+        push_row(&mut html, "th", record, &numeric);
+        html.push_str("</thead>\n");
+    }
+    if !body.is_empty() {
+        html.push_str("<tbody>\n");
+        for record in body {
+            push_row(&mut html, "td", record, &numeric);
+        }
+        html.push_str("</tbody>\n");
+    }
+    html.push_str("</table>");
+    Ok(html)
+}
+
+fn push_row(html: &mut String, tag: &str, record: &[String], numeric: &[bool]) {
+    html.push_str("<tr>\n");
+    for (cell, numeric) in record.iter().zip(numeric) {
+        let align = if *numeric { r#" align="right""# } else { "" };
+        let cell = html_escape(cell).replace('\n', "<br>");
+        html.push_str(&format!("<{tag}{align}>{cell}</{tag}>\n"));
+    }
+    html.push_str("</tr>\n");
+}
+
+/// Splits CSV into records of fields, skipping blank lines.
+fn parse(source: &str, delimiter: char) -> Result<Vec<Vec<String>>, String> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    // Whether anything was read since the start of the record, and whether
+    // the current field was quoted
+    let (mut in_record, mut quoted) = (false, false);
+    // Quoted fields are kept as they are
+    let finish = |field: &mut String, quoted: bool| {
+        let field = std::mem::take(field);
+        if quoted {
+            field
+        } else {
+            field.trim().to_string()
+        }
+    };
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            // Spaces before the opening quote are tolerated, like after it
+            '"' if !quoted && field.trim().is_empty() => {
+                field.clear();
+                quoted = true;
+                // Quoted field, up to a quote that isn't doubled
+                loop {
+                    match chars.next() {
+                        Some('"') if chars.peek() == Some(&'"') => {
+                            chars.next();
+                            field.push('"');
+                        }
+                        Some('"') => break,
+                        Some('\r') if chars.peek() == Some(&'\n') => {}
+                        Some(c) => field.push(c),
+                        None => return Err("unterminated quoted field".to_string()),
+                    }
+                }
+            }
+            c if c == delimiter => {
+                record.push(finish(&mut field, quoted));
+                in_record = true;
+                quoted = false;
+                continue;
+            }
+            '\r' if chars.peek() == Some(&'\n') => continue,
+            c if quoted && c.is_whitespace() && c != '\n' => {}
+            '\n' => {
+                if in_record {
+                    record.push(finish(&mut field, quoted));
+                    records.push(std::mem::take(&mut record));
+                }
+                in_record = false;
+                quoted = false;
+                continue;
+            }
+            c if quoted => return Err(format!("'{c}' after a closing quote")),
+            c => field.push(c),
+        }
+        in_record = true;
+    }
+    if in_record {
+        record.push(finish(&mut field, quoted));
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// Whether a cell reads as a number: digits with an optional sign, decimal
+/// point, thousands separators and percent sign.
+fn is_number(cell: &str) -> bool {
+    let digits = cell.strip_suffix('%').unwrap_or(cell);
+    let digits = digits.strip_prefix(['-', '+']).unwrap_or(digits);
+    digits.starts_with(|c: char| c.is_ascii_digit() || c == '.')
+        && digits.replace(',', "").parse::<f64>().is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rfc_4180() {
+        let records = parse(
+            "name,note\r\n\"Doe, Jane\",\"said \"\"hi\"\"\nthen left\"\r\n\nx,\n",
+            ',',
+        )
+        .unwrap();
+        assert_eq!(
+            records,
+            [
+                vec!["name", "note"],
+                vec!["Doe, Jane", "said \"hi\"\nthen left"],
+                vec!["x", ""],
+            ]
+        );
+        assert_eq!(parse("a\tb", '\t').unwrap(), [vec!["a", "b"]]);
+        // Spaces are only significant in quotes
+        assert_eq!(
+            parse("a , \"  b \",c  \n", ',').unwrap(),
+            [vec!["a", "  b ", "c"]]
+        );
+        assert!(parse("a,\"b", ',').is_err());
+        assert_eq!(
+            parse("\"a\"b,c", ','),
+            Err("'b' after a closing quote".to_string())
+        );
+        assert!(parse("\"a\" \"b\"", ',').is_err());
+    }
+
+    #[test]
+    fn test_numeric_columns_right_aligned() {
+        let html = to_html(
+            "csv",
+            "csv",
+            "item,count,share\napple,1,2.5%\npear,\"1,200\",-3\n",
+        )
+        .unwrap();
+        assert!(
+            html.starts_with(
+                "<table>\n<thead>\n<tr>\n<th>item</th>\n<th align=\"right\">count</th>"
+            )
+        );
+        assert!(html.contains(
+            "<td>pear</td>\n<td align=\"right\">1,200</td>\n<td align=\"right\">-3</td>"
+        ));
+        assert!(html.ends_with("</tbody>\n</table>"));
+
+        assert!(is_number("0.5") && is_number(".5") && is_number("+12"));
+        assert!(!is_number("inf") && !is_number("1.2.3") && !is_number("v2"));
+    }
+
+    #[test]
+    fn test_attributes() {
+        let html = to_html("csv", "csv header=false delimiter=;", "a;<b>\nc;d").unwrap();
+        assert!(!html.contains("<thead>"));
+        assert!(html.contains("<td>a</td>\n<td>&lt;b&gt;</td>"));
+        let html = to_html("csv", "csv header=false", "\" indented\", plain ").unwrap();
+        assert!(html.contains("<td> indented</td>\n<td>plain</td>"));
+
+        let html = to_html("tsv", "tsv", "a\tb\n1").unwrap();
+        // Headers are aligned with their column
+        assert!(html.contains("<th align=\"right\">a</th>\n<th>b</th>"));
+        assert!(html.contains("<td align=\"right\">1</td>\n<td></td>"));
+
+        assert!(to_html("csv", "csv delimiter=;;", "a").is_err());
+        assert!(to_html("csv", "csv header=no", "a").is_err());
+        assert!(to_html("csv", "csv", "\n").is_err());
+    }
+}
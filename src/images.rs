@@ -8,72 +8,116 @@ use std::io::Read;
 use std::path::Path;
 use std::sync::LazyLock;
 
+use crate::pool;
+use crate::report::{ImageReport, Report};
+use crate::target::ImageLimits;
+
 // Static regex pattern for matching img tags
 static IMG_TAG_RE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r#"<img([^>]*)\ssrc="([^"]+)"([^>]*)>"#).unwrap());
 
 /// Inlines all images in the HTML by converting URLs to base64 data URIs.
 /// This ensures pasted content contains the actual image data.
-pub fn inline_images(html: &str, base_path: Option<&Path>) -> String {
-    let mut result = html.to_string();
-    let mut cache: HashMap<String, String> = HashMap::new();
-
-    // Collect all matches first
-    let matches: Vec<_> = IMG_TAG_RE
-        .captures_iter(html)
-        .map(|cap| {
-            let full = cap.get(0).unwrap();
-            let before = cap.get(1).unwrap().as_str();
-            let src = cap.get(2).unwrap().as_str();
-            let after = cap.get(3).unwrap().as_str();
-            (
-                full.start(),
-                full.end(),
-                before.to_string(),
-                src.to_string(),
-                after.to_string(),
-            )
-        })
-        .collect();
+/// Images that can't be fetched or fall outside `limits` (format or size) keep
+/// their original `src`. Each distinct image is recorded in `report`.
+pub fn inline_images(
+    html: &str,
+    base_path: Option<&Path>,
+    limits: &ImageLimits,
+    report: &mut Report,
+) -> String {
+    // The target doesn't take inlined images at all, so nothing is falling back
+    if limits.formats.is_empty() {
+        return html.to_string();
+    }
 
-    // Process in reverse order to preserve indices
-    for (start, end, before, src, after) in matches.into_iter().rev() {
+    // Collect each distinct image once, in document order
+    let mut sources: Vec<String> = Vec::new();
+    for caps in IMG_TAG_RE.captures_iter(html) {
+        let src = &caps[2];
         // Skip if already a data URI
-        if src.starts_with("data:") {
-            continue;
+        if !src.starts_with("data:") && !sources.iter().any(|s| s == src) {
+            sources.push(src.to_string());
         }
+    }
 
-        // Check cache first
-        let data_uri = if let Some(cached) = cache.get(&src) {
-            cached.clone()
-        } else {
-            let uri = fetch_and_encode(&src, base_path).unwrap_or_else(|| src.clone());
-            cache.insert(src.clone(), uri.clone());
-            uri
-        };
-
-        let replacement = format!(r#"<img{before} src="{data_uri}"{after}>"#);
-        result.replace_range(start..end, &replacement);
+    // Fetch them all concurrently
+    let jobs = sources
+        .iter()
+        .map(|src| {
+            let src = src.clone();
+            let base_path = base_path.map(Path::to_path_buf);
+            let limits = limits.clone();
+            move || {
+                let mut image = ImageReport {
+                    src: src.clone(),
+                    ..ImageReport::default()
+                };
+                let fetched = fetch_and_encode(&src, base_path.as_deref(), &limits, &mut image);
+                (image, fetched)
+            }
+        })
+        .collect();
+    let results = pool::run_all(jobs);
+
+    let mut data_uris: HashMap<&str, String> = HashMap::new();
+    for (src, result) in sources.iter().zip(results) {
+        let (mut image, fetched) = result.unwrap_or_else(|e| {
+            let image = ImageReport {
+                src: src.clone(),
+                ..ImageReport::default()
+            };
+            (image, Err(e))
+        });
+        match fetched {
+            Ok(uri) => {
+                image.inlined = true;
+                data_uris.insert(src, uri);
+            }
+            Err(e) => image.error = Some(e),
+        }
+        report.images.push(image);
     }
 
-    result
+    // Splice the data URIs back in; images that weren't inlined keep their src
+    IMG_TAG_RE
+        .replace_all(html, |caps: &regex::Captures| {
+            match data_uris.get(&caps[2]) {
+                Some(uri) => format!(r#"<img{} src="{uri}"{}>"#, &caps[1], &caps[3]),
+                None => caps[0].to_string(),
+            }
+        })
+        .into_owned()
 }
 
 /// Fetches an image and encodes it as a base64 data URI.
-fn fetch_and_encode(src: &str, base_path: Option<&Path>) -> Option<String> {
+///
+/// Where the image was read from, its type and size are filled into `image`
+/// as they become known, even when the image ends up not being inlined.
+fn fetch_and_encode(
+    src: &str,
+    base_path: Option<&Path>,
+    limits: &ImageLimits,
+    image: &mut ImageReport,
+) -> Result<String, String> {
     if src.starts_with("http://") || src.starts_with("https://") {
-        fetch_remote_image(src)
+        fetch_remote_image(src, limits, image)
     } else {
-        fetch_local_image(src, base_path)
+        fetch_local_image(src, base_path, limits, image)
     }
 }
 
 /// Fetches a remote image via HTTP and encodes as data URI.
-fn fetch_remote_image(url: &str) -> Option<String> {
+fn fetch_remote_image(
+    url: &str,
+    limits: &ImageLimits,
+    image: &mut ImageReport,
+) -> Result<String, String> {
+    image.resolved = Some(url.to_string());
     let response = ureq::get(url)
         .timeout(std::time::Duration::from_secs(10))
         .call()
-        .ok()?;
+        .map_err(|e| e.to_string())?;
 
     let content_type = response
         .header("Content-Type")
@@ -82,32 +126,42 @@ fn fetch_remote_image(url: &str) -> Option<String> {
 
     // Read response body
     let mut bytes = Vec::new();
-    // Limit to 10MB to prevent memory exhaustion
+    // Read one byte past the limit to detect oversized images without exhausting memory
     response
         .into_reader()
-        .take(10 * 1024 * 1024)
+        .take(limits.max_bytes as u64 + 1)
         .read_to_end(&mut bytes)
-        .ok()?;
+        .map_err(|e| e.to_string())?;
+
+    image.mime = Some(content_type.clone());
+    image.bytes = Some(bytes.len());
+    check_limits(limits, &content_type, bytes.len())?;
 
     let encoded = STANDARD.encode(&bytes);
-    Some(format!("data:{content_type};base64,{encoded}"))
+    Ok(format!("data:{content_type};base64,{encoded}"))
 }
 
 /// Reads a local image file and encodes as data URI.
-fn fetch_local_image(path: &str, base_path: Option<&Path>) -> Option<String> {
+fn fetch_local_image(
+    path: &str,
+    base_path: Option<&Path>,
+    limits: &ImageLimits,
+    image: &mut ImageReport,
+) -> Result<String, String> {
     let full_path = if let Some(base) = base_path {
         let full = base.join(path);
+        image.resolved = Some(full.display().to_string());
         // If path is absolute, we allow it (as per existing tests/behavior).
         // We only restrict relative paths to stay within the base directory.
         if Path::new(path).is_absolute() {
             full
         } else {
             // Prevent path traversal for relative paths
-            let canonical_base = base.canonicalize().ok()?;
-            let canonical_full = full.canonicalize().ok()?;
+            let canonical_base = base.canonicalize().map_err(|e| e.to_string())?;
+            let canonical_full = full.canonicalize().map_err(|e| e.to_string())?;
 
             if !canonical_full.starts_with(&canonical_base) {
-                return None;
+                return Err("outside the document's directory".to_string());
             }
             full
         }
@@ -115,11 +169,29 @@ fn fetch_local_image(path: &str, base_path: Option<&Path>) -> Option<String> {
         Path::new(path).to_path_buf()
     };
 
-    let bytes = fs::read(&full_path).ok()?;
+    image.resolved = Some(full_path.display().to_string());
     let content_type = guess_mime_type(&full_path);
+    image.mime = Some(content_type.to_string());
+    let len = fs::metadata(&full_path).map_err(|e| e.to_string())?.len();
+    let len = usize::try_from(len).unwrap_or(usize::MAX);
+    image.bytes = Some(len);
+    check_limits(limits, content_type, len)?;
+
+    let bytes = fs::read(&full_path).map_err(|e| e.to_string())?;
     let encoded = STANDARD.encode(&bytes);
 
-    Some(format!("data:{content_type};base64,{encoded}"))
+    Ok(format!("data:{content_type};base64,{encoded}"))
+}
+
+/// Explains why an image falls outside the target's limits.
+fn check_limits(limits: &ImageLimits, mime: &str, len: usize) -> Result<(), String> {
+    if limits.allows(mime, len) {
+        Ok(())
+    } else if len > limits.max_bytes {
+        Err(format!("larger than {} bytes", limits.max_bytes))
+    } else {
+        Err(format!("{mime} is not accepted by the target"))
+    }
 }
 
 /// Guesses MIME type from file extension.
@@ -152,7 +224,7 @@ mod tests {
     #[test]
     fn test_skip_data_uri() {
         let html = r#"<img src="data:image/png;base64,abc123">"#;
-        let result = inline_images(html, None);
+        let result = inline_images(html, None, &ImageLimits::default(), &mut Report::default());
         assert_eq!(result, html);
     }
 
@@ -165,7 +237,12 @@ mod tests {
         fs::write(&img_path, PNG_BYTES).unwrap();
 
         let html = r#"<img src="test.png">"#;
-        let result = inline_images(html, Some(&test_dir));
+        let result = inline_images(
+            html,
+            Some(&test_dir),
+            &ImageLimits::default(),
+            &mut Report::default(),
+        );
 
         assert!(result.starts_with(r#"<img src="data:image/png;base64,"#));
 
@@ -183,7 +260,12 @@ mod tests {
 
         // Relative path should resolve from base_dir
         let html = r#"<img src="images/test.png">"#;
-        let result = inline_images(html, Some(&test_dir));
+        let result = inline_images(
+            html,
+            Some(&test_dir),
+            &ImageLimits::default(),
+            &mut Report::default(),
+        );
 
         assert!(
             result.starts_with(r#"<img src="data:image/png;base64,"#),
@@ -210,7 +292,12 @@ mod tests {
         let other_dir = std::env::temp_dir().join("md2cb_test_other");
         fs::create_dir_all(&other_dir).unwrap();
 
-        let result = inline_images(&html, Some(&other_dir));
+        let result = inline_images(
+            &html,
+            Some(&other_dir),
+            &ImageLimits::default(),
+            &mut Report::default(),
+        );
 
         assert!(
             result.starts_with(r#"<img src="data:image/png;base64,"#),
@@ -236,6 +323,63 @@ mod tests {
         assert_eq!(guess_mime_type(Path::new("test")), "image/png");
     }
 
+    #[test]
+    fn test_image_limits_keep_original_src() {
+        let test_dir = std::env::temp_dir().join("md2cb_test_limits");
+        fs::create_dir_all(&test_dir).unwrap();
+        fs::write(test_dir.join("limits.png"), PNG_BYTES).unwrap();
+        let html = r#"<img src="limits.png">"#;
+
+        // Too large
+        let limits = ImageLimits {
+            max_bytes: 10,
+            ..ImageLimits::default()
+        };
+        let mut report = Report::default();
+        assert_eq!(
+            inline_images(html, Some(&test_dir), &limits, &mut report),
+            html
+        );
+        assert_eq!(
+            report.fallbacks(),
+            ["image limits.png: larger than 10 bytes"]
+        );
+        let image = &report.images[0];
+        assert!(!image.inlined);
+        assert_eq!(image.mime.as_deref(), Some("image/png"));
+        assert_eq!(image.bytes, Some(PNG_BYTES.len()));
+
+        // Format not accepted
+        let limits = ImageLimits {
+            formats: vec!["image/jpeg".to_string()],
+            ..ImageLimits::default()
+        };
+        let mut report = Report::default();
+        assert_eq!(
+            inline_images(html, Some(&test_dir), &limits, &mut report),
+            html
+        );
+        assert_eq!(
+            report.fallbacks(),
+            ["image limits.png: image/png is not accepted by the target"]
+        );
+
+        // Inlining disabled entirely
+        let limits = ImageLimits {
+            max_bytes: 0,
+            formats: Vec::new(),
+        };
+        let mut report = Report::default();
+        assert_eq!(
+            inline_images(html, Some(&test_dir), &limits, &mut report),
+            html
+        );
+        assert!(report.images.is_empty());
+
+        // Cleanup
+        fs::remove_dir_all(&test_dir).ok();
+    }
+
     #[test]
     fn test_path_traversal_prevention() {
         // Setup:
@@ -249,8 +393,13 @@ mod tests {
         fs::write(&secret_path, PNG_BYTES).unwrap();
 
         // Try to access ../secret.png
-        let result = fetch_local_image("../secret.png", Some(&base_dir));
-        assert!(result.is_none(), "Should block path traversal");
+        let result = fetch_local_image(
+            "../secret.png",
+            Some(&base_dir),
+            &ImageLimits::default(),
+            &mut ImageReport::default(),
+        );
+        assert!(result.is_err(), "Should block path traversal");
 
         // Cleanup
         fs::remove_dir_all(&root_dir).ok();
@@ -270,10 +419,15 @@ mod tests {
         fs::write(&img_path, PNG_BYTES).unwrap();
 
         let path_str = img_path.to_string_lossy();
-        let result = fetch_local_image(&path_str, None);
+        let result = fetch_local_image(
+            &path_str,
+            None,
+            &ImageLimits::default(),
+            &mut ImageReport::default(),
+        );
 
         assert!(
-            result.is_some(),
+            result.is_ok(),
             "Should work with absolute path and no base_path"
         );
         let uri = result.unwrap();
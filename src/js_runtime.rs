@@ -1,17 +1,179 @@
-//! Embedded JavaScript runtime for running MathJax.
+//! Embedded JavaScript runtime for running MathJax and KaTeX.
 //!
-//! Uses QuickJS via rquickjs to execute the bundled MathJax library
-//! and convert LaTeX math expressions to SVG.
+//! Uses QuickJS via rquickjs to execute the bundled math libraries and
+//! convert LaTeX math expressions to SVG or MathML (MathJax), or to HTML
+//! (KaTeX). Each library is loaded into the runtime the first time it's used.
+//!
+//! Every library exposes `convertLatexTo*(latex, display)` functions, called
+//! with the LaTeX as a JS string. They return `{ output }`, plus the `width`,
+//! `height` and `verticalAlign` of MathJax's SVG in ex, or `{ error }` with the
+//! `position` of the error in the source when the library knows it.
+//!
+//! The MathJax bundle is precompiled to QuickJS bytecode by `build.rs`, which
+//! skips parsing it at startup. Its source stays embedded as a fallback for
+//! when the bytecode is missing or can't be read.
+//!
+//! Each library is given a math preamble, the document's macro definitions,
+//! with its `set*Preamble(preamble)` function. The macros it defines are kept
+//! aside and every conversion starts from them, so whatever an expression
+//! defines is gone by the next one and a runtime renders the same whichever
+//! expressions it rendered before. A different preamble replaces the macros
+//! without reloading the library.
+//!
+//! Every conversion gets [`CONVERT_TIMEOUT`] to finish, after which QuickJS's
+//! interrupt handler aborts it. A runtime that was interrupted or ran out of
+//! memory may be left half-way through MathJax's state, so it's dropped and
+//! the next expression starts a fresh one.
+//!
+//! User [plugins](crate::plugins) get a runtime of their own, holding nothing
+//! but the plugin scripts and the `md2cb.register` function they call. Their
+//! renderers run under the same limits as the math libraries.
+
+use rquickjs::{Context, Ctx, FromJs, Function, Module, Object, Runtime, Value};
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::plugins::Script;
+use crate::svg_render::MathBox;
+
+/// MathJax version in the bundle
+pub const MATHJAX_VERSION: &str = "3.2.1";
+
+/// KaTeX version in the bundle
+pub const KATEX_VERSION: &str = "0.16.4";
 
-use rquickjs::{Context, Runtime};
-use std::cell::RefCell;
+/// Time budget of a single conversion
+const CONVERT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Errors after which a runtime can't be trusted anymore
+const FATAL_ERRORS: [&str; 1] = ["out of memory"];
 
 /// Embedded MathJax bundle (generated by scripts/build-mathjax.js)
 const MATHJAX_BUNDLE: &str = include_str!("../assets/mathjax-bundle.js");
 
+/// MathJax bundle compiled to QuickJS bytecode by build.rs (empty if that failed)
+const MATHJAX_BYTECODE: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/mathjax-bundle.qjsbc"));
+
+/// Embedded KaTeX library (`dist/katex.min.js` from the katex npm package)
+const KATEX_BUNDLE: &str = include_str!("../assets/katex.min.js");
+
+/// Exposes KaTeX with the same calling convention as the MathJax bundle,
+/// every conversion starting from the preamble's macros like MathJax.
+/// Parse errors leave out KaTeX's underlined excerpt and give the position.
+const KATEX_ENTRY: &str = r#"
+let savedMacros = {};
+let macros = {};
+globalThis.convertLatexToHtml = function(latex, display) {
+    macros = Object.assign({}, savedMacros);
+    try {
+        const html = katex.renderToString(latex, {
+            displayMode: display,
+            output: 'html',
+            throwOnError: true,
+            macros: macros,
+            globalGroup: true,
+        });
+        return { output: html };
+    } catch (e) {
+        if (e instanceof katex.ParseError && e.position !== undefined) {
+            const error = e.message.replace(/ at (position \d+|end of input): [\s\S]*$/, '');
+            return { error: error, position: e.position };
+        }
+        return { error: e.message || String(e) };
+    }
+};
+globalThis.setKatexPreamble = function(preamble) {
+    savedMacros = {};
+    const result = globalThis.convertLatexToHtml(preamble, false);
+    savedMacros = Object.assign({}, macros);
+    return result;
+};
+"#;
+
+/// Defines `md2cb.register` for plugins, and the functions listing and calling
+/// what they registered. Renderers must return a string.
+const PLUGIN_PRELUDE: &str = r#"
+(function() {
+    const renderers = new Map();
+    let registered = [];
+    const register = function(language, render) {
+        if (typeof language !== 'string' || typeof render !== 'function') {
+            throw new TypeError('md2cb.register takes a language and a render function');
+        }
+        renderers.set(language, render);
+        registered.push(language);
+    };
+    globalThis.md2cb = Object.freeze({ register: register });
+    globalThis.md2cbRegistered = function() {
+        const languages = registered;
+        registered = [];
+        return languages;
+    };
+    globalThis.md2cbRender = function(language, body, attributes) {
+        const output = renderers.get(language)(body, attributes);
+        if (typeof output !== 'string') {
+            throw new TypeError(`renderer of ${language} returned ${typeof output}, not a string`);
+        }
+        return output;
+    };
+})();
+"#;
+
+/// Math library evaluated into the runtime on first use
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Engine {
+    MathJax,
+    Katex,
+}
+
+impl Engine {
+    fn name(self) -> &'static str {
+        match self {
+            Engine::MathJax => "MathJax",
+            Engine::Katex => "KaTeX",
+        }
+    }
+
+    /// Precompiled module, if any; evaluated instead of the sources
+    fn bytecode(self) -> &'static [u8] {
+        match self {
+            Engine::MathJax => MATHJAX_BYTECODE,
+            Engine::Katex => &[],
+        }
+    }
+
+    /// Function replacing the macros conversions start from
+    fn preamble_setter(self) -> &'static str {
+        match self {
+            Engine::MathJax => "setMathJaxPreamble",
+            Engine::Katex => "setKatexPreamble",
+        }
+    }
+
+    fn sources(self) -> &'static [&'static str] {
+        match self {
+            Engine::MathJax => &[MATHJAX_BUNDLE],
+            Engine::Katex => &[KATEX_BUNDLE, KATEX_ENTRY],
+        }
+    }
+}
+
 // Thread-local JS runtime (QuickJS is not thread-safe, uses Rc internally)
 thread_local! {
     static JS_RUNTIME: RefCell<Option<JsRuntimeInner>> = const { RefCell::new(None) };
+    static PLUGIN_RUNTIME: RefCell<Option<PluginRuntime>> = const { RefCell::new(None) };
+}
+
+/// A library evaluated into a runtime, with the preamble it was last given
+struct Loaded {
+    engine: Engine,
+    preamble: String,
+    /// Why the preamble couldn't be evaluated, failing every conversion
+    /// until another preamble is set
+    preamble_error: Option<String>,
 }
 
 /// Inner runtime state
@@ -19,10 +181,16 @@ struct JsRuntimeInner {
     context: Context,
     // Keep runtime alive - Context borrows from it
     _runtime: Runtime,
+    loaded: Vec<Loaded>,
+    /// When the running conversion gets interrupted, checked by QuickJS
+    deadline: Rc<Cell<Option<Instant>>>,
+    timeout: Duration,
+    /// Set when a conversion failed in a way that may have corrupted the runtime
+    broken: Cell<bool>,
 }
 
 impl JsRuntimeInner {
-    /// Create a new JS runtime and initialize MathJax
+    /// Create a new JS runtime with no library loaded yet
     fn new() -> Result<Self, String> {
         let runtime = Runtime::new().map_err(|e| format!("Failed to create JS runtime: {e}"))?;
 
@@ -31,86 +199,383 @@ impl JsRuntimeInner {
         runtime.set_max_stack_size(8 * 1024 * 1024); // 8MB stack
         runtime.set_memory_limit(128 * 1024 * 1024); // 128MB memory
 
+        // Abort conversions that run past their deadline
+        let deadline: Rc<Cell<Option<Instant>>> = Rc::new(Cell::new(None));
+        let interrupt_at = Rc::clone(&deadline);
+        runtime.set_interrupt_handler(Some(Box::new(move || {
+            interrupt_at
+                .get()
+                .is_some_and(|deadline| Instant::now() >= deadline)
+        })));
+
         let context =
             Context::full(&runtime).map_err(|e| format!("Failed to create JS context: {e}"))?;
 
-        // Initialize MathJax bundle
-        context.with(|ctx| {
-            ctx.eval::<(), _>(MATHJAX_BUNDLE.as_bytes().to_vec())
-                .map_err(|e| format!("Failed to initialize MathJax: {e}"))
-        })?;
-
         Ok(Self {
             context,
             _runtime: runtime,
+            loaded: Vec::new(),
+            deadline,
+            timeout: CONVERT_TIMEOUT,
+            broken: Cell::new(false),
         })
     }
 
-    /// Convert LaTeX to SVG using MathJax
-    fn latex_to_svg(&self, latex: &str, display: bool) -> Result<String, String> {
+    /// Evaluate `engine`'s library unless it's already loaded, and give it
+    /// `preamble` unless it already has it
+    ///
+    /// A preamble that fails to evaluate fails every conversion made with it,
+    /// on every runtime alike, rather than rendering some without its macros.
+    fn load(&mut self, engine: Engine, preamble: &str) -> Result<(), String> {
+        let index = match self.loaded.iter().position(|l| l.engine == engine) {
+            Some(index) => index,
+            None => {
+                self.context.with(|ctx| {
+                    let bytecode = engine.bytecode();
+                    if !bytecode.is_empty() && eval_bytecode(&ctx, bytecode).is_ok() {
+                        return Ok(());
+                    }
+                    eval_sources(&ctx, engine)
+                })?;
+                // A freshly loaded library has no macros
+                self.loaded.push(Loaded {
+                    engine,
+                    preamble: String::new(),
+                    preamble_error: None,
+                });
+                self.loaded.len() - 1
+            }
+        };
+
+        if self.loaded[index].preamble != preamble {
+            let preamble_error = self
+                .convert(engine.preamble_setter(), preamble, false)
+                .err()
+                .map(|e| format!("Invalid math preamble: {e}"));
+            self.loaded[index] = Loaded {
+                engine,
+                preamble: preamble.to_string(),
+                preamble_error,
+            };
+        }
+        match &self.loaded[index].preamble_error {
+            Some(error) => Err(error.clone()),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether this runtime can still be used
+    fn is_current(&self) -> bool {
+        !self.broken.get()
+    }
+
+    /// Convert LaTeX with one of the `convertLatexTo*` functions, or set the
+    /// preamble
+    fn convert(&self, function: &str, latex: &str, display: bool) -> Result<Converted, String> {
         self.context.with(|ctx| {
-            // Escape the latex string for JavaScript
-            let escaped_latex = escape_js_string(latex);
-
-            let js_code = format!("convertLatexToSvg('{escaped_latex}', {display})");
-
-            let result: String = ctx
-                .eval(js_code.into_bytes())
-                .map_err(|e| format!("JS execution error: {e}"))?;
-
-            // Parse JSON result from MathJax
-            let parsed: serde_json::Value =
-                serde_json::from_str(&result).map_err(|e| format!("JSON parse error: {e}"))?;
-
-            if parsed["success"].as_bool() == Some(true) {
-                parsed["svg"]
-                    .as_str()
-                    .map(|s| s.to_string())
-                    .ok_or_else(|| "Missing SVG in result".to_string())
-            } else {
-                Err(parsed["error"]
-                    .as_str()
-                    .unwrap_or("Unknown MathJax error")
-                    .to_string())
+            let function: Function = ctx
+                .globals()
+                .get(function)
+                .map_err(|e| format!("Missing {function}: {e}"))?;
+
+            let result: Converted = self.timed(&ctx, || function.call((latex, display)))?;
+
+            if let Some(error) = result.error {
+                let error = match result.position {
+                    Some(position) => format!("{error} at position {}", position + 1),
+                    None => error,
+                };
+                return Err(self.check_fatal(error));
+            }
+            // TeX errors are rendered as an merror element rather than thrown
+            match tex_error(&result.output) {
+                Some(error) => Err(error.to_string()),
+                None => Ok(result),
             }
         })
     }
-}
 
-/// Escape a string for safe inclusion in JavaScript code
-fn escape_js_string(s: &str) -> String {
-    let mut result = String::with_capacity(s.len() * 2);
-    for c in s.chars() {
-        match c {
-            '\\' => result.push_str("\\\\"),
-            '\'' => result.push_str("\\'"),
-            '"' => result.push_str("\\\""),
-            '\n' => result.push_str("\\n"),
-            '\r' => result.push_str("\\r"),
-            '\t' => result.push_str("\\t"),
-            '\u{2028}' => result.push_str("\\u2028"),
-            '\u{2029}' => result.push_str("\\u2029"),
-            _ => result.push(c),
+    /// Run JS code against the conversion's deadline
+    fn timed<R>(
+        &self,
+        ctx: &Ctx<'_>,
+        run: impl FnOnce() -> rquickjs::Result<R>,
+    ) -> Result<R, String> {
+        self.deadline.set(Some(Instant::now() + self.timeout));
+        let result = run();
+        let timed_out = self
+            .deadline
+            .take()
+            .is_some_and(|deadline| Instant::now() >= deadline);
+        match result {
+            Ok(result) => Ok(result),
+            Err(_) if timed_out => {
+                self.broken.set(true);
+                Err(format!("Timed out after {}s", self.timeout.as_secs_f32()))
+            }
+            Err(e) => Err(self.check_fatal(format!("JS execution error: {}", eval_error(ctx, e)))),
         }
     }
-    result
+
+    /// Marks the runtime as broken if `error` is one it can't recover from
+    fn check_fatal(&self, error: String) -> String {
+        if FATAL_ERRORS.iter().any(|fatal| error.contains(fatal)) {
+            self.broken.set(true);
+        }
+        error
+    }
+}
+
+/// What a `convertLatexTo*` function returns
+#[derive(Debug, Default, PartialEq)]
+struct Converted {
+    /// SVG, MathML or HTML, depending on the function
+    output: String,
+    /// Size of MathJax's SVG and its depth below the baseline, in ex
+    size: MathBox,
+    error: Option<String>,
+    /// Offset of the error in the source, if the library tells
+    position: Option<usize>,
+}
+
+impl<'js> FromJs<'js> for Converted {
+    fn from_js(ctx: &Ctx<'js>, value: Value<'js>) -> rquickjs::Result<Self> {
+        let object = Object::from_js(ctx, value)?;
+        let length = |name| -> rquickjs::Result<f32> {
+            Ok(object.get::<_, Option<f32>>(name)?.unwrap_or_default())
+        };
+        Ok(Self {
+            output: object
+                .get::<_, Option<String>>("output")?
+                .unwrap_or_default(),
+            size: MathBox {
+                width: length("width")?,
+                height: length("height")?,
+                vertical_align: length("verticalAlign")?,
+            },
+            error: object.get("error")?,
+            position: object.get("position")?,
+        })
+    }
+}
+
+/// Describe a failed evaluation, with the message of the exception if one was thrown
+fn eval_error(ctx: &Ctx<'_>, error: rquickjs::Error) -> String {
+    if !error.is_exception() {
+        return error.to_string();
+    }
+    let exception = ctx.catch();
+    exception
+        .as_exception()
+        .and_then(|e| e.message())
+        .unwrap_or_else(|| format!("{exception:?}"))
+}
+
+/// Evaluate a module precompiled by build.rs
+fn eval_bytecode(ctx: &Ctx<'_>, bytecode: &'static [u8]) -> rquickjs::Result<()> {
+    // SAFETY: the bytecode was written by this QuickJS version in build.rs, and
+    // lives for the whole process as QuickJS may keep pointing into it.
+    // QuickJS rejects bytecode written by another version.
+    let module = unsafe { Module::load(ctx.clone(), bytecode)? };
+    let (_, promise) = module.eval()?;
+    promise.finish::<()>()
 }
 
-/// Convert LaTeX to SVG (public API)
-pub fn convert_latex_to_svg(latex: &str, display: bool) -> Result<String, String> {
+/// Evaluate `engine`'s library from source
+fn eval_sources(ctx: &Ctx<'_>, engine: Engine) -> Result<(), String> {
+    engine.sources().iter().try_for_each(|source| {
+        ctx.eval::<(), _>(source.as_bytes().to_vec())
+            .map_err(|e| format!("Failed to initialize {}: {e}", engine.name()))
+    })
+}
+
+/// Returns the message of the first TeX error embedded in MathJax's output.
+fn tex_error(output: &str) -> Option<&str> {
+    const ATTR: &str = "data-mjx-error=\"";
+    let start = output.find(ATTR)? + ATTR.len();
+    let end = output[start..].find('"')?;
+    Some(&output[start..start + end])
+}
+
+/// Run a conversion on the thread's JS runtime, starting it and loading
+/// `engine` with `preamble` if needed
+fn with_runtime<T>(
+    engine: Engine,
+    preamble: &str,
+    convert: impl FnOnce(&JsRuntimeInner) -> Result<T, String>,
+) -> Result<T, String> {
     JS_RUNTIME.with(|cell| {
         let mut runtime_ref = cell.borrow_mut();
 
+        if runtime_ref
+            .as_ref()
+            .is_some_and(|runtime| !runtime.is_current())
+        {
+            *runtime_ref = None;
+        }
+
         // Initialize runtime if needed
         if runtime_ref.is_none() {
             *runtime_ref = Some(JsRuntimeInner::new()?);
         }
 
         // Use the runtime
-        runtime_ref
+        let runtime = runtime_ref
+            .as_mut()
+            .ok_or_else(|| "Runtime not initialized".to_string())?;
+        runtime.load(engine, preamble)?;
+        convert(runtime)
+    })
+}
+
+/// Convert LaTeX to SVG, with the macros defined in `preamble` (public API)
+///
+/// Also returns the SVG's size and depth below the baseline, as MathJax
+/// reports them in ex.
+pub fn convert_latex_to_svg(
+    latex: &str,
+    display: bool,
+    preamble: &str,
+) -> Result<(String, MathBox), String> {
+    with_runtime(Engine::MathJax, preamble, |runtime| {
+        let converted = runtime.convert("convertLatexToSvg", latex, display)?;
+        Ok((converted.output, converted.size))
+    })
+}
+
+/// Convert LaTeX to a standalone `<math>` element (public API)
+///
+/// The TeX source is kept in the `alttext` attribute.
+pub fn convert_latex_to_mathml(
+    latex: &str,
+    display: bool,
+    preamble: &str,
+) -> Result<String, String> {
+    with_runtime(Engine::MathJax, preamble, |runtime| {
+        Ok(runtime
+            .convert("convertLatexToMathml", latex, display)?
+            .output)
+    })
+}
+
+/// Convert LaTeX to HTML styled by `katex.min.css` (public API)
+pub fn convert_latex_to_html(latex: &str, display: bool, preamble: &str) -> Result<String, String> {
+    with_runtime(Engine::Katex, preamble, |runtime| {
+        Ok(runtime
+            .convert("convertLatexToHtml", latex, display)?
+            .output)
+    })
+}
+
+/// Runtime with the user's plugins evaluated
+struct PluginRuntime {
+    js: JsRuntimeInner,
+    scripts: Arc<[Script]>,
+    /// Registered languages, with the name of the plugin rendering them
+    languages: Vec<(String, String)>,
+}
+
+impl PluginRuntime {
+    /// Evaluate `scripts` in order; a language registered twice goes to the
+    /// last plugin
+    fn new(scripts: &Arc<[Script]>) -> Result<Self, String> {
+        let js = JsRuntimeInner::new()?;
+        let mut languages: Vec<(String, String)> = Vec::new();
+        js.context.with(|ctx| {
+            ctx.eval::<(), _>(PLUGIN_PRELUDE)
+                .map_err(|e| format!("Failed to initialize plugins: {e}"))?;
+            let registered: Function = ctx
+                .globals()
+                .get("md2cbRegistered")
+                .map_err(|e| format!("Missing md2cbRegistered: {e}"))?;
+            for script in scripts.iter() {
+                let registered = js
+                    .timed(&ctx, || {
+                        ctx.eval::<(), _>(script.source.as_bytes().to_vec())?;
+                        registered.call::<_, Vec<String>>(())
+                    })
+                    .map_err(|e| format!("{}: {e}", script.name))?;
+                for language in registered {
+                    match languages.iter_mut().find(|(l, _)| *l == language) {
+                        Some((_, name)) => name.clone_from(&script.name),
+                        None => languages.push((language, script.name.clone())),
+                    }
+                }
+            }
+            Ok::<_, String>(())
+        })?;
+        Ok(Self {
+            js,
+            scripts: Arc::clone(scripts),
+            languages,
+        })
+    }
+
+    /// Call the renderer registered for `language`
+    fn render(
+        &self,
+        language: &str,
+        body: &str,
+        attributes: &BTreeMap<String, String>,
+    ) -> Result<String, String> {
+        let (_, name) = self
+            .languages
+            .iter()
+            .find(|(l, _)| l == language)
+            .ok_or_else(|| format!("no plugin renders {language}"))?;
+        self.js
+            .context
+            .with(|ctx| {
+                let render: Function = ctx
+                    .globals()
+                    .get("md2cbRender")
+                    .map_err(|e| format!("Missing md2cbRender: {e}"))?;
+                self.js
+                    .timed(&ctx, || render.call((language, body, attributes.clone())))
+            })
+            .map_err(|e| format!("{name}: {e}"))
+    }
+}
+
+/// Run `f` on the thread's plugin runtime, starting it for `scripts` if needed
+fn with_plugins<T>(
+    scripts: &Arc<[Script]>,
+    f: impl FnOnce(&PluginRuntime) -> Result<T, String>,
+) -> Result<T, String> {
+    PLUGIN_RUNTIME.with(|cell| {
+        let mut runtime_ref = cell.borrow_mut();
+        if runtime_ref
+            .as_ref()
+            .is_some_and(|runtime| !runtime.js.is_current() || runtime.scripts != *scripts)
+        {
+            *runtime_ref = None;
+        }
+        if runtime_ref.is_none() {
+            *runtime_ref = Some(PluginRuntime::new(scripts)?);
+        }
+        let runtime = runtime_ref
             .as_ref()
-            .ok_or_else(|| "Runtime not initialized".to_string())?
-            .latex_to_svg(latex, display)
+            .ok_or_else(|| "Runtime not initialized".to_string())?;
+        f(runtime)
+    })
+}
+
+/// Evaluate plugin scripts and list the languages they register
+pub fn plugin_languages(scripts: &Arc<[Script]>) -> Result<Vec<String>, String> {
+    with_plugins(scripts, |runtime| {
+        Ok(runtime.languages.iter().map(|(l, _)| l.clone()).collect())
+    })
+}
+
+/// Render a fenced block with the plugin registered for `language`
+pub fn render_plugin(
+    scripts: &Arc<[Script]>,
+    language: &str,
+    body: &str,
+    attributes: &BTreeMap<String, String>,
+) -> Result<String, String> {
+    with_plugins(scripts, |runtime| {
+        runtime.render(language, body, attributes)
     })
 }
 
@@ -120,28 +585,139 @@ mod tests {
 
     #[test]
     fn test_simple_latex() {
-        let svg = convert_latex_to_svg("x^2", false).unwrap();
+        let (svg, size) = convert_latex_to_svg("x_i^2", false, "").unwrap();
         assert!(svg.contains("<svg"));
         assert!(svg.contains("xmlns="));
+        assert!(size.width > 0.0 && size.height > 0.0);
+        assert!(size.vertical_align < 0.0);
+        assert!(svg.contains(&format!("vertical-align: {}ex", size.vertical_align)));
     }
 
     #[test]
     fn test_display_mode() {
-        let svg = convert_latex_to_svg("\\int_0^1 x dx", true).unwrap();
+        let (svg, _) = convert_latex_to_svg("\\int_0^1 x dx", true, "").unwrap();
         assert!(svg.contains("<svg"));
     }
 
     #[test]
     fn test_complex_latex() {
-        let svg =
-            convert_latex_to_svg(r"\begin{split} a &= b \\ c &= d \end{split}", true).unwrap();
+        let (svg, _) =
+            convert_latex_to_svg(r"\begin{split} a &= b \\ c &= d \end{split}", true, "").unwrap();
         assert!(svg.contains("<svg"));
     }
 
     #[test]
-    fn test_escape_js_string() {
-        assert_eq!(escape_js_string("a\\b"), "a\\\\b");
-        assert_eq!(escape_js_string("a'b"), "a\\'b");
-        assert_eq!(escape_js_string("a\nb"), "a\\nb");
+    fn test_tex_error_is_reported() {
+        let err = convert_latex_to_svg(r"\frac{", false, "").unwrap_err();
+        assert_eq!(err, "Missing close brace");
+    }
+
+    #[test]
+    fn test_mathml_output() {
+        let mathml = convert_latex_to_mathml("a < b", true, "").unwrap();
+        assert!(mathml.starts_with(r#"<math xmlns="http://www.w3.org/1998/Math/MathML""#));
+        assert!(mathml.contains(r#"alttext="a &lt; b""#));
+        assert!(mathml.contains(r#"display="block""#));
+        assert!(mathml.contains("<mo>&lt;</mo>"));
+        assert_eq!(
+            convert_latex_to_mathml(r"\frac{", false, "").unwrap_err(),
+            "Missing close brace"
+        );
+
+        // The source is passed as is, whatever characters it holds
+        let mathml =
+            convert_latex_to_mathml("\\text{it's \"a\"}\n\u{2028}\\\\", false, "").unwrap();
+        assert!(mathml.contains("alttext=\"\\text{it's &quot;a&quot;}\n\u{2028}\\\\\""));
+    }
+
+    #[test]
+    fn test_katex_html_output() {
+        let html = convert_latex_to_html(r"\frac{a}{b}", true, "").unwrap();
+        assert!(html.starts_with(r#"<span class="katex-display">"#));
+        assert!(html.contains("mfrac"));
+        // MathJax and KaTeX share the runtime
+        assert!(convert_latex_to_svg("x", false, "").is_ok());
+
+        let err = convert_latex_to_html(r"\frac{", false, "").unwrap_err();
+        assert!(err.starts_with("KaTeX parse error"));
+        assert_eq!(
+            convert_latex_to_html("x^", false, "").unwrap_err(),
+            "KaTeX parse error: Expected group after '^' at position 2"
+        );
+    }
+
+    #[test]
+    fn test_mathjax_bytecode_and_source_fallback() {
+        assert!(!MATHJAX_BYTECODE.is_empty());
+
+        let from_bytecode = JsRuntimeInner::new().unwrap();
+        from_bytecode
+            .context
+            .with(|ctx| eval_bytecode(&ctx, MATHJAX_BYTECODE))
+            .unwrap();
+        let from_source = JsRuntimeInner::new().unwrap();
+        from_source
+            .context
+            .with(|ctx| eval_sources(&ctx, Engine::MathJax))
+            .unwrap();
+
+        let convert = |runtime: &JsRuntimeInner| runtime.convert("convertLatexToSvg", "x^2", false);
+        assert_eq!(convert(&from_bytecode), convert(&from_source));
+    }
+
+    #[test]
+    fn test_preamble_macros() {
+        let preamble = "\\def\\RR{\\mathbb{R}}\\def\\norm#1{\\lVert #1 \\rVert}";
+        // MathJax renders undefined macros as their name instead of failing
+        let svg = |latex, preamble| convert_latex_to_svg(latex, false, preamble).unwrap();
+        let defined = svg(r"\norm{x}", preamble);
+        assert!(
+            convert_latex_to_mathml(r"\RR", false, preamble)
+                .unwrap()
+                .contains("double-struck")
+        );
+        assert!(convert_latex_to_html(r"\norm{x}", false, preamble).is_ok());
+
+        // Switching to another preamble drops these macros
+        assert_ne!(svg(r"\norm{x}", ""), defined);
+        assert!(convert_latex_to_html(r"\norm{x}", false, "").is_err());
+        assert_eq!(svg(r"\norm{x}", preamble), defined);
+
+        // Definitions in an expression don't outlive it
+        svg(r"\def\norm#1{\lVert #1 \rVert}", "");
+        assert_ne!(svg(r"\norm{x}", ""), defined);
+        assert!(convert_latex_to_html(r"\def\RR{R}\RR", false, "").is_ok());
+        assert!(convert_latex_to_html(r"\RR", false, "").is_err());
+
+        // A broken preamble fails every expression, not only the first one
+        for _ in 0..2 {
+            let err = convert_latex_to_svg("x", false, r"\def\broken{").unwrap_err();
+            assert!(err.starts_with("Invalid math preamble"), "{err}");
+        }
+        assert!(convert_latex_to_svg("x", false, "").is_ok());
+    }
+
+    #[test]
+    fn test_timeout_drops_runtime() {
+        let mut runtime = JsRuntimeInner::new().unwrap();
+        runtime.timeout = Duration::from_millis(50);
+        runtime
+            .context
+            .with(|ctx| {
+                // Interrupts can't be caught
+                ctx.eval::<(), _>(
+                    "globalThis.spin = function() { try { for (;;) {} } catch (e) { return {}; } }",
+                )
+            })
+            .unwrap();
+
+        let err = runtime.convert("spin", "x", false).unwrap_err();
+        assert!(err.starts_with("Timed out"), "{err}");
+        assert!(!runtime.is_current());
+
+        // The next conversion on this thread starts over
+        JS_RUNTIME.with(|cell| *cell.borrow_mut() = Some(runtime));
+        assert!(convert_latex_to_svg("x", false, "").is_ok());
+        JS_RUNTIME.with(|cell| assert!(cell.borrow().as_ref().unwrap().is_current()));
     }
 }
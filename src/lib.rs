@@ -0,0 +1,505 @@
+//! Convert GitHub Flavored Markdown to rich HTML for pasting into editors.
+//!
+//! The `md2cb` binary is a thin CLI over this crate. Build a [`Converter`]
+//! from [`ConvertOptions`] and reuse it for as many documents as needed:
+//!
+//! ```no_run
+//! use md2cb::{ConvertOptions, Converter, Target};
+//!
+//! let converter = Converter::new(ConvertOptions::for_target(Target::Teams).fragment(true));
+//! let doc = converter.convert("# Notes\n\nEnergy: $E = mc^2$");
+//! for warning in &doc.warnings {
+//!     eprintln!("warning: {warning}");
+//! }
+//! println!("{}", doc.html);
+//! ```
+
+pub mod cache;
+pub mod css_inline;
+mod csv_table;
+pub mod images;
+mod js_runtime;
+mod math;
+pub mod parser;
+pub mod plain_text;
+pub mod plugins;
+mod pool;
+pub mod report;
+pub mod reverse;
+pub mod settings;
+pub mod svg_render;
+pub mod target;
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+pub use cache::Cache;
+pub use parser::Extensions;
+pub use plugins::Plugins;
+pub use report::Report;
+pub use settings::Settings;
+pub use target::{
+    CheckboxStyle, CodeBlockStyle, EquationNumbering, ImageLimits, MathStyle, Profile, Target,
+};
+
+/// Options for a [`Converter`], built up from a target's profile.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ConvertOptions {
+    profile: Profile,
+    base_path: Option<PathBuf>,
+    cache: Option<Cache>,
+    fragment: bool,
+}
+
+impl ConvertOptions {
+    /// Options tuned for pasting into `target`.
+    pub fn for_target(target: Target) -> Self {
+        Self::from_profile(target.profile())
+    }
+
+    /// Options using a fully custom profile.
+    pub fn from_profile(profile: Profile) -> Self {
+        Self {
+            profile,
+            base_path: None,
+            cache: None,
+            fragment: false,
+        }
+    }
+
+    /// Applies config file settings on top of the current profile, and uses
+    /// the render cache they configure.
+    pub fn settings(mut self, settings: &Settings) -> Self {
+        settings.apply(&mut self.profile);
+        self.cache = settings.cache();
+        self
+    }
+
+    /// Markdown extensions enabled when parsing.
+    pub fn extensions(mut self, extensions: Extensions) -> Self {
+        self.profile.extensions = extensions;
+        self
+    }
+
+    /// Syntax highlighting theme, one of [`parser::THEMES`].
+    pub fn theme(mut self, theme: impl Into<Cow<'static, str>>) -> Self {
+        self.profile.theme = theme.into();
+        self
+    }
+
+    /// How math expressions are emitted.
+    pub fn math(mut self, style: MathStyle) -> Self {
+        self.profile.math = style;
+        self
+    }
+
+    /// Size of math images relative to 16px text.
+    pub fn math_scale(mut self, scale: f32) -> Self {
+        self.profile.math_scale = scale;
+        self
+    }
+
+    /// Which display equations are numbered.
+    pub fn numbering(mut self, numbering: EquationNumbering) -> Self {
+        self.profile.numbering = numbering;
+        self
+    }
+
+    /// LaTeX macros available to every math expression, by name, on top of
+    /// those already set. The document's front matter can override them.
+    pub fn macros(mut self, macros: BTreeMap<String, String>) -> Self {
+        self.profile.macros.extend(macros);
+        self
+    }
+
+    /// Whether Mermaid blocks are rendered as diagrams or left as code.
+    pub fn mermaid(mut self, enabled: bool) -> Self {
+        self.profile.mermaid = enabled;
+        self
+    }
+
+    /// User plugins rendering fenced blocks of the languages they register.
+    pub fn plugins(mut self, plugins: Plugins) -> Self {
+        self.profile.plugins = plugins;
+        self
+    }
+
+    /// Whether Graphviz DOT blocks are rendered as diagrams or left as code.
+    pub fn graphviz(mut self, enabled: bool) -> Self {
+        self.profile.graphviz = enabled;
+        self
+    }
+
+    /// Which images get inlined as data URIs.
+    pub fn images(mut self, limits: ImageLimits) -> Self {
+        self.profile.images = limits;
+        self
+    }
+
+    /// Directory relative image paths are resolved against.
+    pub fn base_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.base_path = Some(path.into());
+        self
+    }
+
+    /// On-disk cache for rendered math and diagrams; `None` renders everything.
+    pub fn cache(mut self, cache: Option<Cache>) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Whether the stylesheet is resolved into per-element `style` attributes.
+    pub fn inline_styles(mut self, enabled: bool) -> Self {
+        self.profile.inline_styles = enabled;
+        self
+    }
+
+    /// Stylesheet embedded in the full document, if any, and resolved into
+    /// `style` attributes with [`inline_styles`](Self::inline_styles).
+    pub fn stylesheet(mut self, css: Option<Cow<'static, str>>) -> Self {
+        self.profile.stylesheet = css;
+        self
+    }
+
+    /// Emit only the `markdown-body` content instead of a full HTML document.
+    pub fn fragment(mut self, fragment: bool) -> Self {
+        self.fragment = fragment;
+        self
+    }
+
+    pub fn profile(&self) -> &Profile {
+        &self.profile
+    }
+}
+
+/// Result of converting one Markdown document.
+#[derive(Debug)]
+pub struct ConvertedDocument {
+    /// Full HTML document, or only the content with [`ConvertOptions::fragment`].
+    pub html: String,
+    /// Plain-text rendering for apps that don't accept HTML.
+    pub text: String,
+    /// Images of the Markdown embedded in `html` as data URIs. Math,
+    /// diagrams and plugin output rendered to PNG are embedded too, but
+    /// aren't listed: see [`Report`] for those.
+    pub assets: Vec<Asset>,
+    /// Math, diagrams and images that fell back to source.
+    pub warnings: Vec<String>,
+    /// Full details of what was rendered, with stage timings.
+    pub report: Report,
+}
+
+/// An image of the Markdown embedded in the converted document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Asset {
+    /// `src` as written in the Markdown.
+    pub src: String,
+    pub mime: String,
+    pub bytes: usize,
+}
+
+/// Converts Markdown documents with a fixed set of options.
+///
+/// Math, diagrams and images are rendered on a small worker pool shared by
+/// every conversion in the process. Each worker initializes its own MathJax
+/// runtime on first use and keeps it.
+#[derive(Debug, Clone, Default)]
+pub struct Converter {
+    options: ConvertOptions,
+}
+
+impl Converter {
+    pub fn new(options: ConvertOptions) -> Self {
+        Self { options }
+    }
+
+    pub fn options(&self) -> &ConvertOptions {
+        &self.options
+    }
+
+    /// Runs the conversion pipeline on `markdown`.
+    pub fn convert(&self, markdown: &str) -> ConvertedDocument {
+        let profile = &self.options.profile;
+        let mut report = Report::default();
+        let started = Instant::now();
+
+        // Convert to HTML
+        let stage = Instant::now();
+        let cache = self.options.cache.as_ref();
+        let html = parser::convert(markdown, profile, cache, &mut report);
+        report.timings_ms.parser = elapsed_ms(stage);
+
+        // Keep the cache under its size cap; best-effort like the rest of the cache
+        if let Some(cache) = cache {
+            let _ = cache.trim();
+        }
+
+        // Plain-text flavor for apps that don't accept HTML
+        let stage = Instant::now();
+        let text = plain_text::convert(markdown, &profile.extensions);
+        report.timings_ms.plain_text = elapsed_ms(stage);
+
+        // KaTeX math is plain HTML and needs its own stylesheet
+        let katex = profile.math == MathStyle::Katex && report.math.rendered > 0;
+
+        // Resolve the stylesheet into style attributes for editors that drop <style>
+        let stage = Instant::now();
+        let inlined = if profile.inline_styles {
+            let css = profile.stylesheet.as_deref().unwrap_or(target::GITHUB_CSS);
+            css_inline::inline_css(&html, &with_math_css(css, katex))
+        } else {
+            css_inline::Inlined {
+                html,
+                container_style: String::new(),
+            }
+        };
+        report.timings_ms.css = elapsed_ms(stage);
+
+        // Inline images (convert URLs to base64 data URIs)
+        // Use the markdown file's directory for resolving relative image paths
+        let stage = Instant::now();
+        let base_path = self.options.base_path.as_deref();
+        let html = images::inline_images(&inlined.html, base_path, &profile.images, &mut report);
+        let inlined = css_inline::Inlined { html, ..inlined };
+        report.timings_ms.images = elapsed_ms(stage);
+
+        // Build full HTML document with CSS, unless only the fragment was requested
+        let html = if self.options.fragment {
+            // Without a stylesheet on the other end, the container's font and
+            // colors only survive on an element of the fragment itself
+            match profile.inline_styles && profile.stylesheet.is_none() {
+                true => css_inline::wrap(&inlined),
+                false => inlined.html,
+            }
+        } else {
+            // Inlined rules would only be pasted twice
+            let stylesheet = profile
+                .stylesheet
+                .as_deref()
+                .map(|css| with_math_css(css, katex))
+                .map(|css| match profile.inline_styles {
+                    true => Cow::Owned(css_inline::non_inlinable_rules(&css)),
+                    false => css,
+                })
+                .filter(|css| !css.is_empty());
+            build_document(&inlined, stylesheet.as_deref())
+        };
+
+        report.payload_bytes = html.len();
+        report.timings_ms.total = elapsed_ms(started);
+
+        let assets = report
+            .images
+            .iter()
+            .filter(|image| image.inlined)
+            .map(|image| Asset {
+                src: image.src.clone(),
+                mime: image.mime.clone().unwrap_or_default(),
+                bytes: image.bytes.unwrap_or_default(),
+            })
+            .collect();
+
+        ConvertedDocument {
+            html,
+            text,
+            assets,
+            warnings: report.fallbacks(),
+            report,
+        }
+    }
+}
+
+/// Keeps panics of rendering libraries off stderr. They are caught and
+/// reported as failures either way.
+///
+/// This replaces the process-wide panic hook, so call it once, at startup.
+pub fn silence_render_panics() {
+    pool::silence_caught_panics();
+}
+
+/// Converts Markdown with the default options.
+pub fn convert(markdown: &str) -> ConvertedDocument {
+    Converter::default().convert(markdown)
+}
+
+/// Directory used to resolve relative image paths in `file_path`.
+pub fn base_dir(file_path: &Path) -> Option<PathBuf> {
+    file_path
+        .canonicalize()
+        .ok()
+        .and_then(|p| p.parent().map(|p| p.to_path_buf()))
+}
+
+fn elapsed_ms(since: Instant) -> f64 {
+    since.elapsed().as_secs_f64() * 1000.0
+}
+
+/// Appends the KaTeX stylesheet to `css` if the document has KaTeX math.
+fn with_math_css(css: &str, katex: bool) -> Cow<'_, str> {
+    if katex {
+        Cow::Owned(format!("{css}\n{}", *target::KATEX_CSS))
+    } else {
+        Cow::Borrowed(css)
+    }
+}
+
+/// Wraps the converted HTML in a full document with the target's stylesheet,
+/// the container's inlined styles going on its body.
+fn build_document(content: &css_inline::Inlined, stylesheet: Option<&str>) -> String {
+    let style = stylesheet
+        .map(|css| format!("<style>{css}</style>\n"))
+        .unwrap_or_default();
+    let html = &content.html;
+    let body_style = match content.container_style.as_str() {
+        "" => String::new(),
+        declarations => format!(r#" style="{declarations}""#),
+    };
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+{style}</head>
+<body class="markdown-body"{body_style}>{html}</body>
+</html>"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_document() {
+        let content = css_inline::Inlined {
+            html: "<p>hi</p>".to_string(),
+            container_style: "margin:0".to_string(),
+        };
+        let doc = build_document(&content, Some(target::GITHUB_CSS));
+        assert!(doc.starts_with("<!DOCTYPE html>"));
+        assert!(doc.contains("<style>"));
+        assert!(doc.contains(r#"<body class="markdown-body" style="margin:0"><p>hi</p></body>"#));
+    }
+
+    #[test]
+    fn test_build_document_without_stylesheet() {
+        let content = css_inline::Inlined {
+            html: "<p>hi</p>".to_string(),
+            container_style: String::new(),
+        };
+        let doc = build_document(&content, None);
+        assert!(!doc.contains("<style>"));
+        assert!(doc.contains(r#"<body class="markdown-body"><p>hi</p></body>"#));
+    }
+
+    #[test]
+    fn test_convert_fragment() {
+        let converter = Converter::new(ConvertOptions::for_target(Target::Slack).fragment(true));
+        let doc = converter.convert("# Title\n\n- [x] done");
+        assert!(doc.html.starts_with("<h1>"));
+        assert!(!doc.html.contains("<!DOCTYPE html>"));
+        assert_eq!(doc.text, "Title\n\n- [x] done\n");
+        assert!(doc.warnings.is_empty());
+        assert_eq!(doc.report.payload_bytes, doc.html.len());
+    }
+
+    #[test]
+    fn test_container_styles_placement() {
+        // A single container: the body of full documents
+        let doc = Converter::default().convert("# Title");
+        assert_eq!(doc.html.matches(r#"class="markdown-body""#).count(), 1);
+        assert!(doc.html.contains(r#"<body class="markdown-body" style=""#));
+
+        // Fragments keep their own top-level elements...
+        let options = ConvertOptions::default().fragment(true);
+        let doc = Converter::new(options).convert("# Title");
+        assert!(doc.html.starts_with(r#"<h1 style=""#), "{}", doc.html);
+
+        // ...unless the target has no stylesheet to style the container
+        let options = ConvertOptions::for_target(Target::Teams).fragment(true);
+        let doc = Converter::new(options).convert("# Title");
+        assert!(
+            doc.html
+                .starts_with(r#"<div class="markdown-body" style=""#)
+        );
+        assert!(doc.html.ends_with("</h1></div>"));
+    }
+
+    #[test]
+    fn test_convert_reports_assets_and_warnings() {
+        let dir = std::env::temp_dir().join(format!("md2cb-lib-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("pixel.gif"), b"GIF89a").unwrap();
+
+        let options = ConvertOptions::default().base_path(&dir).fragment(true);
+        let doc = Converter::new(options).convert("![a](pixel.gif) ![b](missing.png)");
+
+        assert_eq!(
+            doc.assets,
+            [Asset {
+                src: "pixel.gif".to_string(),
+                mime: "image/gif".to_string(),
+                bytes: 6,
+            }]
+        );
+        assert_eq!(doc.warnings.len(), 1);
+        assert!(doc.warnings[0].starts_with("image missing.png"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_katex_stylesheet_added_only_with_math() {
+        let converter = Converter::new(ConvertOptions::default().math(MathStyle::Katex));
+        let doc = converter.convert("$x$");
+        assert!(doc.html.contains("KaTeX_Main"));
+        assert!(doc.html.contains(r#"<span class="katex""#));
+        // Nothing is fetched relative to wherever the document is pasted
+        let urls: Vec<_> = doc.html.match_indices("url(").collect();
+        assert!(!urls.is_empty());
+        for (at, _) in urls {
+            assert!(
+                doc.html[at..].starts_with(r#"url("data:"#),
+                "{}",
+                &doc.html[at..at + 40]
+            );
+        }
+        assert!(doc.html.contains(r#"src:local("KaTeX_Main-Regular")"#));
+        assert!(!converter.convert("no math").html.contains("KaTeX_Main"));
+    }
+
+    #[test]
+    fn test_inlined_rules_not_embedded_again() {
+        let doc = Converter::new(ConvertOptions::default()).convert("# Title");
+        assert!(doc.html.contains(r#"<h1 style=""#));
+        let style =
+            &doc.html[doc.html.find("<style>").unwrap()..doc.html.find("</style>").unwrap()];
+        assert!(style.contains(":hover"));
+        assert!(!style.contains(".markdown-body h1{"), "{style}");
+        assert!(style.len() < target::GITHUB_CSS.len() / 2);
+
+        // Without inlining, the whole stylesheet is embedded
+        let doc = Converter::new(ConvertOptions::default().inline_styles(false)).convert("# Title");
+        assert!(doc.html.contains(target::GITHUB_CSS));
+    }
+
+    #[test]
+    fn test_custom_stylesheet_inlined() {
+        let css = ".markdown-body h1 { color: rebeccapurple; }".to_string();
+        let options = ConvertOptions::for_target(Target::Teams)
+            .stylesheet(Some(css.into()))
+            .fragment(true);
+        let doc = Converter::new(options).convert("# Title");
+        assert!(doc.html.contains(r#"<h1 style="color:rebeccapurple">"#));
+    }
+
+    #[test]
+    fn test_mermaid_disabled_keeps_code() {
+        let options = ConvertOptions::default().mermaid(false).fragment(true);
+        let doc = Converter::new(options).convert("```mermaid\ngraph LR\n  A --> B\n```");
+        assert!(!doc.html.contains("mermaid-diagram"));
+        assert_eq!(doc.report.mermaid.rendered, 0);
+    }
+}
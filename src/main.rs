@@ -1,17 +1,25 @@
 mod clipboard;
-mod images;
-mod js_runtime;
-mod parser;
-mod svg_render;
 
 use std::env;
 use std::fs;
 use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use md2cb::report::{Report, ReportFormat};
+use md2cb::{
+    Cache, ConvertOptions, ConvertedDocument, Converter, EquationNumbering, MathStyle, Plugins,
+    Target, base_dir, reverse, settings, silence_render_panics,
+};
 
 const VERSION: &str = env!("GIT_VERSION");
 const REPO_URL: &str = "https://github.com/letientai299/md2cb";
 
+/// How often --watch checks the input file for changes.
+const WATCH_INTERVAL: Duration = Duration::from_millis(200);
+
 fn print_help() {
     eprintln!(
         "md2cb - Convert Markdown to rich HTML clipboard content
@@ -19,21 +27,55 @@ fn print_help() {
 USAGE:
     md2cb [OPTIONS] [FILE/STDIN]
     cat file.md | md2cb
+    md2cb --watch FILE
+    md2cb --reverse [FILE]
 
 OPTIONS:
-    -e, --edit       Open $EDITOR to edit before converting
-    -h, --help       Print this help message
-    -V, --version    Print version information
+    -e, --edit           Open $EDITOR to edit before converting
+    -t, --target NAME    Tune the output for the app you paste into:
+                         generic (default), teams, gdocs, word, outlook, slack
+        --math STYLE     How math is emitted, overriding the target's choice:
+                         png, mathml, katex (selectable HTML) or source
+        --numbering MODE Number display equations: none (default), ams
+                         (equation, align... environments) or all
+    -o, --output FILE    Write the HTML to FILE instead of the clipboard
+        --stdout         Write the HTML to stdout instead of the clipboard
+        --fragment       Emit only the markdown-body content, without the
+                         surrounding document and stylesheet
+    -w, --watch          Keep running and re-convert FILE every time it is
+                         saved
+        --strict         Fail without writing anything if any math, diagram
+                         or image could not be rendered
+        --report json    Print a summary of rendered math, diagrams, images,
+                         payload size and stage timings to stdout
+        --no-cache       Render all math and diagrams again instead of
+                         reusing earlier renders
+        --prune-cache    Delete every cached render and exit
+    -r, --reverse        Convert rich HTML on the clipboard (or in FILE) back
+                         to Markdown, copied as plain text or written with
+                         --stdout/--output
+    -h, --help           Print this help message
+    -V, --version        Print version information
 
 DESCRIPTION:
     Reads Markdown from stdin, converts it to styled HTML, and copies
     the result to the system clipboard. The HTML can then be pasted
     into rich text editors like Microsoft Word, Google Docs, Slack, etc.
+    Use --stdout or --output to get the HTML back in scripts or on
+    headless machines.
 
 FEATURES:
     - GitHub Flavored Markdown (tables, task lists, strikethrough, etc.)
-    - Math equations rendered as PNG images (embedded MathJax)
+    - Math equations as PNG images or MathML (embedded MathJax), or as
+      selectable HTML (embedded KaTeX), written as $...$, $$...$$,
+      \\(...\\) or \\[...\\]
+    - Equation numbering, with \\eqref links to labelled equations
+    - Custom fenced blocks rendered by JavaScript plugins in
+      ~/.config/md2cb/plugins
+    - Mermaid and Graphviz DOT diagrams as PNG images
+    - CSV and TSV code blocks as tables
     - Images automatically inlined as base64 data URIs
+    - Plain-text fallback for apps that don't accept HTML
 
 {REPO_URL}"
     );
@@ -44,7 +86,7 @@ fn print_version() {
 }
 
 /// Generate a random temp file path with .md extension
-fn temp_file_path() -> std::path::PathBuf {
+fn temp_file_path() -> PathBuf {
     let timestamp = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)
         .unwrap()
@@ -140,6 +182,166 @@ mod tests {
         assert!(err.contains("too many arguments"));
     }
 
+    #[test]
+    fn test_parse_args_default_output_is_clipboard() {
+        let config = parse_args(&[]).unwrap();
+        assert_eq!(config.output, Output::Clipboard);
+        assert!(!config.fragment);
+    }
+
+    #[test]
+    fn test_parse_args_stdout() {
+        let args = vec!["--stdout".to_string(), "--fragment".to_string()];
+        let config = parse_args(&args).unwrap();
+        assert_eq!(config.output, Output::Stdout);
+        assert!(config.fragment);
+    }
+
+    #[test]
+    fn test_parse_args_output_file() {
+        let args = vec![
+            "-o".to_string(),
+            "out.html".to_string(),
+            "readme.md".to_string(),
+        ];
+        let config = parse_args(&args).unwrap();
+        assert_eq!(config.output, Output::File("out.html".to_string()));
+        assert_eq!(config.input_file.as_deref(), Some("readme.md"));
+    }
+
+    #[test]
+    fn test_parse_args_output_missing_file() {
+        let args = vec!["--output".to_string()];
+        let err = parse_args(&args).unwrap_err();
+        assert!(err.contains("requires a file argument"));
+    }
+
+    #[test]
+    fn test_parse_args_conflicting_outputs() {
+        let args = vec!["--stdout".to_string(), "-o".to_string(), "x".to_string()];
+        let err = parse_args(&args).unwrap_err();
+        assert!(err.contains("only one of"));
+    }
+
+    #[test]
+    fn test_parse_args_target() {
+        let args = vec!["--target".to_string(), "teams".to_string()];
+        let config = parse_args(&args).unwrap();
+        assert_eq!(config.target, Some(Target::Teams));
+        assert_eq!(parse_args(&[]).unwrap().target, None);
+    }
+
+    #[test]
+    fn test_parse_args_unknown_target() {
+        let args = vec!["-t".to_string(), "notion".to_string()];
+        let err = parse_args(&args).unwrap_err();
+        assert!(err.contains("unknown target"));
+    }
+
+    #[test]
+    fn test_parse_args_math() {
+        let args = vec!["--math".to_string(), "katex".to_string()];
+        assert_eq!(parse_args(&args).unwrap().math, Some(MathStyle::Katex));
+        assert_eq!(parse_args(&[]).unwrap().math, None);
+
+        let args = vec!["--math".to_string(), "svg".to_string()];
+        assert!(
+            parse_args(&args)
+                .unwrap_err()
+                .contains("unknown math style")
+        );
+    }
+
+    #[test]
+    fn test_parse_args_numbering() {
+        let args = vec!["--numbering".to_string(), "ams".to_string()];
+        let config = parse_args(&args).unwrap();
+        assert_eq!(config.numbering, Some(EquationNumbering::Ams));
+
+        let err = parse_args(&["--numbering".to_string()]).unwrap_err();
+        assert!(err.contains("requires a numbering mode"));
+    }
+
+    #[test]
+    fn test_parse_args_cache_flags() {
+        let config = parse_args(&["--no-cache".to_string()]).unwrap();
+        assert!(config.no_cache);
+        assert!(!config.prune_cache);
+        assert!(
+            parse_args(&["--prune-cache".to_string()])
+                .unwrap()
+                .prune_cache
+        );
+    }
+
+    #[test]
+    fn test_parse_args_reverse() {
+        let args = vec!["--reverse".to_string(), "--stdout".to_string()];
+        let config = parse_args(&args).unwrap();
+        assert!(config.reverse);
+        assert_eq!(config.output, Output::Stdout);
+    }
+
+    #[test]
+    fn test_parse_args_reverse_with_edit() {
+        let args = vec!["-r".to_string(), "-e".to_string()];
+        let err = parse_args(&args).unwrap_err();
+        assert!(err.contains("--reverse cannot be combined with --edit"));
+    }
+
+    #[test]
+    fn test_parse_args_watch() {
+        let args = vec!["--watch".to_string(), "notes.md".to_string()];
+        let config = parse_args(&args).unwrap();
+        assert!(config.watch);
+        assert_eq!(config.input_file, Some("notes.md".to_string()));
+    }
+
+    #[test]
+    fn test_parse_args_watch_requires_file() {
+        let args = vec!["-w".to_string()];
+        let err = parse_args(&args).unwrap_err();
+        assert!(err.contains("--watch requires a file argument"));
+
+        let args = vec!["-w".to_string(), "-e".to_string(), "notes.md".to_string()];
+        assert!(parse_args(&args).is_err());
+    }
+
+    #[test]
+    fn test_parse_args_strict() {
+        let args = vec!["--strict".to_string(), "notes.md".to_string()];
+        assert!(parse_args(&args).unwrap().strict);
+        assert!(!parse_args(&[]).unwrap().strict);
+    }
+
+    #[test]
+    fn test_report_fallbacks() {
+        assert!(report_fallbacks(&[], true));
+        let fallbacks = vec!["image cat.png: not found".to_string()];
+        assert!(report_fallbacks(&fallbacks, false));
+        assert!(!report_fallbacks(&fallbacks, true));
+    }
+
+    #[test]
+    fn test_parse_args_report() {
+        let args = vec!["--report".to_string(), "json".to_string()];
+        assert_eq!(parse_args(&args).unwrap().report, Some(ReportFormat::Json));
+
+        let args = vec!["--report".to_string(), "xml".to_string()];
+        assert!(
+            parse_args(&args)
+                .unwrap_err()
+                .contains("unknown report format")
+        );
+
+        let args = vec![
+            "--report".to_string(),
+            "json".to_string(),
+            "--stdout".to_string(),
+        ];
+        assert!(parse_args(&args).is_err());
+    }
+
     #[test]
     fn test_temp_file_path() {
         let path = temp_file_path();
@@ -158,11 +360,49 @@ fn parse_args(args: &[String]) -> Result<Config, String> {
     let mut config = Config::default();
     let mut positional = Vec::new();
 
-    for arg in args {
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
         match arg.as_str() {
             "--help" | "-h" => config.show_help = true,
             "--version" | "-V" => config.show_version = true,
             "--edit" | "-e" => config.edit_mode = true,
+            "--fragment" => config.fragment = true,
+            "--reverse" | "-r" => config.reverse = true,
+            "--watch" | "-w" => config.watch = true,
+            "--strict" => config.strict = true,
+            "--no-cache" => config.no_cache = true,
+            "--prune-cache" => config.prune_cache = true,
+            "--report" => {
+                let format = iter
+                    .next()
+                    .ok_or_else(|| format!("option '{arg}' requires a format"))?;
+                config.report = Some(format.parse()?);
+            }
+            "--target" | "-t" => {
+                let name = iter
+                    .next()
+                    .ok_or_else(|| format!("option '{arg}' requires a target name"))?;
+                config.target = Some(name.parse()?);
+            }
+            "--math" => {
+                let style = iter
+                    .next()
+                    .ok_or_else(|| format!("option '{arg}' requires a math style"))?;
+                config.math = Some(style.parse()?);
+            }
+            "--numbering" => {
+                let mode = iter
+                    .next()
+                    .ok_or_else(|| format!("option '{arg}' requires a numbering mode"))?;
+                config.numbering = Some(mode.parse()?);
+            }
+            "--stdout" => set_output(&mut config, Output::Stdout)?,
+            "--output" | "-o" => {
+                let path = iter
+                    .next()
+                    .ok_or_else(|| format!("option '{arg}' requires a file argument"))?;
+                set_output(&mut config, Output::File(path.clone()))?;
+            }
             s if s.starts_with('-') => return Err(format!("unknown option '{s}'")),
             _ => positional.push(arg.clone()),
         }
@@ -171,20 +411,67 @@ fn parse_args(args: &[String]) -> Result<Config, String> {
     if positional.len() > 1 {
         return Err("too many arguments".to_string());
     }
-
     config.input_file = positional.first().cloned();
+
+    if config.reverse && config.edit_mode {
+        return Err("--reverse cannot be combined with --edit".to_string());
+    }
+
+    if config.report.is_some() && config.output == Output::Stdout {
+        return Err("--report cannot be combined with --stdout".to_string());
+    }
+
+    if config.watch {
+        if config.edit_mode || config.reverse {
+            return Err("--watch cannot be combined with --edit or --reverse".to_string());
+        }
+        if config.input_file.is_none() {
+            return Err("--watch requires a file argument".to_string());
+        }
+    }
+
     Ok(config)
 }
 
+/// Sets the output destination, rejecting a second conflicting one.
+fn set_output(config: &mut Config, output: Output) -> Result<(), String> {
+    if config.output != Output::Clipboard {
+        return Err("only one of --stdout and --output can be given".to_string());
+    }
+    config.output = output;
+    Ok(())
+}
+
+/// Where the converted HTML is written.
+#[derive(Default, Debug, PartialEq)]
+enum Output {
+    #[default]
+    Clipboard,
+    Stdout,
+    File(String),
+}
+
 #[derive(Default, Debug, PartialEq)]
 struct Config {
     input_file: Option<String>,
     edit_mode: bool,
+    target: Option<Target>,
+    math: Option<MathStyle>,
+    numbering: Option<EquationNumbering>,
+    output: Output,
+    fragment: bool,
+    reverse: bool,
+    watch: bool,
+    strict: bool,
+    report: Option<ReportFormat>,
+    no_cache: bool,
+    prune_cache: bool,
     show_help: bool,
     show_version: bool,
 }
 
 fn main() {
+    silence_render_panics();
     let args: Vec<String> = env::args().skip(1).collect();
 
     let config = match parse_args(&args) {
@@ -209,22 +496,38 @@ fn main() {
         return;
     }
 
+    // Handle --prune-cache
+    if config.prune_cache {
+        run_prune_cache();
+        return;
+    }
+
+    // Handle --reverse
+    if config.reverse {
+        run_reverse(&config);
+        return;
+    }
+
     let input_file = config.input_file.as_deref();
+
+    // Handle --watch
+    if let (true, Some(file_path)) = (config.watch, input_file) {
+        run_watch(&config, file_path);
+        return;
+    }
     let edit_mode = config.edit_mode;
 
     // Read markdown content and track base path for relative image resolution
     let mut markdown = String::new();
-    let base_path: Option<std::path::PathBuf> = if let Some(file_path) = input_file {
+    let base_path: Option<PathBuf> = if let Some(file_path) = input_file {
         // Read from file
-        let path = std::path::Path::new(file_path);
+        let path = Path::new(file_path);
         markdown = fs::read_to_string(path).unwrap_or_else(|e| {
             eprintln!("error: cannot read '{file_path}': {e}");
             std::process::exit(1);
         });
         // Use the file's parent directory for resolving relative image paths
-        path.canonicalize()
-            .ok()
-            .and_then(|p| p.parent().map(|p| p.to_path_buf()))
+        base_dir(path)
     } else if edit_mode && stdin_is_terminal() {
         // No stdin input and no file, start with empty content
         None
@@ -247,32 +550,244 @@ fn main() {
         }
     }
 
-    // Convert to HTML
-    let html = parser::convert(&markdown);
-
-    // Inline images (convert URLs to base64 data URIs)
-    // Use the markdown file's directory for resolving relative image paths
-    let html = images::inline_images(&html, base_path.as_deref());
-
-    // Build full HTML document with CSS
-    let markdown_css = include_str!("../assets/github-markdown.css");
-    let full_html = format!(
-        r#"<!DOCTYPE html>
-<html>
-<head>
-<meta charset="utf-8">
-<style>{markdown_css}</style>
-</head>
-<body class="markdown-body">{html}</body>
-</html>"#
-    );
+    let converter = Converter::new(convert_options(&config, base_path));
+    let ConvertedDocument {
+        html: output_html,
+        text,
+        warnings,
+        report,
+        ..
+    } = converter.convert(&markdown);
+
+    if config.report == Some(ReportFormat::Json) {
+        print_report(&report);
+    }
+    if !report_fallbacks(&warnings, config.strict) {
+        std::process::exit(1);
+    }
+
+    match &config.output {
+        Output::Clipboard => match clipboard::copy_html(&output_html, &text) {
+            Ok(()) => eprintln!("Copied to clipboard"),
+            Err(e) => {
+                eprintln!("Error copying to clipboard: {e}");
+                std::process::exit(1);
+            }
+        },
+        Output::Stdout => write_stdout(&output_html),
+        Output::File(path) => write_file(path, &output_html),
+    }
+}
 
-    // Copy to clipboard
-    match clipboard::copy_html(&full_html) {
-        Ok(()) => eprintln!("Copied to clipboard"),
+/// Builds conversion options from the target and the config files.
+///
+/// `--target` wins over the configured target; the rest of the config is
+/// applied on top of the target's profile, then `--math`. Plugins are loaded
+/// from the config directory.
+fn convert_options(config: &Config, base_path: Option<PathBuf>) -> ConvertOptions {
+    let settings =
+        settings::load(config.input_file.as_deref().map(Path::new)).unwrap_or_else(|e| {
+            eprintln!("error: invalid config: {e}");
+            std::process::exit(1);
+        });
+    let target = config.target.or(settings.target).unwrap_or_default();
+    let plugins = settings::plugins_dir()
+        .map_or_else(|| Ok(Plugins::default()), |dir| Plugins::load(&dir))
+        .unwrap_or_else(|e| {
+            eprintln!("error: invalid plugin: {e}");
+            std::process::exit(1);
+        });
+    let mut options = ConvertOptions::for_target(target)
+        .settings(&settings)
+        .plugins(plugins)
+        .fragment(config.fragment);
+    if let Some(style) = config.math {
+        options = options.math(style);
+    }
+    if let Some(numbering) = config.numbering {
+        options = options.numbering(numbering);
+    }
+    if config.no_cache {
+        options = options.cache(None);
+    }
+    match base_path {
+        Some(dir) => options.base_path(dir),
+        None => options,
+    }
+}
+
+/// Deletes every cached render in the default cache directory.
+fn run_prune_cache() {
+    let Some(dir) = Cache::default_dir() else {
+        eprintln!("error: cannot locate the cache directory");
+        std::process::exit(1);
+    };
+    match Cache::new(&dir, 0).clear() {
+        Ok(removed) => eprintln!(
+            "Removed {} cached render(s), {} KiB, from {}",
+            removed.entries,
+            removed.bytes / 1024,
+            dir.display()
+        ),
         Err(e) => {
-            eprintln!("Error copying to clipboard: {e}");
+            eprintln!("error: cannot prune {}: {e}", dir.display());
             std::process::exit(1);
         }
     }
 }
+
+/// Prints the conversion report as a single line of JSON on stdout.
+fn print_report(report: &Report) {
+    match serde_json::to_string(report) {
+        Ok(json) => println!("{json}"),
+        Err(e) => eprintln!("error: cannot serialize report: {e}"),
+    }
+}
+
+/// Prints rendering fallbacks, returning whether the output may still be used.
+///
+/// Fallbacks are warnings, unless `--strict` was given.
+fn report_fallbacks(fallbacks: &[String], strict: bool) -> bool {
+    if fallbacks.is_empty() {
+        return true;
+    }
+    if strict {
+        eprintln!(
+            "error: {} part(s) could not be rendered, nothing was written:",
+            fallbacks.len()
+        );
+        for fallback in fallbacks {
+            eprintln!("  - {fallback}");
+        }
+        return false;
+    }
+    for fallback in fallbacks {
+        eprintln!("warning: {fallback}");
+    }
+    true
+}
+
+/// Re-converts the input file whenever it changes, until interrupted.
+///
+/// The process stays alive, so the font database is initialized once and
+/// reused for every update.
+fn run_watch(config: &Config, file_path: &str) {
+    let path = Path::new(file_path);
+    let converter = Converter::new(convert_options(config, base_dir(path)));
+
+    // Keep one clipboard handle alive so it keeps serving the latest content
+    let mut session = match config.output {
+        Output::Clipboard => match clipboard::ClipboardSession::new() {
+            Ok(session) => Some(session),
+            Err(e) => {
+                eprintln!("Error opening clipboard: {e}");
+                std::process::exit(1);
+            }
+        },
+        _ => None,
+    };
+
+    eprintln!("Watching {file_path} (Ctrl-C to stop)");
+    let mut last_modified = None;
+    loop {
+        let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+        if modified.is_some() && modified != last_modified {
+            last_modified = modified;
+            match fs::read_to_string(path) {
+                Ok(markdown) => {
+                    let started = Instant::now();
+                    let ConvertedDocument {
+                        html: output_html,
+                        text,
+                        warnings,
+                        report,
+                        ..
+                    } = converter.convert(&markdown);
+                    if config.report == Some(ReportFormat::Json) {
+                        print_report(&report);
+                    }
+                    if !report_fallbacks(&warnings, config.strict) {
+                        thread::sleep(WATCH_INTERVAL);
+                        continue;
+                    }
+                    let result = match (&config.output, session.as_mut()) {
+                        (Output::Clipboard, Some(session)) => session
+                            .copy_html(&output_html, &text)
+                            .map_err(|e| format!("Error copying to clipboard: {e}")),
+                        (Output::File(out_path), _) => fs::write(out_path, &output_html)
+                            .map_err(|e| format!("error: cannot write '{out_path}': {e}")),
+                        _ => {
+                            write_stdout(&output_html);
+                            Ok(())
+                        }
+                    };
+                    match result {
+                        Ok(()) => eprintln!(
+                            "Updated {} ({} ms)",
+                            output_name(&config.output),
+                            started.elapsed().as_millis()
+                        ),
+                        Err(e) => eprintln!("{e}"),
+                    }
+                }
+                Err(e) => eprintln!("error: cannot read '{file_path}': {e}"),
+            }
+        }
+        thread::sleep(WATCH_INTERVAL);
+    }
+}
+
+fn output_name(output: &Output) -> &str {
+    match output {
+        Output::Clipboard => "clipboard",
+        Output::Stdout => "stdout",
+        Output::File(path) => path,
+    }
+}
+
+/// Converts rich HTML from the clipboard (or the input file) back to Markdown.
+fn run_reverse(config: &Config) {
+    let html = match config.input_file.as_deref() {
+        Some(file_path) => fs::read_to_string(file_path).unwrap_or_else(|e| {
+            eprintln!("error: cannot read '{file_path}': {e}");
+            std::process::exit(1);
+        }),
+        None => clipboard::read_html().unwrap_or_else(|e| {
+            eprintln!("Error reading HTML from clipboard: {e}");
+            std::process::exit(1);
+        }),
+    };
+
+    let markdown = reverse::html_to_markdown(&html);
+
+    match &config.output {
+        Output::Clipboard => match clipboard::copy_text(&markdown) {
+            Ok(()) => eprintln!("Copied Markdown to clipboard"),
+            Err(e) => {
+                eprintln!("Error copying to clipboard: {e}");
+                std::process::exit(1);
+            }
+        },
+        Output::Stdout => write_stdout(&markdown),
+        Output::File(path) => write_file(path, &markdown),
+    }
+}
+
+fn write_stdout(content: &str) {
+    let mut stdout = io::stdout().lock();
+    if let Err(e) = stdout
+        .write_all(content.as_bytes())
+        .and_then(|()| stdout.flush())
+    {
+        eprintln!("Error writing to stdout: {e}");
+        std::process::exit(1);
+    }
+}
+
+fn write_file(path: &str, content: &str) {
+    if let Err(e) = fs::write(path, content) {
+        eprintln!("error: cannot write '{path}': {e}");
+        std::process::exit(1);
+    }
+    eprintln!("Wrote {path}");
+}
@@ -0,0 +1,686 @@
+//! Document-wide LaTeX state: macros and equation numbers.
+//!
+//! Every expression is rendered on its own, on whichever worker picks it up,
+//! so a `\newcommand` in one expression can't be relied on in the next.
+//! Instead, macros are gathered into a preamble up front and every expression
+//! is rendered with it and nothing else. Macros come from, in order:
+//!
+//! - the `[math.macros]` table of the config file,
+//! - a `macros` map in the document's YAML front matter,
+//! - definitions anywhere in the document's math, in document order, such as
+//!   a leading `$$\newcommand{\R}{\mathbb{R}}$$`.
+//!
+//! Likewise, `\tag`, `\label` and `\eqref` only make sense across
+//! equations, so [`Equations`] numbers display math in document order and
+//! takes those commands out before rendering. Numbers are emitted as HTML
+//! next to the equation, and references become links to it.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::target::EquationNumbering;
+
+/// Commands taking a name, an optional argument count and a body in braces.
+const NEWCOMMAND: [&str; 4] = [
+    "newcommand",
+    "renewcommand",
+    "providecommand",
+    "DeclareMathOperator",
+];
+
+/// Environments amsmath numbers, unless starred.
+const NUMBERED_ENVIRONMENTS: [&str; 7] = [
+    "equation", "align", "alignat", "gather", "multline", "flalign", "eqnarray",
+];
+
+/// Environments whose `\\`-separated rows are numbered one by one.
+const MULTIROW_ENVIRONMENTS: [&str; 5] = ["align", "alignat", "gather", "flalign", "eqnarray"];
+
+/// Number of a display equation, or of one of its rows, and its anchor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tag {
+    /// As shown next to the equation, e.g. `(1)`.
+    pub text: String,
+    /// Anchor id of a labelled equation.
+    pub id: Option<String>,
+}
+
+/// Equation numbers and labels of one document.
+#[derive(Debug, Default)]
+pub struct Equations {
+    numbering: EquationNumbering,
+    count: usize,
+    /// Number (or custom tag) by label
+    labels: HashMap<String, String>,
+}
+
+/// Text with `\eqref{label}` and `\ref{label}` split out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Piece<'a> {
+    Text(&'a str),
+    Ref { label: &'a str, parens: bool },
+}
+
+impl Equations {
+    pub fn new(numbering: EquationNumbering) -> Self {
+        Self {
+            numbering,
+            ..Self::default()
+        }
+    }
+
+    /// Numbers the next display equation. Returns its source without
+    /// `\label`, `\tag` and `\notag`, which MathJax can't lay out in an
+    /// image, along with the tag of each row, or no tags at all if none
+    /// gets one.
+    ///
+    /// Rows are those of `align` and the like; other equations are a single
+    /// row. Labels, tags and `\notag` apply to the row holding them.
+    pub fn number(&mut self, latex: &str) -> (String, Vec<Option<Tag>>) {
+        let numbered = match self.numbering {
+            EquationNumbering::None => false,
+            EquationNumbering::Ams => {
+                environments(latex).any(|env| NUMBERED_ENVIRONMENTS.contains(&env))
+            }
+            EquationNumbering::All => !environments(latex).any(|env| env.ends_with('*')),
+        };
+        let (rows, mut tags): (Vec<_>, Vec<_>) = rows(latex)
+            .into_iter()
+            .map(|row| self.number_row(row, numbered))
+            .unzip();
+        if tags.iter().all(Option::is_none) {
+            tags.clear();
+        }
+        (rows.join("\\\\"), tags)
+    }
+
+    /// Numbers one row of an equation, if `numbered` or tagged.
+    fn number_row(&mut self, latex: &str, numbered: bool) -> (String, Option<Tag>) {
+        let (latex, labels) = take_command(latex, "label", true);
+        let (latex, tags) = take_command(&latex, "tag", true);
+        let (latex, notag) = take_command(&latex, "notag", false);
+        let (latex, nonumber) = take_command(&latex, "nonumber", false);
+
+        let numbered = numbered && notag.is_empty() && nonumber.is_empty();
+        let (number, text) = match tags.last() {
+            Some((true, custom)) => (custom.clone(), custom.clone()),
+            Some((false, custom)) => (custom.clone(), format!("({custom})")),
+            None if numbered => {
+                self.count += 1;
+                (self.count.to_string(), format!("({})", self.count))
+            }
+            None => return (latex, None),
+        };
+
+        for (_, label) in &labels {
+            self.labels.insert(label.clone(), number.clone());
+        }
+        let id = labels.first().map(|(_, label)| anchor(label));
+        (latex, Some(Tag { text, id }))
+    }
+
+    /// Number of the equation labelled `label`, as `\eqref` (with
+    /// parentheses) or `\ref` show it.
+    pub fn reference(&self, label: &str, parens: bool) -> Option<String> {
+        let number = self.labels.get(label)?;
+        Some(if parens {
+            format!("({number})")
+        } else {
+            number.clone()
+        })
+    }
+}
+
+/// Anchor id of the equation labelled `label`.
+pub fn anchor(label: &str) -> String {
+    format!("eq-{label}")
+}
+
+/// Splits `\eqref{label}` and `\ref{label}` out of `s`.
+pub fn split_refs(s: &str) -> Vec<Piece<'_>> {
+    let mut pieces = Vec::new();
+    let mut rest = s;
+    loop {
+        let start = ["\\eqref{", "\\ref{"]
+            .iter()
+            .filter_map(|command| rest.find(command))
+            .min();
+        let Some(start) = start else {
+            break;
+        };
+        let (command, after) = control_sequence(&rest[start..]).unwrap_or_default();
+        let Some(end) = after.find('}') else {
+            break;
+        };
+        if start > 0 {
+            pieces.push(Piece::Text(&rest[..start]));
+        }
+        pieces.push(Piece::Ref {
+            label: after[1..end].trim(),
+            parens: command == "eqref",
+        });
+        rest = &after[end + 1..];
+    }
+    if !rest.is_empty() {
+        pieces.push(Piece::Text(rest));
+    }
+    pieces
+}
+
+/// Removes every `\name` from `latex`, returning what's left and, for each
+/// occurrence, whether it was starred and its `{...}` argument if
+/// `takes_argument`.
+fn take_command(latex: &str, name: &str, takes_argument: bool) -> (String, Vec<(bool, String)>) {
+    let mut kept = String::with_capacity(latex.len());
+    let mut taken = Vec::new();
+    let mut rest = latex;
+    while let Some(start) = rest.find('\\') {
+        let Some((command, after)) = control_sequence(&rest[start..]) else {
+            break;
+        };
+        kept.push_str(&rest[..start]);
+        if command != name {
+            kept.push_str(&rest[start..rest.len() - after.len()]);
+            rest = after;
+            continue;
+        }
+        let starred = after.starts_with('*');
+        let after = after.strip_prefix('*').unwrap_or(after);
+        match group(skip_space(after)).filter(|_| takes_argument) {
+            Some(next) => {
+                let after = skip_space(after);
+                let argument = &after[1..after.len() - next.len() - 1];
+                taken.push((starred, argument.trim().to_string()));
+                rest = next;
+            }
+            None => {
+                taken.push((starred, String::new()));
+                rest = after;
+            }
+        }
+    }
+    kept.push_str(rest);
+    (kept, taken)
+}
+
+/// Splits `latex` on the `\\` ending the rows of a multi-row environment
+/// such as `align`, leaving those of nested environments and groups alone.
+fn rows(latex: &str) -> Vec<&str> {
+    let mut rows = Vec::new();
+    let mut environments = Vec::new();
+    let mut depth = 0;
+    let (mut start, mut pos) = (0, 0);
+    while let Some(found) = latex[pos..].find(['\\', '{', '}']) {
+        let at = pos + found;
+        pos = at + 1;
+        let rest = &latex[at..];
+        if rest.starts_with('{') {
+            depth += 1;
+            continue;
+        }
+        if rest.starts_with('}') {
+            depth -= 1;
+            continue;
+        }
+        let Some((command, after)) = control_sequence(rest) else {
+            break;
+        };
+        pos = latex.len() - after.len();
+        match command {
+            "begin" | "end" => {
+                let Some(next) = group(after) else {
+                    continue;
+                };
+                pos = latex.len() - next.len();
+                if command == "begin" {
+                    environments.push(after[1..after.len() - next.len() - 1].trim());
+                } else {
+                    environments.pop();
+                }
+            }
+            "\\" if depth == 0
+                && environments.len() == 1
+                && MULTIROW_ENVIRONMENTS.contains(&environments[0].trim_end_matches('*')) =>
+            {
+                rows.push(&latex[start..at]);
+                start = pos;
+            }
+            _ => {}
+        }
+    }
+    rows.push(&latex[start..]);
+    rows
+}
+
+/// Names of the environments begun in `latex`.
+fn environments(latex: &str) -> impl Iterator<Item = &str> {
+    latex.split("\\begin{").skip(1).filter_map(|rest| {
+        let end = rest.find('}')?;
+        Some(rest[..end].trim())
+    })
+}
+
+/// Definitions for a map of macro names to bodies, one per line.
+///
+/// Names may be given with or without their backslash. `\def` is used so
+/// macros can replace built-in ones (KaTeX refuses to `\newcommand` `\R`).
+pub fn macro_definitions(macros: &BTreeMap<String, String>) -> String {
+    macros
+        .iter()
+        .map(|(name, body)| {
+            let name = name.trim_start_matches('\\');
+            let params: String = (1..=parameter_count(body))
+                .map(|n| format!("#{n}"))
+                .collect();
+            format!("\\def\\{name}{params}{{{body}}}\n")
+        })
+        .collect()
+}
+
+/// Highest `#n` parameter used in a macro body.
+fn parameter_count(body: &str) -> u32 {
+    body.split('#')
+        .skip(1)
+        .filter_map(|rest| rest.chars().next()?.to_digit(10))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Reads the `macros` map out of YAML front matter, delimiters included.
+///
+/// Only the subset needed for macros is understood: a top-level `macros:`
+/// key followed by indented `name: body` lines, with plain, single-quoted
+/// or double-quoted bodies.
+///
+/// ```yaml
+/// macros:
+///   R: \mathbb{R}
+///   norm: '\left\lVert #1 \right\rVert'
+/// ```
+pub fn front_matter_macros(front_matter: &str) -> BTreeMap<String, String> {
+    let mut macros = BTreeMap::new();
+    let mut lines = front_matter
+        .lines()
+        .skip_while(|line| line.trim_end() != "macros:")
+        .skip(1);
+    for line in lines.by_ref() {
+        let entry = line.trim();
+        if entry.is_empty() || entry.starts_with('#') {
+            continue;
+        }
+        // The map ends at the next top-level key or the closing delimiter
+        if !line.starts_with([' ', '\t']) {
+            break;
+        }
+        if let Some((name, body)) = entry.split_once(':') {
+            macros.insert(name.trim().to_string(), yaml_scalar(body.trim()));
+        }
+    }
+    macros
+}
+
+/// Unquotes a YAML scalar.
+fn yaml_scalar(value: &str) -> String {
+    if let Some(quoted) = value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')) {
+        return quoted.replace("''", "'");
+    }
+    if let Some(quoted) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        let mut unescaped = String::with_capacity(quoted.len());
+        let mut chars = quoted.chars();
+        while let Some(c) = chars.next() {
+            if c == '\\' {
+                unescaped.extend(chars.next());
+            } else {
+                unescaped.push(c);
+            }
+        }
+        return unescaped;
+    }
+    value.to_string()
+}
+
+/// Moves the macro definitions out of `latex`, returning what's left and the
+/// definitions, one per line.
+///
+/// `\newcommand` and the like, `\def`, `\gdef` and `\let` are taken; an
+/// incomplete definition is left in place for the renderer to report.
+/// `\newcommand` and `\renewcommand` become `\def`, like in
+/// [`macro_definitions`].
+pub fn take_definitions(latex: &str) -> (String, String) {
+    let mut rest_of_latex = String::with_capacity(latex.len());
+    let mut definitions = String::new();
+    let mut rest = latex;
+    while let Some(start) = rest.find('\\') {
+        rest_of_latex.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some((command, after)) = control_sequence(rest) else {
+            break;
+        };
+        let end = match command {
+            c if NEWCOMMAND.contains(&c) => skip_newcommand(after),
+            "def" | "gdef" => skip_def(after),
+            "let" => skip_let(after),
+            _ => None,
+        };
+        match end {
+            Some(end) => {
+                let len = rest.len() - end.len();
+                match as_def(command, &after[..after.len() - end.len()]) {
+                    Some(def) => definitions.push_str(&def),
+                    None => definitions.push_str(rest[..len].trim_end()),
+                }
+                definitions.push('\n');
+                rest = end;
+            }
+            None => {
+                // Other commands, `\\` included, are copied whole
+                let len = rest.len() - after.len();
+                rest_of_latex.push_str(&rest[..len]);
+                rest = after;
+            }
+        }
+    }
+    rest_of_latex.push_str(rest);
+    (rest_of_latex, definitions)
+}
+
+/// `\newcommand`'s `*{\name}[n]{body}` as `\def\name#1...#n{body}`.
+///
+/// `\providecommand` must not replace anything and `\def` can't give a
+/// parameter a default, so those are left alone.
+fn as_def(command: &str, definition: &str) -> Option<String> {
+    if command != "newcommand" && command != "renewcommand" {
+        return None;
+    }
+    let s = skip_space(definition);
+    let s = skip_space(s.strip_prefix('*').unwrap_or(s));
+    let after_name = macro_name(s)?;
+    let name = s[..s.len() - after_name.len()]
+        .trim_start_matches('{')
+        .trim_end_matches('}')
+        .trim();
+    let mut s = skip_space(after_name);
+    let mut count = 0;
+    if let Some(option) = s.strip_prefix('[') {
+        let (n, after) = option.split_once(']')?;
+        count = n.trim().parse().ok()?;
+        s = skip_space(after);
+    }
+    if s.starts_with('[') {
+        return None;
+    }
+    let body = &s[1..s.len() - group(s)?.len() - 1];
+    let params: String = (1..=count).map(|n| format!("#{n}")).collect();
+    Some(format!("\\def{name}{params}{{{body}}}"))
+}
+
+/// Whether `latex` is blank but for whitespace and comments.
+pub fn is_blank(latex: &str) -> bool {
+    skip_space(latex).is_empty()
+}
+
+/// Skips whitespace and `%` comments.
+fn skip_space(mut s: &str) -> &str {
+    loop {
+        s = s.trim_start();
+        match s.strip_prefix('%') {
+            Some(comment) => s = comment.split_once('\n').map_or("", |(_, next)| next),
+            None => return s,
+        }
+    }
+}
+
+/// Splits a leading `\name` (or `\` and one symbol) into its name and the rest.
+fn control_sequence(s: &str) -> Option<(&str, &str)> {
+    let s = s.strip_prefix('\\')?;
+    let len = s
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(s.len());
+    let len = if len == 0 {
+        s.chars().next()?.len_utf8()
+    } else {
+        len
+    };
+    Some(s.split_at(len))
+}
+
+/// Skips a leading `{...}` group, braces escaped with `\` aside.
+fn group(s: &str) -> Option<&str> {
+    if !s.starts_with('{') {
+        return None;
+    }
+    let mut depth = 0;
+    let mut chars = s.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&s[i + 1..]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Skips a macro name, either `\name` or `{\name}`.
+fn macro_name(s: &str) -> Option<&str> {
+    let s = skip_space(s);
+    if s.starts_with('{') {
+        group(s)
+    } else {
+        control_sequence(s).map(|(_, rest)| rest)
+    }
+}
+
+/// Skips what follows `\newcommand`: `*{\name}[n][default]{body}`.
+fn skip_newcommand(s: &str) -> Option<&str> {
+    let s = skip_space(s);
+    let mut s = skip_space(macro_name(s.strip_prefix('*').unwrap_or(s))?);
+    while let Some(option) = s.strip_prefix('[') {
+        s = skip_space(option.split_once(']')?.1);
+    }
+    group(s)
+}
+
+/// Skips what follows `\def`: `\name#1#2{body}`.
+fn skip_def(s: &str) -> Option<&str> {
+    let s = macro_name(s)?;
+    let body = s.find('{')?;
+    let params = &s[..body];
+    if !params
+        .chars()
+        .all(|c| c == '#' || c.is_ascii_digit() || c.is_whitespace())
+    {
+        return None;
+    }
+    group(&s[body..])
+}
+
+/// Skips what follows `\let`: `\name = \other`.
+fn skip_let(s: &str) -> Option<&str> {
+    let s = skip_space(macro_name(s)?);
+    let s = skip_space(s.strip_prefix('=').unwrap_or(s));
+    match control_sequence(s) {
+        Some((_, rest)) => Some(rest),
+        None => s.chars().next().map(|c| &s[c.len_utf8()..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_macro_definitions() {
+        let macros = BTreeMap::from([
+            ("R".to_string(), "\\mathbb{R}".to_string()),
+            ("\\norm".to_string(), "\\lVert #1 \\rVert_{#2}".to_string()),
+        ]);
+        assert_eq!(
+            macro_definitions(&macros),
+            "\\def\\R{\\mathbb{R}}\n\\def\\norm#1#2{\\lVert #1 \\rVert_{#2}}\n"
+        );
+    }
+
+    #[test]
+    fn test_front_matter_macros() {
+        let front_matter = r#"---
+title: Notes
+macros:
+  R: \mathbb{R}
+  # comment
+  norm: '\left\lVert #1 \right\rVert'
+  abs: "\\lvert #1 \\rvert"
+tags: [math]
+---
+"#;
+        let macros = front_matter_macros(front_matter);
+        assert_eq!(macros.len(), 3);
+        assert_eq!(macros["R"], r"\mathbb{R}");
+        assert_eq!(macros["norm"], r"\left\lVert #1 \right\rVert");
+        assert_eq!(macros["abs"], r"\lvert #1 \rvert");
+        assert!(front_matter_macros("---\ntitle: x\n---\n").is_empty());
+    }
+
+    #[test]
+    fn test_equation_numbers() {
+        let mut equations = Equations::new(EquationNumbering::Ams);
+        assert_eq!(equations.number("x = 1"), ("x = 1".to_string(), vec![]));
+
+        let (latex, tag) =
+            equations.number(r"\begin{equation} E = mc^2 \label{energy} \end{equation}");
+        assert_eq!(latex, r"\begin{equation} E = mc^2  \end{equation}");
+        let expected = Tag {
+            text: "(1)".to_string(),
+            id: Some("eq-energy".to_string()),
+        };
+        assert_eq!(tag, [Some(expected)]);
+
+        assert_eq!(equations.number(r"\begin{align*} a \end{align*}").1, []);
+        assert_eq!(
+            equations.number(r"\begin{align} a \notag \end{align}").1,
+            []
+        );
+        let (latex, tag) = equations.number(r"a \tag{A} \label{a}");
+        assert_eq!(latex, "a  ");
+        assert_eq!(tag[0].as_ref().unwrap().text, "(A)");
+        let tag = equations.number(r"\begin{gather} b \end{gather}").1;
+        assert_eq!(tag[0].as_ref().unwrap().text, "(2)");
+
+        assert_eq!(equations.reference("energy", true).as_deref(), Some("(1)"));
+        assert_eq!(equations.reference("a", false).as_deref(), Some("A"));
+        assert_eq!(equations.reference("b", true), None);
+
+        let mut all = Equations::new(EquationNumbering::All);
+        assert_eq!(all.number("x").1[0].as_ref().unwrap().text, "(1)");
+        assert_eq!(
+            all.number(r"\tag*{$\star$} y").1[0].as_ref().unwrap().text,
+            r"$\star$"
+        );
+        assert_eq!(all.number(r"\begin{gather*} y \end{gather*}").1, []);
+    }
+
+    #[test]
+    fn test_equation_rows() {
+        let mut equations = Equations::new(EquationNumbering::Ams);
+        let (latex, tags) = equations
+            .number(r"\begin{align} a \label{x} \\ b \nonumber \\ c \label{y} \end{align}");
+        assert_eq!(latex, r"\begin{align} a  \\ b  \\ c  \end{align}");
+        let tag = |text: &str, label: &str| {
+            Some(Tag {
+                text: text.to_string(),
+                id: Some(anchor(label)),
+            })
+        };
+        assert_eq!(tags, [tag("(1)", "x"), None, tag("(2)", "y")]);
+        assert_eq!(equations.reference("y", true).as_deref(), Some("(2)"));
+
+        // Line breaks of nested environments and groups don't end a row
+        let (_, tags) = equations.number(
+            r"\begin{gather} \begin{matrix} 1 \\ 2 \end{matrix} \\ \text{{a \\ b}} \end{gather}",
+        );
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags[1].as_ref().unwrap().text, "(4)");
+        // Nor do those of single equations
+        let (_, tags) = equations.number(r"\begin{multline} a \\ b \end{multline}");
+        assert_eq!(tags.len(), 1);
+        // Starred environments only get the tags they're given
+        let (_, tags) = equations.number(r"\begin{align*} a \\ b \tag{B} \end{align*}");
+        assert_eq!(tags.len(), 2);
+        assert_eq!(
+            (tags[0].as_ref(), tags[1].as_ref().unwrap().text.as_str()),
+            (None, "(B)")
+        );
+    }
+
+    #[test]
+    fn test_split_refs() {
+        assert_eq!(
+            split_refs(r"see \eqref{a}, \ref{ b }."),
+            [
+                Piece::Text("see "),
+                Piece::Ref {
+                    label: "a",
+                    parens: true
+                },
+                Piece::Text(", "),
+                Piece::Ref {
+                    label: "b",
+                    parens: false
+                },
+                Piece::Text("."),
+            ]
+        );
+        assert_eq!(split_refs(r"\eqref{a"), [Piece::Text(r"\eqref{a")]);
+    }
+
+    #[test]
+    fn test_take_definitions() {
+        let only = |latex| {
+            let (rest, definitions) = take_definitions(latex);
+            is_blank(&rest) && !definitions.is_empty()
+        };
+        assert!(only(r"\newcommand{\R}{\mathbb{R}}"));
+        assert!(only(
+            "\\newcommand\\norm[1]{\\lVert #1 \\rVert} % norm\n\\def\\abs#1{|#1|}\n\\let\\eps=\\varepsilon"
+        ));
+        assert!(only(r"\DeclareMathOperator*{\argmax}{arg\,max}"));
+        assert!(only(r"\renewcommand{\vec}[2][x]{\{#1_#2\}}"));
+        assert!(!only(""));
+        assert!(!only(r"\frac{a}{b}"));
+
+        // Definitions are taken out of the expressions using them
+        assert_eq!(
+            take_definitions(r"\newcommand{\R}{\mathbb{R}} \R^n"),
+            (r" \R^n".to_string(), "\\def\\R{\\mathbb{R}}\n".to_string())
+        );
+        assert_eq!(
+            take_definitions(
+                r"\renewcommand*\norm [2] {\lVert #1 \rVert_{#2}} \newcommand{\v}[1][x]{\vec{#1}}"
+            ),
+            (
+                " ".to_string(),
+                "\\def\\norm#1#2{\\lVert #1 \\rVert_{#2}}\n\\newcommand{\\v}[1][x]{\\vec{#1}}\n"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            take_definitions(r"x^2 \\ \def\y{y} + \gdef\z#1{#1}\y"),
+            (
+                r"x^2 \\  + \y".to_string(),
+                "\\def\\y{y}\n\\gdef\\z#1{#1}\n".to_string()
+            )
+        );
+        // Incomplete definitions are left for the renderer to report
+        assert_eq!(
+            take_definitions(r"\newcommand{\R}{\mathbb{R}"),
+            (r"\newcommand{\R}{\mathbb{R}".to_string(), String::new())
+        );
+    }
+}
@@ -1,159 +1,839 @@
 //! GitHub Flavored Markdown to HTML converter using comrak.
-
-use comrak::plugins::syntect::SyntectAdapterBuilder;
-use comrak::{Options, Plugins, markdown_to_html_with_plugins};
-use regex::Regex;
-use std::sync::LazyLock;
-
+//!
+//! Math, diagrams, CSV tables, task list checkboxes and code blocks are
+//! rendered straight from the parsed AST, with their raw source, instead of
+//! being matched back out of comrak's HTML. Math, diagrams and blocks of
+//! languages registered by [plugins](crate::plugins) are rendered
+//! concurrently on the worker pool.
+//!
+//! Every expression sees the document's macros: those of the profile and the
+//! front matter, and the definitions made so far in the document's math.
+//! Definitions are taken out of their expression, and an expression holding
+//! nothing else is dropped. Display equations are numbered in document order, and
+//! `\eqref` in math or text links to the equation it refers to.
+
+use comrak::adapters::SyntaxHighlighterAdapter;
+use comrak::arena_tree::Node;
+use comrak::nodes::{Ast, AstNode, LineColumn, NodeHtmlBlock, NodeList, NodeValue};
+use comrak::plugins::syntect::{SyntectAdapter, SyntectAdapterBuilder};
+use comrak::{Arena, Options, format_html, parse_document};
+use layout::backends::svg::SVGWriter;
+use layout::gv::{DotParser, GraphBuilder};
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::OnceLock;
+
+use crate::cache::{Cache, CacheKey};
+use crate::csv_table;
 use crate::js_runtime;
+use crate::math;
+use crate::plugins::Plugins;
+use crate::pool;
+use crate::report::{Report, StageItems};
 use crate::svg_render;
+use crate::target::{CheckboxStyle, CodeBlockStyle, EquationNumbering, MathStyle, Profile};
+
+/// Diagram renderers and their locked versions, as cache keys name them.
+const MERMAID_ENGINE: &str = concat!("mermaid-rs-renderer ", env!("MERMAID_RS_RENDERER_VERSION"));
+const GRAPHVIZ_ENGINE: &str = concat!("layout-rs ", env!("LAYOUT_RS_VERSION"));
+
+/// Syntax highlighting themes bundled with syntect.
+pub const THEMES: [&str; 7] = [
+    "InspiredGitHub",
+    "Solarized (dark)",
+    "Solarized (light)",
+    "base16-eighties.dark",
+    "base16-mocha.dark",
+    "base16-ocean.dark",
+    "base16-ocean.light",
+];
+
+/// Theme closest to GitHub's own code block colors.
+pub const DEFAULT_THEME: &str = THEMES[0];
+
+// Syntect adapters for syntax highlighting, one per theme - built on first use and reused
+static SYNTECT_ADAPTERS: [OnceLock<SyntectAdapter>; THEMES.len()] =
+    [const { OnceLock::new() }; THEMES.len()];
+
+/// Markdown extensions that can be toggled in the config file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Extensions {
+    pub strikethrough: bool,
+    pub table: bool,
+    pub autolink: bool,
+    pub tasklist: bool,
+    pub superscript: bool,
+    pub footnotes: bool,
+    pub description_lists: bool,
+    /// `$...$`, `$$...$$`, `\(...\)`, `\[...\]` and ```` ```math ```` blocks.
+    pub math: bool,
+    /// ```` ```latex ```` and ```` ```tex ```` blocks as display math. Off by
+    /// default, as these often hold whole documents rather than an equation.
+    pub latex_fences: bool,
+}
 
-// Static regex patterns - compiled once and reused
-static PRE_BG_COLOR_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r#"<pre style="background-color:#[0-9a-fA-F]+;">"#).unwrap());
+impl Default for Extensions {
+    fn default() -> Self {
+        Self {
+            strikethrough: true,
+            table: true,
+            autolink: true,
+            tasklist: true,
+            superscript: true,
+            footnotes: true,
+            description_lists: true,
+            math: true,
+            latex_fences: false,
+        }
+    }
+}
 
-static CHECKED_CHECKBOX_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r#"<input[^>]*type="checkbox"[^>]*checked[^>]*/?\s*>"#).unwrap());
+fn syntect_adapter(theme: &str) -> &'static SyntectAdapter {
+    let index = THEMES.iter().position(|t| *t == theme).unwrap_or(0);
+    SYNTECT_ADAPTERS[index]
+        .get_or_init(|| SyntectAdapterBuilder::new().theme(THEMES[index]).build())
+}
 
-static UNCHECKED_CHECKBOX_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r#"<input[^>]*type="checkbox"[^>]*/?\s*>"#).unwrap());
+/// Converts GitHub Flavored Markdown to HTML tuned for a target profile.
+///
+/// Math and diagrams that fail to render are left as source; every
+/// expression and diagram is recorded in `report`. Rendered ones are reused
+/// from `cache` when possible.
+pub fn convert(
+    markdown: &str,
+    profile: &Profile,
+    cache: Option<&Cache>,
+    report: &mut Report,
+) -> String {
+    let arena = Arena::new();
+    let options = options(&profile.extensions);
+    let root = parse(&arena, markdown, &profile.extensions);
+    let mut preamble = document_macros(root, profile);
+
+    // Replace nodes comrak can't render the way we want with their final HTML.
+    // Math and diagrams are only collected here, then rendered all at once.
+    // Collect first: task items get a new text child while we walk.
+    let nodes: Vec<_> = root.descendants().collect();
+    let mut jobs = Vec::new();
+    let mut texts_with_refs = Vec::new();
+    for node in nodes {
+        match render_node(&arena, node, profile) {
+            // Definitions apply from where they are on, like in LaTeX. Each
+            // expression carries those made so far, so it renders the same
+            // on whichever runtime picks it up.
+            Some(RenderJob::Math {
+                latex,
+                display,
+                tags,
+                ..
+            }) => {
+                let (rest, definitions) = math::take_definitions(&latex);
+                preamble.push_str(&definitions);
+                if !definitions.is_empty() && math::is_blank(&rest) {
+                    remove_node(node);
+                    continue;
+                }
+                // Source is shown as written
+                let latex = if profile.math == MathStyle::Source {
+                    latex
+                } else {
+                    rest
+                };
+                jobs.push((
+                    node,
+                    RenderJob::Math {
+                        latex,
+                        display,
+                        tags,
+                        preamble: preamble.clone(),
+                    },
+                ));
+            }
+            Some(job) => jobs.push((node, job)),
+            None if has_refs(node) => texts_with_refs.push(node),
+            None => {}
+        }
+    }
 
-static DISPLAY_MATH_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r#"<span data-math-style="display">([^<]*)</span>"#).unwrap());
+    // References may come before the equation, so number them all first
+    let equations = number_equations(&mut jobs, profile.numbering);
+    resolve_refs(&mut jobs, &texts_with_refs, &equations, report);
+    render_jobs(&jobs, profile, cache, report);
 
-static INLINE_MATH_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r#"<span data-math-style="inline">([^<]*)</span>"#).unwrap());
+    let mut html = Vec::new();
+    format_html(root, &options, &mut html).expect("writing to a Vec never fails");
+    let html = String::from_utf8_lossy(&html).into_owned();
 
-static MATH_CODE_RE: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r#"<pre><code class="language-math" data-math-style="display">([^<]*)</code></pre>"#)
-        .unwrap()
-});
+    // Drop the newlines comrak puts between block tags for rich text editor compatibility
+    // (soft breaks were already turned into spaces and code blocks into <br>)
+    if profile.collapse_whitespace {
+        normalize_whitespace(&html)
+    } else {
+        html
+    }
+}
 
-static MERMAID_RE: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r#"<pre[^>]*><code class="language-mermaid">([\s\S]*?)</code></pre>"#).unwrap()
-});
+/// Math, a diagram or a plugin block, rendered on the worker pool.
+#[derive(Debug, Clone)]
+enum RenderJob {
+    Math {
+        latex: String,
+        display: bool,
+        /// Numbers shown next to a display equation, one per row.
+        tags: Vec<Option<math::Tag>>,
+        /// Macro definitions made before the expression, and in it.
+        preamble: String,
+    },
+    Mermaid {
+        definition: String,
+    },
+    Graphviz {
+        definition: String,
+    },
+    Plugin {
+        plugins: Plugins,
+        language: String,
+        body: String,
+        attributes: BTreeMap<String, String>,
+    },
+}
 
-static SPAN_TAG_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"</?span[^>]*>"#).unwrap());
+impl RenderJob {
+    /// Renders the content alone; wrapping it and recording it in the report
+    /// happen back in document order.
+    fn render(
+        &self,
+        style: MathStyle,
+        math_scale: f32,
+        cache: Option<&Cache>,
+    ) -> Result<String, String> {
+        match self {
+            RenderJob::Math {
+                latex,
+                display,
+                preamble,
+                ..
+            } => match style {
+                MathStyle::Source => render_latex(latex, *display, style, math_scale, preamble),
+                _ => cached(
+                    cache,
+                    || math_cache_key(latex, *display, style, math_scale, preamble),
+                    || render_latex(latex, *display, style, math_scale, preamble),
+                ),
+            },
+            RenderJob::Mermaid { definition } => {
+                let key = || {
+                    CacheKey::new("mermaid", MERMAID_ENGINE, definition, true)
+                        .scale(svg_render::RENDER_SCALE)
+                };
+                cached(cache, key, || mermaid_to_png(definition))
+            }
+            RenderJob::Graphviz { definition } => {
+                let key = || {
+                    CacheKey::new("graphviz", GRAPHVIZ_ENGINE, definition, true)
+                        .scale(svg_render::RENDER_SCALE)
+                };
+                cached(cache, key, || graphviz_to_png(definition))
+            }
+            // Plugins may render differently from one run to the next
+            RenderJob::Plugin {
+                plugins,
+                language,
+                body,
+                attributes,
+            } => plugin_to_html(plugins, language, body, attributes),
+        }
+    }
+}
+
+/// Rewrites a single AST node for the target profile, or returns the job
+/// that will render it.
+fn render_node<'a>(
+    arena: &'a Arena<AstNode<'a>>,
+    node: &'a AstNode<'a>,
+    profile: &Profile,
+) -> Option<RenderJob> {
+    let value = match &node.data.borrow().value {
+        NodeValue::Math(math) => {
+            return Some(RenderJob::Math {
+                latex: math.literal.clone(),
+                display: math.display_math,
+                tags: Vec::new(),
+                preamble: String::new(),
+            });
+        }
+        NodeValue::CodeBlock(block) => {
+            let lang = block.info.split_whitespace().next().unwrap_or("");
+            match lang {
+                _ if profile.plugins.renders(lang) => {
+                    return Some(RenderJob::Plugin {
+                        plugins: profile.plugins.clone(),
+                        language: lang.to_string(),
+                        body: block.literal.clone(),
+                        attributes: info_attributes(&block.info),
+                    });
+                }
+                "math" if profile.extensions.math => {
+                    return Some(RenderJob::Math {
+                        latex: block.literal.trim().to_string(),
+                        display: true,
+                        tags: Vec::new(),
+                        preamble: String::new(),
+                    });
+                }
+                "latex" | "tex" if profile.extensions.math && profile.extensions.latex_fences => {
+                    return Some(RenderJob::Math {
+                        latex: block.literal.trim().to_string(),
+                        display: true,
+                        tags: Vec::new(),
+                        preamble: String::new(),
+                    });
+                }
+                "mermaid" if profile.mermaid => {
+                    return Some(RenderJob::Mermaid {
+                        definition: block.literal.clone(),
+                    });
+                }
+                "dot" | "graphviz" if profile.graphviz => {
+                    return Some(RenderJob::Graphviz {
+                        definition: block.literal.clone(),
+                    });
+                }
+                // Malformed CSV is still readable as code
+                "csv" | "tsv" if profile.extensions.table => html_block(
+                    csv_table::to_html(lang, &block.info, &block.literal)
+                        .unwrap_or_else(|_| highlight_code(lang, &block.literal, profile)),
+                ),
+                _ => html_block(highlight_code(lang, &block.literal, profile)),
+            }
+        }
+        NodeValue::TaskItem(symbol) => {
+            render_task_item(arena, node, symbol.is_some(), profile.checkboxes)
+        }
+        NodeValue::SoftBreak if profile.collapse_whitespace => NodeValue::Text(" ".to_string()),
+        _ => return None,
+    };
+    node.data.borrow_mut().value = value;
+    None
+}
 
-// Match code blocks for newline conversion (pre tag with code inside)
-static CODE_BLOCK_RE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r#"(<pre[^>]*><code[^>]*>)([\s\S]*?)(</code></pre>)"#).unwrap());
+/// Renders every job concurrently on the worker pool, then splices the
+/// results into their nodes and records them in document order.
+fn render_jobs<'a>(
+    jobs: &[(&'a AstNode<'a>, RenderJob)],
+    profile: &Profile,
+    cache: Option<&Cache>,
+    report: &mut Report,
+) {
+    let (style, math_scale) = (profile.math, profile.math_scale);
+    let tasks = jobs
+        .iter()
+        .map(|(_, job)| {
+            let job = job.clone();
+            let cache = cache.cloned();
+            move || job.render(style, math_scale, cache.as_ref())
+        })
+        .collect();
+    let results = pool::run_all(tasks);
+
+    for ((node, job), rendered) in jobs.iter().zip(results) {
+        let rendered = rendered.and_then(|result| result);
+        let start = node.data.borrow().sourcepos.start;
+        let html = match job {
+            RenderJob::Math {
+                latex,
+                display,
+                tags,
+                ..
+            } => math_html(latex, *display, tags, start, rendered, report),
+            RenderJob::Mermaid { definition } => diagram_html(
+                "mermaid",
+                definition,
+                profile.code_blocks,
+                start,
+                rendered,
+                &mut report.mermaid,
+            ),
+            RenderJob::Graphviz { definition } => diagram_html(
+                "graphviz",
+                definition,
+                profile.code_blocks,
+                start,
+                rendered,
+                &mut report.graphviz,
+            ),
+            RenderJob::Plugin { language, body, .. } => {
+                plugin_html(language, body, profile.code_blocks, start, rendered, report)
+            }
+        };
+        let is_block = matches!(node.data.borrow().value, NodeValue::CodeBlock(_));
+        node.data.borrow_mut().value = if is_block {
+            html_block(html)
+        } else {
+            NodeValue::HtmlInline(html)
+        };
+    }
+}
 
-// Match whitespace between block-level tags (should be removed, not converted to space)
-static BLOCK_TAG_WHITESPACE_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r">\s+<").unwrap());
+/// Macro definitions from the profile, overridden by the front matter's.
+fn document_macros<'a>(root: &'a AstNode<'a>, profile: &Profile) -> String {
+    let mut macros = profile.macros.clone();
+    for node in root.children() {
+        if let NodeValue::FrontMatter(front_matter) = &node.data.borrow().value {
+            macros.extend(math::front_matter_macros(front_matter));
+        }
+    }
+    math::macro_definitions(&macros)
+}
 
-// Static comrak options - built once and reused
-static COMRAK_OPTIONS: LazyLock<Options> = LazyLock::new(build_options);
+/// Takes a node out of the document, along with its paragraph if nothing
+/// else is left in it.
+fn remove_node<'a>(node: &'a AstNode<'a>) {
+    let parent = node.parent();
+    node.detach();
+    if let Some(paragraph) = parent {
+        let is_empty = paragraph.children().all(|child| {
+            matches!(
+                child.data.borrow().value,
+                NodeValue::SoftBreak | NodeValue::LineBreak
+            )
+        });
+        if is_empty && matches!(paragraph.data.borrow().value, NodeValue::Paragraph) {
+            paragraph.detach();
+        }
+    }
+}
 
-// Static syntect adapter for syntax highlighting - built once and reused
-static SYNTECT_ADAPTER: LazyLock<comrak::plugins::syntect::SyntectAdapter> =
-    LazyLock::new(|| SyntectAdapterBuilder::new().build());
+/// Whether a text node mentions `\eqref` or `\ref`.
+fn has_refs(node: &AstNode<'_>) -> bool {
+    match &node.data.borrow().value {
+        NodeValue::Text(text) => math::split_refs(text)
+            .iter()
+            .any(|piece| matches!(piece, math::Piece::Ref { .. })),
+        _ => false,
+    }
+}
 
-/// Converts GitHub Flavored Markdown to HTML.
-pub fn convert(markdown: &str) -> String {
-    // Set up plugins with the cached syntax highlighter adapter
-    let mut plugins = Plugins::default();
-    plugins.render.codefence_syntax_highlighter = Some(&*SYNTECT_ADAPTER);
+/// Numbers display equations in document order, taking their `\label` and
+/// `\tag` out of the source to render.
+fn number_equations(
+    jobs: &mut [(&AstNode<'_>, RenderJob)],
+    numbering: EquationNumbering,
+) -> math::Equations {
+    let mut equations = math::Equations::new(numbering);
+    for (_, job) in jobs.iter_mut() {
+        if let RenderJob::Math {
+            latex,
+            display: true,
+            tags,
+            ..
+        } = job
+        {
+            (*latex, *tags) = equations.number(latex);
+        }
+    }
+    equations
+}
 
-    // Convert markdown to HTML using comrak with math support and syntax highlighting
-    let html = markdown_to_html_with_plugins(markdown, &COMRAK_OPTIONS, &plugins);
+/// Replaces `\eqref` and `\ref` with equation numbers. References in text,
+/// and math that is nothing but a reference, become links to the equation.
+fn resolve_refs<'a>(
+    jobs: &mut Vec<(&'a AstNode<'a>, RenderJob)>,
+    texts: &[&'a AstNode<'a>],
+    equations: &math::Equations,
+    report: &mut Report,
+) {
+    jobs.retain_mut(|(node, job)| {
+        let RenderJob::Math { latex, .. } = job else {
+            return true;
+        };
+        let start = node.data.borrow().sourcepos.start;
+        let pieces = math::split_refs(latex.trim());
+        if let [math::Piece::Ref { label, parens }] = pieces[..] {
+            let link = ref_link(label, parens, equations, start, report);
+            node.data.borrow_mut().value = NodeValue::HtmlInline(link);
+            return false;
+        }
+        if pieces.len() > 1 {
+            let resolved = pieces
+                .iter()
+                .map(|piece| match *piece {
+                    math::Piece::Text(text) => text.to_string(),
+                    math::Piece::Ref { label, parens } => {
+                        format!(
+                            r"\text{{{}}}",
+                            reference(label, parens, equations, start, report)
+                        )
+                    }
+                })
+                .collect();
+            *latex = resolved;
+        }
+        true
+    });
 
-    // Post-process: convert checkboxes to Unicode for compatibility
-    let html = convert_checkboxes_to_unicode(&html);
+    for node in texts {
+        let start = node.data.borrow().sourcepos.start;
+        let html = match &node.data.borrow().value {
+            NodeValue::Text(text) => math::split_refs(text)
+                .iter()
+                .map(|piece| match *piece {
+                    math::Piece::Text(text) => html_escape(text),
+                    math::Piece::Ref { label, parens } => {
+                        ref_link(label, parens, equations, start, report)
+                    }
+                })
+                .collect(),
+            _ => continue,
+        };
+        node.data.borrow_mut().value = NodeValue::HtmlInline(html);
+    }
+}
 
-    // Post-process: convert LaTeX in math spans to SVG using MathJax
-    let html = convert_math_to_svg(&html);
+/// Link to the equation labelled `label`.
+fn ref_link(
+    label: &str,
+    parens: bool,
+    equations: &math::Equations,
+    start: LineColumn,
+    report: &mut Report,
+) -> String {
+    format!(
+        r##"<a href="#{}">{}</a>"##,
+        html_escape(math::anchor(label)),
+        html_escape(reference(label, parens, equations, start, report))
+    )
+}
 
-    // Post-process: convert Mermaid code blocks to PNG images
-    // Note: must run BEFORE fix_pre_background_color so the regex matches
-    let html = convert_mermaid_to_png(&html);
+/// Number of the equation labelled `label`. Unknown labels are shown as
+/// `??`, like LaTeX does, and reported.
+fn reference(
+    label: &str,
+    parens: bool,
+    equations: &math::Equations,
+    start: LineColumn,
+    report: &mut Report,
+) -> String {
+    equations.reference(label, parens).unwrap_or_else(|| {
+        let command = if parens { "eqref" } else { "ref" };
+        let error = format!("unknown equation label '{label}'");
+        report
+            .math
+            .record(&format!("\\{command}{{{label}}}"), start, Err(error));
+        if parens { "(??)" } else { "??" }.to_string()
+    })
+}
 
-    // Post-process: fix background-color in pre tags for proper code block styling
-    // The syntect adapter adds white background which doesn't match GitHub styling
-    let html = fix_pre_background_color(&html);
+/// Raw HTML block node holding `html`.
+fn html_block(html: String) -> NodeValue {
+    NodeValue::HtmlBlock(NodeHtmlBlock {
+        block_type: 0,
+        literal: html + "\n",
+    })
+}
 
-    // Post-process: convert newlines to <br> in code blocks for Teams compatibility
-    let html = convert_code_block_newlines(&html);
+/// Syntax-highlights code with the profile's theme.
+fn highlight_code(lang: &str, code: &str, profile: &Profile) -> String {
+    let adapter = syntect_adapter(&profile.theme);
+    let mut pre_tag = Vec::new();
+    let mut highlighted = Vec::new();
+    adapter
+        .write_pre_tag(&mut pre_tag, HashMap::new())
+        .and_then(|()| {
+            adapter.write_highlighted(&mut highlighted, Some(lang).filter(|l| !l.is_empty()), code)
+        })
+        .expect("writing to a Vec never fails");
 
-    // Post-process: convert remaining newlines to spaces for rich text editor compatibility
-    // (code blocks already have newlines converted to <br>, so this only affects regular content)
-    normalize_whitespace(&html)
+    // The syntect adapter adds its theme background, which doesn't match GitHub styling
+    let pre_tag = String::from_utf8_lossy(&pre_tag);
+    let pre_tag = if profile.inline_code_style {
+        fix_pre_background_color(&pre_tag)
+    } else {
+        pre_tag.into_owned()
+    };
+    let code_tag = if lang.is_empty() {
+        "<code>".to_string()
+    } else {
+        format!(r#"<code class="language-{}">"#, html_escape(lang))
+    };
+    let content = code_line_breaks(&String::from_utf8_lossy(&highlighted), profile.code_blocks);
+    format!("{pre_tag}{code_tag}{content}</code></pre>")
 }
 
+/// Elements whose content keeps its whitespace as written.
+const PREFORMATTED_TAGS: [&str; 3] = ["pre", "textarea", "code"];
+
 /// Normalizes whitespace in HTML content.
-/// - Removes whitespace between HTML tags (block-level structure)
-/// - Converts newlines within text content to spaces (inline text wrapping)
+/// - Removes whitespace runs containing a newline between two tags (block-level structure)
+/// - Converts other newlines to spaces (inline text wrapping in raw HTML)
 ///
-/// Code blocks are unaffected since their newlines have already been converted to `<br>` tags.
+/// Whitespace without a newline is kept, so `</em> <strong>` stays separated.
+/// The content of [`PREFORMATTED_TAGS`] elements, such as a raw `<pre>`
+/// block, is left untouched.
 fn normalize_whitespace(html: &str) -> String {
-    // First, remove whitespace between tags (preserves block structure without gaps)
-    let collapsed = BLOCK_TAG_WHITESPACE_RE.replace_all(html, "><");
-    // Then replace any remaining newlines (within text content) with spaces
-    collapsed.replace('\n', " ")
+    let mut result = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find(|c: char| c.is_ascii_whitespace() || c == '<') {
+        let (text, tail) = rest.split_at(start);
+        result.push_str(text);
+        if tail.starts_with('<') {
+            let end = preformatted_len(tail).unwrap_or(1);
+            result.push_str(&tail[..end]);
+            rest = &tail[end..];
+            continue;
+        }
+        let end = tail
+            .find(|c: char| !c.is_ascii_whitespace())
+            .unwrap_or(tail.len());
+        let (run, after) = tail.split_at(end);
+        let between_tags = (result.is_empty() || result.ends_with('>'))
+            && (after.is_empty() || after.starts_with('<'));
+        if !run.contains('\n') {
+            result.push_str(run);
+        } else if !between_tags {
+            result.push(' ');
+        }
+        rest = after;
+    }
+    result.push_str(rest);
+    result
 }
 
-/// Replaces syntect's background-color in pre tags with GitHub's code block background.
+/// Length of the preformatted element `html` starts with, up to the end of
+/// its closing tag (or of `html` if it isn't closed).
+fn preformatted_len(html: &str) -> Option<usize> {
+    let name = PREFORMATTED_TAGS.iter().find(|name| {
+        html.get(1..=name.len())
+            .is_some_and(|n| n.eq_ignore_ascii_case(name))
+            && html[name.len() + 1..].starts_with(|c: char| c == '>' || c.is_ascii_whitespace())
+    })?;
+    let close = format!("</{name}");
+    let Some(close_at) = html
+        .as_bytes()
+        .windows(close.len())
+        .position(|w| w.eq_ignore_ascii_case(close.as_bytes()))
+    else {
+        return Some(html.len());
+    };
+    let end = html[close_at..]
+        .find('>')
+        .map_or(html.len(), |i| close_at + i + 1);
+    Some(end)
+}
+
+/// Replaces syntect's background-color in a pre tag with GitHub's code block background.
 /// Syntect uses white (#ffffff) which doesn't match GitHub styling.
 /// We use GitHub's light-mode code block background (#f6f8fa) for better visibility.
+/// Other themes keep their own background so their token colors stay readable.
 /// Also adds monospace font-family for editors that strip CSS classes (e.g., Google Docs).
-fn fix_pre_background_color(html: &str) -> String {
-    PRE_BG_COLOR_RE.replace_all(
-        html,
-        r#"<pre style="background-color:#f6f8fa;padding:16px;border-radius:6px;overflow:auto;font-family:monospace;">"#,
+fn fix_pre_background_color(pre_tag: &str) -> String {
+    let color = pre_tag
+        .split("background-color:")
+        .nth(1)
+        .and_then(|rest| rest.split(';').next())
+        .filter(|color| color.starts_with('#'));
+    let color = match color {
+        Some(c) if c.eq_ignore_ascii_case("#ffffff") => "#f6f8fa",
+        Some(c) => c,
+        None => return pre_tag.to_string(),
+    };
+    format!(
+        r#"<pre style="background-color:{color};padding:16px;border-radius:6px;overflow:auto;font-family:monospace;">"#
     )
-    .into_owned()
 }
 
-/// Converts newlines to `<br>` tags inside code blocks.
+/// Converts newlines to `<br>` tags inside a code block's content.
 /// This ensures line breaks are preserved when pasting into rich text editors
 /// like Microsoft Teams, which may strip plain newlines.
-fn convert_code_block_newlines(html: &str) -> String {
-    CODE_BLOCK_RE
-        .replace_all(html, |caps: &regex::Captures| {
-            let pre_open = caps.get(1).map(|m| m.as_str()).unwrap_or("");
-            let content = caps.get(2).map(|m| m.as_str()).unwrap_or("");
-            let close = caps.get(3).map(|m| m.as_str()).unwrap_or("");
-            // Replace newlines with <br> to preserve line breaks in rich text editors
-            let content_with_br = content.replace('\n', "<br>");
-            // Remove trailing <br> that appears before </span></code> (from fenced code block syntax)
-            let content_trimmed = content_with_br
-                .strip_suffix("<br></span>")
-                .map(|s| format!("{s}</span>"))
-                .unwrap_or(content_with_br);
-            format!("{pre_open}{content_trimmed}{close}")
-        })
-        .into_owned()
+fn code_line_breaks(content: &str, style: CodeBlockStyle) -> String {
+    if style == CodeBlockStyle::Preformatted {
+        return content.to_string();
+    }
+    let content = content.replace('\n', "<br>");
+    // Remove the trailing <br> left by the fence's final newline
+    if let Some(s) = content.strip_suffix("<br></span>") {
+        format!("{s}</span>")
+    } else if let Some(s) = content.strip_suffix("<br>") {
+        s.to_string()
+    } else {
+        content
+    }
 }
 
-/// Converts HTML checkbox inputs to Unicode square symbols for better compatibility.
-/// - Checked: ✅ (U+2705 WHITE HEAVY CHECK MARK)
-/// - Unchecked: ⬜ (U+2B1C WHITE LARGE SQUARE)
-fn convert_checkboxes_to_unicode(html: &str) -> String {
-    // Match checked checkbox (has "checked" attribute)
-    let result = CHECKED_CHECKBOX_RE.replace_all(html, "✅ ");
+/// Turns a task item into a plain list item starting with a Unicode checkbox.
+/// - Emoji: ✅ (U+2705 WHITE HEAVY CHECK MARK) / ⬜ (U+2B1C WHITE LARGE SQUARE)
+/// - Ballot box: ☑ (U+2611 BALLOT BOX WITH CHECK) / ☐ (U+2610 BALLOT BOX)
+fn render_task_item<'a>(
+    arena: &'a Arena<AstNode<'a>>,
+    node: &'a AstNode<'a>,
+    checked: bool,
+    style: CheckboxStyle,
+) -> NodeValue {
+    let symbol = match (style, checked) {
+        (CheckboxStyle::Emoji, true) => "✅ ",
+        (CheckboxStyle::Emoji, false) => "⬜ ",
+        (CheckboxStyle::BallotBox, true) => "☑ ",
+        (CheckboxStyle::BallotBox, false) => "☐ ",
+    };
+
+    // Put the symbol inside the first paragraph so it stays on the item's first line
+    let start = node.data.borrow().sourcepos.start;
+    let text = arena.alloc(Node::new(RefCell::new(Ast::new(
+        NodeValue::Text(symbol.to_string()),
+        start,
+    ))));
+    match node.first_child() {
+        Some(first) if matches!(first.data.borrow().value, NodeValue::Paragraph) => {
+            first.prepend(text)
+        }
+        _ => node.prepend(text),
+    }
 
-    // Match unchecked checkbox (no "checked" attribute)
-    UNCHECKED_CHECKBOX_RE
-        .replace_all(&result, "⬜ ")
-        .into_owned()
+    let list = node
+        .parent()
+        .map(|parent| match parent.data.borrow().value {
+            NodeValue::List(list) => list,
+            _ => NodeList::default(),
+        });
+    NodeValue::Item(list.unwrap_or_default())
 }
 
-/// Builds comrak options with GFM extensions enabled.
-fn build_options() -> Options {
+/// Parses `markdown` with the given extensions enabled.
+///
+/// Markdown reads LaTeX's `\(...\)` and `\[...\]` as escaped brackets, so
+/// with math enabled they are first rewritten to comrak's `` $`...`$ `` and
+/// `$$...$$`.
+pub fn parse<'a>(
+    arena: &'a Arena<AstNode<'a>>,
+    markdown: &str,
+    extensions: &Extensions,
+) -> &'a AstNode<'a> {
+    let options = options(extensions);
+    let markdown = if extensions.math {
+        bracket_math(markdown, &options)
+    } else {
+        Cow::Borrowed(markdown)
+    };
+    parse_document(arena, &markdown, &options)
+}
+
+/// Rewrites `\(...\)` and `\[...\]` to dollar math where comrak parses
+/// them as escaped brackets in text, so not in code or raw HTML.
+///
+/// A delimiter right after a letter or digit, as in `a\[0\]`, is taken for
+/// an escaped bracket, as are delimiters left unclosed in their block.
+fn bracket_math<'m>(markdown: &'m str, options: &Options) -> Cow<'m, str> {
+    if !markdown.contains("\\(") && !markdown.contains("\\[") {
+        return Cow::Borrowed(markdown);
+    }
+    let mut options = options.clone();
+    options.render.escaped_char_spans = true;
+    let arena = Arena::new();
+    let root = parse_document(&arena, markdown, &options);
+
+    // Offsets of the brackets' backslashes, by block. Sourcepos columns count
+    // bytes, and inline ones are accurate from comrak 0.36 on.
+    let line_starts: Vec<_> = std::iter::once(0)
+        .chain(markdown.match_indices('\n').map(|(i, _)| i + 1))
+        .collect();
+    let mut blocks: Vec<(&AstNode<'_>, Vec<(char, usize)>)> = Vec::new();
+    for node in root.descendants() {
+        if !matches!(node.data.borrow().value, NodeValue::Escaped) {
+            continue;
+        }
+        let start = node.data.borrow().sourcepos.start;
+        let offset = start
+            .line
+            .checked_sub(1)
+            .and_then(|line| line_starts.get(line))
+            .map(|line_start| line_start + start.column.saturating_sub(1));
+        let bracket = offset.and_then(|offset| {
+            let bracket = markdown.get(offset..)?.strip_prefix('\\')?.chars().next()?;
+            matches!(bracket, '(' | ')' | '[' | ']').then_some((bracket, offset))
+        });
+        let Some(bracket) = bracket else {
+            continue;
+        };
+        let block = node
+            .ancestors()
+            .find(|ancestor| ancestor.data.borrow().value.block())
+            .unwrap_or(root);
+        match blocks.last_mut() {
+            Some((last, brackets)) if std::ptr::eq(*last, block) => brackets.push(bracket),
+            _ => blocks.push((block, vec![bracket])),
+        }
+    }
+
+    let mut out = String::with_capacity(markdown.len());
+    let mut copied = 0;
+    for (_, brackets) in blocks {
+        let mut rest = &brackets[..];
+        while let Some((&(open, start), after)) = rest.split_first() {
+            rest = after;
+            let (close, display) = match open {
+                '(' => (')', false),
+                '[' => (']', true),
+                _ => continue,
+            };
+            if markdown[..start].ends_with(|c: char| c.is_alphanumeric()) {
+                continue;
+            }
+            let Some(end) = after.iter().position(|&(c, _)| c == close) else {
+                continue;
+            };
+            let latex = &markdown[start + 2..after[end].1];
+            if latex.trim().is_empty() {
+                continue;
+            }
+            out.push_str(&markdown[copied..start]);
+            push_dollar_math(latex, display, &mut out);
+            copied = after[end].1 + 2;
+            rest = &after[end + 1..];
+        }
+    }
+    out.push_str(&markdown[copied..]);
+    Cow::Owned(out)
+}
+
+fn push_dollar_math(latex: &str, display: bool, out: &mut String) {
+    if display {
+        out.push_str("$$");
+        out.push_str(latex);
+        out.push_str("$$");
+    } else if latex.contains('`') {
+        // Code math can't hold backticks; dollar math can't start or end with a space
+        out.push('$');
+        out.push_str(latex.trim());
+        out.push('$');
+    } else {
+        out.push_str("$`");
+        out.push_str(latex);
+        out.push_str("`$");
+    }
+}
+
+/// Builds comrak options with the given extensions enabled.
+pub fn options(extensions: &Extensions) -> Options<'static> {
     let mut options = Options::default();
 
     // Enable GFM extensions
-    options.extension.strikethrough = true;
-    options.extension.table = true;
-    options.extension.autolink = true;
-    options.extension.tasklist = true;
-    options.extension.superscript = true;
-    options.extension.footnotes = true;
-    options.extension.description_lists = true;
+    options.extension.strikethrough = extensions.strikethrough;
+    options.extension.table = extensions.table;
+    options.extension.autolink = extensions.autolink;
+    options.extension.tasklist = extensions.tasklist;
+    options.extension.superscript = extensions.superscript;
+    options.extension.footnotes = extensions.footnotes;
+    options.extension.description_lists = extensions.description_lists;
 
     // Enable math with dollar syntax ($...$ and $$...$$)
-    options.extension.math_dollars = true;
-    options.extension.math_code = true;
+    options.extension.math_dollars = extensions.math;
+    options.extension.math_code = extensions.math;
+
+    // YAML front matter is metadata (and math macros), not content
+    options.extension.front_matter_delimiter = Some("---".to_string());
 
     // Render options
     options.render.unsafe_ = true; // Allow raw HTML passthrough
@@ -161,25 +841,8 @@ fn build_options() -> Options {
     options
 }
 
-/// Decodes HTML entities in LaTeX content.
-/// Comrak HTML-encodes content, but MathJax needs raw LaTeX.
-fn decode_html_entities(s: &str) -> std::borrow::Cow<'_, str> {
-    // Fast path: if no entities present, return borrowed reference
-    if !s.contains('&') {
-        return std::borrow::Cow::Borrowed(s);
-    }
-    // Slow path: decode entities
-    std::borrow::Cow::Owned(
-        s.replace("&amp;", "&")
-            .replace("&lt;", "<")
-            .replace("&gt;", ">")
-            .replace("&quot;", "\"")
-            .replace("&#39;", "'"),
-    )
-}
-
 /// HTML-escapes a string.
-fn html_escape(s: impl AsRef<str>) -> String {
+pub(crate) fn html_escape(s: impl AsRef<str>) -> String {
     let s = s.as_ref();
     s.replace("&", "&amp;")
         .replace("<", "&lt;")
@@ -188,26 +851,73 @@ fn html_escape(s: impl AsRef<str>) -> String {
         .replace("'", "&#39;")
 }
 
+/// Parses the attributes following the language in an info string:
+/// `key=value`, `key="quoted value"`, or a bare `key` set to an empty string.
+pub fn info_attributes(info: &str) -> BTreeMap<String, String> {
+    let mut attributes = BTreeMap::new();
+    let mut rest = info.trim_start();
+    // Skip the language
+    rest = rest
+        .find(char::is_whitespace)
+        .map_or("", |end| rest[end..].trim_start());
+    while !rest.is_empty() {
+        let end = rest
+            .find(|c: char| c.is_whitespace() || c == '=')
+            .unwrap_or(rest.len());
+        let key = &rest[..end];
+        rest = &rest[end..];
+        let value = match rest.strip_prefix('=') {
+            Some(value) => {
+                let (value, after) = attribute_value(value);
+                rest = after;
+                value
+            }
+            None => String::new(),
+        };
+        if !key.is_empty() {
+            attributes.insert(key.to_string(), value);
+        }
+        rest = rest.trim_start();
+    }
+    attributes
+}
+
+/// Splits a leading attribute value, quoted or not, from what follows it.
+fn attribute_value(s: &str) -> (String, &str) {
+    for quote in ['"', '\''] {
+        if let Some(quoted) = s.strip_prefix(quote) {
+            return match quoted.find(quote) {
+                Some(end) => (quoted[..end].to_string(), &quoted[end + 1..]),
+                None => (quoted.to_string(), ""),
+            };
+        }
+    }
+    let end = s.find(char::is_whitespace).unwrap_or(s.len());
+    (s[..end].to_string(), &s[end..])
+}
+
 /// Renders LaTeX to PNG image tag using embedded MathJax + resvg.
 ///
 /// This function:
 /// 1. Converts LaTeX to SVG using MathJax (via embedded QuickJS)
-/// 2. Renders SVG to PNG using resvg (pure Rust)
-/// 3. Returns an HTML img tag with base64-encoded PNG
-fn latex_to_svg(latex: &str, display: bool) -> Result<String, String> {
+/// 2. Renders SVG to PNG using resvg (pure Rust), sized for text `scale`
+///    times [`svg_render::MATH_FONT_SIZE`]
+/// 3. Returns an HTML img tag with base64-encoded PNG, inline math sitting
+///    on the text's baseline
+fn latex_to_svg(latex: &str, display: bool, scale: f32, preamble: &str) -> Result<String, String> {
     // Step 1: Convert LaTeX to SVG using embedded MathJax
-    let svg = js_runtime::convert_latex_to_svg(latex, display)?;
+    let (svg, size) = js_runtime::convert_latex_to_svg(latex, display, preamble)?;
 
     // Step 2: Render SVG to PNG using resvg
-    let render_result = svg_render::render_svg_to_png(&svg)?;
+    let render_result = svg_render::render_math_svg_to_png(&svg, size, scale)?;
 
     // Step 3: Build <img> tag with base64 PNG
     let data_uri = format!("data:image/png;base64,{}", render_result.png_base64);
     let alt = latex.replace('"', "&quot;");
     let style = if display {
-        "display:block;margin:0.5em auto;"
+        "display:block;margin:0.5em auto;".to_string()
     } else {
-        "vertical-align:middle;"
+        format!("vertical-align:{:.2}px;", render_result.vertical_align)
     };
 
     Ok(format!(
@@ -216,54 +926,116 @@ fn latex_to_svg(latex: &str, display: bool) -> Result<String, String> {
     ))
 }
 
-/// Converts LaTeX content in comrak's math spans to SVG using MathJax.
-///
-/// Comrak outputs math as:
-/// - Inline: `<span data-math-style="inline">latex</span>`
-/// - Display: `<span data-math-style="display">latex</span>`
-///
-/// This function converts the LaTeX content to inline SVG.
-fn convert_math_to_svg(html: &str) -> String {
-    // Match display math spans
-    let result = DISPLAY_MATH_RE.replace_all(html, |caps: &regex::Captures| {
-        let latex_raw = caps.get(1).map(|m| m.as_str()).unwrap_or("");
-        let latex = decode_html_entities(latex_raw);
-        match latex_to_svg(&latex, true) {
-            Ok(svg) => format!(r#"<div class="math math-display">{svg}</div>"#),
-            Err(_) => format!(
-                r#"<div class="math math-display math-error">$${}$$</div>"#,
-                html_escape(latex)
-            ),
-        }
-    });
+/// Runs `render` through the cache, if there is one.
+fn cached(
+    cache: Option<&Cache>,
+    key: impl FnOnce() -> CacheKey,
+    render: impl FnOnce() -> Result<String, String>,
+) -> Result<String, String> {
+    match cache {
+        Some(cache) => cache.get_or_render(&key(), render),
+        None => render(),
+    }
+}
 
-    // Match inline math spans
-    let result = INLINE_MATH_RE.replace_all(&result, |caps: &regex::Captures| {
-        let latex_raw = caps.get(1).map(|m| m.as_str()).unwrap_or("");
-        let latex = decode_html_entities(latex_raw);
-        match latex_to_svg(&latex, false) {
-            Ok(svg) => format!(r#"<span class="math math-inline">{svg}</span>"#),
-            Err(_) => format!(
-                r#"<span class="math math-inline math-error">${}$</span>"#,
-                html_escape(latex)
-            ),
+/// Cache key for math rendered in `style` at `scale` with the macros of
+/// `preamble`.
+fn math_cache_key(
+    latex: &str,
+    display: bool,
+    style: MathStyle,
+    scale: f32,
+    preamble: &str,
+) -> CacheKey {
+    let engine = match style {
+        MathStyle::Katex => format!("KaTeX {}", js_runtime::KATEX_VERSION),
+        _ => format!("MathJax {}", js_runtime::MATHJAX_VERSION),
+    };
+    let kind = format!("math-{}", style.name());
+    CacheKey::new(&kind, &engine, latex, display)
+        .scale(svg_render::RENDER_SCALE * scale)
+        .preamble(preamble)
+}
+
+/// Renders a LaTeX expression in the requested style.
+fn render_latex(
+    latex: &str,
+    display: bool,
+    style: MathStyle,
+    scale: f32,
+    preamble: &str,
+) -> Result<String, String> {
+    match style {
+        MathStyle::Png => latex_to_svg(latex, display, scale, preamble),
+        // A TeX error fails the PNG the same way, so its message is kept
+        MathStyle::MathMl => js_runtime::convert_latex_to_mathml(latex, display, preamble)
+            .or_else(|_| latex_to_svg(latex, display, scale, preamble)),
+        MathStyle::Katex => js_runtime::convert_latex_to_html(latex, display, preamble),
+        MathStyle::Source => {
+            let delim = if display { "$$" } else { "$" };
+            Ok(format!("<code>{delim}{}{delim}</code>", html_escape(latex)))
         }
-    });
+    }
+}
 
-    // Also handle math code blocks (```math)
-    MATH_CODE_RE
-        .replace_all(&result, |caps: &regex::Captures| {
-            let latex_raw = caps.get(1).map(|m| m.as_str()).unwrap_or("").trim();
-            let latex = decode_html_entities(latex_raw);
-            match latex_to_svg(&latex, true) {
-                Ok(svg) => format!(r#"<div class="math math-display">{svg}</div>"#),
-                Err(_) => format!(
-                    r#"<div class="math math-display math-error">$${}$$</div>"#,
-                    html_escape(latex)
-                ),
-            }
-        })
-        .into_owned()
+/// Wraps rendered math in its `math` element, falling back to the escaped
+/// source wrapped in a `math-error` element when rendering failed.
+///
+/// A numbered equation gets its number floated to the right, and an anchor
+/// if it's labelled.
+fn math_html(
+    latex: &str,
+    display: bool,
+    tags: &[Option<math::Tag>],
+    start: LineColumn,
+    rendered: Result<String, String>,
+    report: &mut Report,
+) -> String {
+    let (tag, class, delim) = if display {
+        ("div", "math math-display", "$$")
+    } else {
+        ("span", "math math-inline", "$")
+    };
+    // The numbers of several rows are stacked, each with its own anchor
+    let (id, numbers) = match tags {
+        [] => (None, String::new()),
+        [Some(tag)] => (tag.id.as_ref(), html_escape(&tag.text)),
+        rows => {
+            let numbers = rows
+                .iter()
+                .map(|tag| match tag {
+                    Some(math::Tag { text, id: Some(id) }) => {
+                        format!(
+                            r#"<span id="{}">{}</span>"#,
+                            html_escape(id),
+                            html_escape(text)
+                        )
+                    }
+                    Some(tag) => html_escape(&tag.text),
+                    None => "&nbsp;".to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join("<br>");
+            (None, numbers)
+        }
+    };
+    let id = id
+        .map(|id| format!(r#" id="{}""#, html_escape(id)))
+        .unwrap_or_default();
+    let number = if tags.is_empty() {
+        String::new()
+    } else {
+        format!(r#"<span class="math-tag" style="float:right;">{numbers}</span>"#)
+    };
+    let html = match &rendered {
+        Ok(inner) => format!(r#"<{tag} class="{class}"{id}>{number}{inner}</{tag}>"#),
+        Err(_) => format!(
+            r#"<{tag} class="{class} math-error"{id}>{number}{delim}{}{delim}</{tag}>"#,
+            html_escape(latex)
+        ),
+    };
+    report.math.record(latex, start, rendered.map(drop));
+    html
 }
 
 /// Sanitizes SVG font-family attributes that contain unescaped quotes.
@@ -294,12 +1066,38 @@ fn mermaid_to_png(definition: &str) -> Result<String, String> {
     // Step 1.5: Sanitize the SVG (fix invalid font-family attributes)
     let svg = sanitize_mermaid_svg(&svg);
 
-    // Step 2: Render SVG to PNG using resvg
-    let render_result = svg_render::render_svg_to_png(&svg)?;
+    // Step 2 and 3: Render SVG to PNG using resvg, in an <img> tag
+    diagram_img(&svg, "Mermaid diagram")
+}
 
-    // Step 3: Build <img> tag with base64 PNG
+/// Renders a Graphviz DOT graph to PNG image tag, laid out by layout-rs
+/// (pure Rust) instead of Graphviz's `dot`.
+fn graphviz_to_png(definition: &str) -> Result<String, String> {
+    let graph = DotParser::new(definition)
+        .process()
+        .map_err(|e| format!("Graphviz parse error: {e}"))?;
+    // layout-rs panics on some graphs it can't build or lay out
+    let svg = pool::catch_silently(|| {
+        let mut builder = GraphBuilder::new();
+        builder.visit_graph(&graph);
+        let mut graph = builder.get();
+        // And always on a graph without nodes
+        if graph.num_nodes() == 0 {
+            return Err("Graphviz rendering error: the graph has no nodes".to_string());
+        }
+        let mut svg = SVGWriter::new();
+        graph.do_it(false, false, false, &mut svg);
+        Ok(svg.finalize())
+    })
+    .map_err(|e| format!("Graphviz layout error: {e}"))??;
+
+    diagram_img(&svg, "Graphviz diagram")
+}
+
+/// Renders a diagram's SVG to a centered PNG `<img>` tag.
+fn diagram_img(svg: &str, alt: &str) -> Result<String, String> {
+    let render_result = svg_render::render_svg_to_png(svg)?;
     let data_uri = format!("data:image/png;base64,{}", render_result.png_base64);
-    let alt = "Mermaid diagram";
 
     Ok(format!(
         r#"<img src="{}" alt="{}" width="{}" height="{}" style="display:block;margin:0.5em auto;">"#,
@@ -307,72 +1105,113 @@ fn mermaid_to_png(definition: &str) -> Result<String, String> {
     ))
 }
 
-/// Strips HTML span tags from content, preserving the text inside.
-/// Used to clean up syntect's syntax highlighting spans from mermaid content.
-fn strip_span_tags(html: &str) -> String {
-    SPAN_TAG_RE.replace_all(html, "").into_owned()
+/// Wraps a rendered diagram in a `{kind}-diagram` block, falling back to its
+/// source in a `{kind}-error` block.
+fn diagram_html(
+    kind: &str,
+    definition: &str,
+    code_blocks: CodeBlockStyle,
+    start: LineColumn,
+    rendered: Result<String, String>,
+    items: &mut StageItems,
+) -> String {
+    let html = match &rendered {
+        Ok(img) => format!(r#"<div class="{kind}-diagram">{img}</div>"#),
+        Err(_) => format!(
+            r#"<pre class="{kind}-error"><code>{}</code></pre>"#,
+            code_line_breaks(&html_escape(definition), code_blocks)
+        ),
+    };
+    items.record(definition, start, rendered.map(drop));
+    html
 }
 
-/// Converts Mermaid code blocks to PNG images.
-///
-/// Comrak with syntect outputs mermaid code blocks as:
-/// `<pre style="..."><code class="language-mermaid"><span>...</span></code></pre>`
-///
-/// This function converts the Mermaid content to PNG images.
-fn convert_mermaid_to_png(html: &str) -> String {
-    MERMAID_RE
-        .replace_all(html, |caps: &regex::Captures| {
-            let definition_raw = caps.get(1).map(|m| m.as_str()).unwrap_or("");
-            // Strip span tags added by syntect syntax highlighting
-            let definition_stripped = strip_span_tags(definition_raw);
-            let definition = decode_html_entities(&definition_stripped);
-            match mermaid_to_png(&definition) {
-                Ok(img) => format!(r#"<div class="mermaid-diagram">{img}</div>"#),
-                Err(e) => {
-                    eprintln!("Mermaid rendering error: {e}");
-                    format!(
-                        r#"<pre class="mermaid-error"><code>{}</code></pre>"#,
-                        html_escape(definition)
-                    )
-                }
-            }
-        })
-        .into_owned()
+/// Renders a block with its plugin, turning SVG into a PNG image tag like
+/// Mermaid diagrams. Anything else is taken as HTML.
+fn plugin_to_html(
+    plugins: &Plugins,
+    language: &str,
+    body: &str,
+    attributes: &BTreeMap<String, String>,
+) -> Result<String, String> {
+    let output = plugins.render(language, body, attributes)?;
+    let trimmed = output.trim_start();
+    if !trimmed.starts_with("<svg") && !trimmed.starts_with("<?xml") {
+        return Ok(output);
+    }
+    let render_result = svg_render::render_svg_to_png(trimmed)?;
+    Ok(format!(
+        r#"<img src="data:image/png;base64,{}" alt="{}" width="{}" height="{}" style="display:block;margin:0.5em 0;">"#,
+        render_result.png_base64,
+        html_escape(language),
+        render_result.display_width,
+        render_result.display_height
+    ))
+}
+
+/// Wraps a block rendered by a plugin, falling back to its source in a
+/// `plugin-error` block.
+fn plugin_html(
+    language: &str,
+    body: &str,
+    code_blocks: CodeBlockStyle,
+    start: LineColumn,
+    rendered: Result<String, String>,
+    report: &mut Report,
+) -> String {
+    let html = match &rendered {
+        Ok(html) => format!(
+            r#"<div class="plugin plugin-{}">{html}</div>"#,
+            html_escape(language)
+        ),
+        Err(_) => format!(
+            r#"<pre class="plugin-error"><code>{}</code></pre>"#,
+            code_line_breaks(&html_escape(body), code_blocks)
+        ),
+    };
+    report.plugins.record(body, start, rendered.map(drop));
+    html
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::target::Target;
+
+    /// Converts with the default (generic) profile.
+    fn to_html(markdown: &str) -> String {
+        convert(markdown, &Profile::default(), None, &mut Report::default())
+    }
 
     #[test]
     fn test_headers() {
-        assert!(convert("# Hello").contains("<h1>"));
-        assert!(convert("## World").contains("<h2>"));
-        assert!(convert("### Test").contains("<h3>"));
+        assert!(to_html("# Hello").contains("<h1>"));
+        assert!(to_html("## World").contains("<h2>"));
+        assert!(to_html("### Test").contains("<h3>"));
     }
 
     #[test]
     fn test_bold() {
-        assert!(convert("**bold**").contains("<strong>"));
-        assert!(convert("__bold__").contains("<strong>"));
+        assert!(to_html("**bold**").contains("<strong>"));
+        assert!(to_html("__bold__").contains("<strong>"));
     }
 
     #[test]
     fn test_italic() {
-        assert!(convert("*italic*").contains("<em>"));
-        assert!(convert("_italic_").contains("<em>"));
+        assert!(to_html("*italic*").contains("<em>"));
+        assert!(to_html("_italic_").contains("<em>"));
     }
 
     #[test]
     fn test_links() {
-        let result = convert("[text](http://example.com)");
+        let result = to_html("[text](http://example.com)");
         assert!(result.contains("<a href="));
         assert!(result.contains("example.com"));
     }
 
     #[test]
     fn test_code_blocks() {
-        let result = convert("```rust\nfn main() {}\n```");
+        let result = to_html("```rust\nfn main() {}\n```");
         assert!(result.contains("<pre"));
         assert!(result.contains("<code"));
         // Syntax highlighting splits tokens across spans, so check for individual parts
@@ -386,45 +1225,52 @@ mod tests {
 
     #[test]
     fn test_inline_code() {
-        assert!(convert("`code`").contains("<code>"));
+        assert!(to_html("`code`").contains("<code>"));
     }
 
     #[test]
     fn test_horizontal_rule() {
-        assert!(convert("---").contains("<hr"));
+        assert!(to_html("---").contains("<hr"));
     }
 
     #[test]
     fn test_blockquote() {
-        assert!(convert("> quote").contains("<blockquote>"));
+        assert!(to_html("> quote").contains("<blockquote>"));
     }
 
     #[test]
     fn test_unordered_list() {
-        let result = convert("- item1\n- item2");
+        let result = to_html("- item1\n- item2");
         assert!(result.contains("<ul>"));
         assert!(result.contains("<li>"));
     }
 
     #[test]
     fn test_ordered_list() {
-        let result = convert("1. first\n2. second");
+        let result = to_html("1. first\n2. second");
         assert!(result.contains("<ol>"));
         assert!(result.contains("<li>"));
     }
 
     #[test]
     fn test_task_list() {
-        let result = convert("- [ ] todo\n- [x] done");
+        let result = to_html("- [ ] todo\n- [x] done");
         // Should use Unicode symbols instead of HTML checkboxes
         assert!(result.contains("⬜")); // Unchecked: WHITE LARGE SQUARE
         assert!(result.contains("✅")); // Checked: WHITE HEAVY CHECK MARK
         assert!(!result.contains(r#"type="checkbox""#)); // No HTML checkbox
     }
 
+    #[test]
+    fn test_task_list_symbol_inside_item() {
+        let result = to_html("- [x] **done**\n- [ ] todo");
+        assert!(result.contains("<li>✅ <strong>done</strong></li>"));
+        assert!(result.contains("<li>⬜ todo</li>"));
+    }
+
     #[test]
     fn test_table() {
-        let result = convert("| A | B |\n|---|---|\n| 1 | 2 |");
+        let result = to_html("| A | B |\n|---|---|\n| 1 | 2 |");
         assert!(result.contains("<table>"));
         assert!(result.contains("<th>"));
         assert!(result.contains("<td>"));
@@ -432,25 +1278,25 @@ mod tests {
 
     #[test]
     fn test_strikethrough() {
-        assert!(convert("~~deleted~~").contains("<del>"));
+        assert!(to_html("~~deleted~~").contains("<del>"));
     }
 
     #[test]
     fn test_images() {
-        let result = convert("![alt](http://example.com/img.png)");
+        let result = to_html("![alt](http://example.com/img.png)");
         assert!(result.contains("<img"));
         assert!(result.contains("src="));
     }
 
     #[test]
     fn test_autolink() {
-        let result = convert("Visit https://example.com for more.");
+        let result = to_html("Visit https://example.com for more.");
         assert!(result.contains("<a href="));
     }
 
     #[test]
     fn test_display_math() {
-        let result = convert("$$x^2 + y^2 = z^2$$");
+        let result = to_html("$$x^2 + y^2 = z^2$$");
         assert!(result.contains("math-display"));
         assert!(result.contains("<img"));
         assert!(
@@ -461,9 +1307,11 @@ mod tests {
 
     #[test]
     fn test_inline_math() {
-        let result = convert("The equation $E = mc^2$ is famous.");
+        let result = to_html("The equation $E = mc^2$ is famous.");
         assert!(result.contains("math-inline"));
         assert!(result.contains("<img"));
+        // Aligned on MathJax's baseline rather than centered
+        assert!(result.contains("vertical-align:-"));
         assert!(
             result.contains("data:image/png;base64")
                 || result.contains("data:image/svg+xml;base64")
@@ -472,22 +1320,141 @@ mod tests {
 
     #[test]
     fn test_math_does_not_match_double_dollar() {
-        let result = convert("$$x^2$$");
+        let result = to_html("$$x^2$$");
         assert!(result.contains("math-display"));
         assert!(!result.contains("math-inline"));
     }
 
     #[test]
     fn test_math_code_block() {
-        let result = convert("```math\nx^2 + y^2\n```");
+        let result = to_html("```math\nx^2 + y^2\n```");
         assert!(result.contains("math-display"));
         assert!(result.contains("<img"));
     }
 
+    #[test]
+    fn test_info_attributes() {
+        let attributes = info_attributes(r#"status team=infra title="On call" compact size='2'"#);
+        assert_eq!(attributes.len(), 4);
+        assert_eq!(attributes["team"], "infra");
+        assert_eq!(attributes["title"], "On call");
+        assert_eq!(attributes["compact"], "");
+        assert_eq!(attributes["size"], "2");
+        assert!(info_attributes("status").is_empty());
+    }
+
+    fn bracket(markdown: &str) -> String {
+        bracket_math(markdown, &options(&Extensions::default())).into_owned()
+    }
+
+    #[test]
+    fn test_bracket() {
+        assert_eq!(
+            bracket(r"where \(x^2\) and \(y\)5"),
+            r"where $`x^2`$ and $`y`$5"
+        );
+        assert_eq!(
+            bracket("Sum:\n\\[\n\\sum_i x_i\n\\]\nDone"),
+            "Sum:\n$$\n\\sum_i x_i\n$$\nDone"
+        );
+        assert_eq!(bracket(r"\(\text{`a`}\)"), r"$\text{`a`}$");
+        // Whatever Markdown makes of the LaTeX, and wherever the block is
+        assert_eq!(bracket(r"\(x^2\) or \(*y*^2\)"), r"$`x^2`$ or $`*y*^2`$");
+        assert_eq!(
+            bracket("---\ntitle: x\n---\n\n> - \\[\n>   a^2\n>   \\]\n\n| \\(b\\) |\n|-|\n"),
+            "---\ntitle: x\n---\n\n> - $$\n>   a^2\n>   $$\n\n| $`b`$ |\n|-|\n"
+        );
+
+        // Escaped brackets, code and unclosed delimiters are left alone
+        for markdown in [
+            r"array\[0\] and f\(x\)",
+            r"`\(x\)` and ``a ` \[y\]``",
+            "```\n\\(x\\)\n```\n",
+            r"\\(x\\)",
+            "\\(x\n\ny\\)",
+            r"\(\)",
+        ] {
+            assert_eq!(bracket(markdown), markdown);
+        }
+        assert_eq!(
+            bracket("~~~~\n\\(a\\)\n~~~\n~~~~\n\\(b\\)"),
+            "~~~~\n\\(a\\)\n~~~\n~~~~\n$`b`$"
+        );
+    }
+
+    #[test]
+    fn test_bracket_math_skips_indented_code() {
+        for markdown in ["    \\(x\\)\n", "Code:\n\n\t\\[x\\]\n\n    \\(y\\)\n"] {
+            assert_eq!(bracket(markdown), markdown);
+        }
+        // The code ends at the first line indented less
+        assert_eq!(bracket("    \\(x\\)\n\\(y\\)"), "    \\(x\\)\n$`y`$");
+        // Paragraph continuations and list items aren't code
+        assert_eq!(bracket("Text\n    \\(x\\)"), "Text\n    $`x`$");
+        assert_eq!(
+            bracket("- item\n\n  \\(x\\)\n\n1. \\(y\\)"),
+            "- item\n\n  $`x`$\n\n1. $`y`$"
+        );
+        // Unless indented 4 more columns than the item's content
+        assert_eq!(
+            bracket("- item\n\n      \\(x\\)\n"),
+            "- item\n\n      \\(x\\)\n"
+        );
+        assert_eq!(
+            bracket("- item\n\nText\n\n    \\(x\\)\n"),
+            "- item\n\nText\n\n    \\(x\\)\n"
+        );
+    }
+
+    #[test]
+    fn test_bracket_math_skips_html_blocks() {
+        for markdown in [
+            "<div>\n\\(x\\)\n</div>\n",
+            "<!-- \\(x\\)\n\n\\(y\\) -->\n",
+            "<pre>\na\n\n\\(x\\)\n</pre>\n",
+            "<custom-element>\n\\(x\\)\n",
+        ] {
+            assert_eq!(bracket(markdown), markdown);
+        }
+        // Blocks end at a blank line or at their closing tag
+        assert_eq!(
+            bracket("<div>\n\\(x\\)\n\n\\(y\\)"),
+            "<div>\n\\(x\\)\n\n$`y`$"
+        );
+        assert_eq!(bracket("<pre>a</pre>\n\\(y\\)"), "<pre>a</pre>\n$`y`$");
+        // Inline HTML is prose, as is a lone tag interrupting a paragraph
+        assert_eq!(bracket("<b>\\(x\\)</b>"), "<b>$`x`$</b>");
+        assert_eq!(bracket("Text\n<span>\n\\(x\\)"), "Text\n<span>\n$`x`$");
+    }
+
+    #[test]
+    fn test_bracket_math_rendered() {
+        let result = to_html(r"Inline \(E = mc^2\) and \[x^2\]");
+        assert!(result.contains("math-inline"));
+        assert!(result.contains("math-display"));
+        assert!(!result.contains("math-error"));
+
+        let mut profile = Profile::default();
+        profile.extensions.math = false;
+        let result = convert(r"\(x\)", &profile, None, &mut Report::default());
+        assert!(result.contains("<p>(x)</p>"));
+    }
+
+    #[test]
+    fn test_latex_fences_opt_in() {
+        let markdown = "```latex\nx^2\n```\n\n```tex\ny^2\n```";
+        assert!(!to_html(markdown).contains("math-display"));
+
+        let mut profile = Profile::default();
+        profile.extensions.latex_fences = true;
+        let result = convert(markdown, &profile, None, &mut Report::default());
+        assert_eq!(result.matches("math-display").count(), 2);
+    }
+
     #[test]
     fn test_math_split_environment() {
         // Test complex LaTeX that requires display mode
-        let result = convert(
+        let result = to_html(
             r#"$$
 \begin{split}
 p_n &= 1-\frac{1}{2^r} \\
@@ -503,7 +1470,7 @@ $$"#,
 
     #[test]
     fn test_mermaid_flowchart() {
-        let result = convert("```mermaid\ngraph LR\n    A --> B\n```");
+        let result = to_html("```mermaid\ngraph LR\n    A --> B\n```");
         assert!(result.contains("mermaid-diagram"));
         assert!(result.contains("<img"));
         assert!(result.contains("data:image/png;base64"));
@@ -511,14 +1478,14 @@ $$"#,
 
     #[test]
     fn test_mermaid_sequence_diagram() {
-        let result = convert("```mermaid\nsequenceDiagram\n    Alice->>Bob: Hello\n```");
+        let result = to_html("```mermaid\nsequenceDiagram\n    Alice->>Bob: Hello\n```");
         assert!(result.contains("mermaid-diagram"));
         assert!(result.contains("<img"));
     }
 
     #[test]
     fn test_mermaid_complex_flowchart() {
-        let result = convert(
+        let result = to_html(
             "```mermaid\ngraph TD\n    A[Start] --> B{Decision}\n    B -->|Yes| C[OK]\n    B -->|No| D[Cancel]\n```",
         );
         assert!(result.contains("mermaid-diagram"));
@@ -527,9 +1494,106 @@ $$"#,
         assert!(!result.contains("mermaid-error"));
     }
 
+    #[test]
+    fn test_plugin_blocks() {
+        let plugins = Plugins::from_scripts(vec![crate::plugins::Script {
+            name: "team.js".to_string(),
+            source: r#"
+                md2cb.register("status", (body, attrs) => `<p>${attrs.team}: ${body.trim()}</p>`);
+                md2cb.register("meter", () =>
+                    '<svg xmlns="http://www.w3.org/2000/svg" width="40" height="10"><rect width="40" height="10"/></svg>');
+                md2cb.register("oncall", () => { throw new Error("no rota"); });
+            "#
+            .to_string(),
+        }])
+        .unwrap();
+        let profile = Profile {
+            plugins,
+            ..Profile::default()
+        };
+        let mut report = Report::default();
+        let html = convert(
+            "```status team=infra\nall good\n```\n\n```meter\n```\n\n```oncall\nalice\n```",
+            &profile,
+            None,
+            &mut report,
+        );
+
+        assert!(html.contains(r#"<div class="plugin plugin-status"><p>infra: all good</p></div>"#));
+        assert!(html.contains(r#"<img src="data:image/png;base64,"#));
+        assert!(html.contains(r#"alt="meter" width="40" height="10""#));
+        assert!(html.contains(r#"<pre class="plugin-error"><code>alice</code></pre>"#));
+        assert_eq!(report.plugins.rendered, 2);
+        assert!(report.plugins.failures[0].error.contains("no rota"));
+
+        // Without plugins these are plain code blocks
+        assert!(!to_html("```status\nall good\n```").contains("plugin"));
+    }
+
+    #[test]
+    fn test_graphviz_diagrams() {
+        let mut report = Report::default();
+        let html = convert(
+            "```dot\ndigraph { a -> b [label=\"uses\"]; b -> c }\n```\n\n```graphviz\ngraph { x -- y }\n```",
+            &Profile::default(),
+            None,
+            &mut report,
+        );
+        assert_eq!(
+            html.matches(r#"<div class="graphviz-diagram"><img"#)
+                .count(),
+            2
+        );
+        assert!(html.contains(r#"alt="Graphviz diagram""#));
+        assert_eq!(report.graphviz.rendered, 2);
+
+        // Invalid and empty graphs fall back to their source, and so do
+        // graphs layout-rs panics on, such as a record without fields
+        let mut report = Report::default();
+        let html = convert(
+            "```dot\ndigraph { a -> }\n```\n\n```dot\ndigraph {}\n```\n\n\
+             ```dot\ndigraph { a [shape=record, label=\"\"] }\n```",
+            &Profile::default(),
+            None,
+            &mut report,
+        );
+        assert_eq!(html.matches(r#"<pre class="graphviz-error">"#).count(), 3);
+        assert_eq!(report.graphviz.failures.len(), 3);
+        assert!(
+            report.graphviz.failures[0]
+                .error
+                .starts_with("Graphviz parse error")
+        );
+        assert_eq!(
+            report.graphviz.failures[1].error,
+            "Graphviz rendering error: the graph has no nodes"
+        );
+        assert!(
+            report.graphviz.failures[2]
+                .error
+                .starts_with("Graphviz layout error: index out of bounds"),
+            "{:?}",
+            report.graphviz.failures[2]
+        );
+    }
+
+    #[test]
+    fn test_csv_blocks_as_tables() {
+        let html =
+            to_html("```csv\nname,qty\nbolt,12\n```\n\n| name | qty |\n|---|--:|\n| bolt | 12 |");
+        // Styled like the GFM table right after it
+        let tables: Vec<_> = html.split("<table").skip(1).collect();
+        assert_eq!(tables.len(), 2);
+        assert_eq!(tables[0], tables[1]);
+
+        assert!(to_html("```tsv header=false\na\tb\n```").contains("<td>a</td>"));
+        // Malformed CSV stays a code block
+        assert!(to_html("```csv\na,\"b\n```").contains("<pre"));
+    }
+
     #[test]
     fn test_code_block_newlines_converted_to_br() {
-        let result = convert("```rust\nfn main() {\n    println!(\"Hello\");\n}\n```");
+        let result = to_html("```rust\nfn main() {\n    println!(\"Hello\");\n}\n```");
         assert!(result.contains("<pre"));
         assert!(result.contains("<code"));
         // Should have <br> tags instead of plain newlines inside code block
@@ -541,7 +1605,7 @@ $$"#,
 
     #[test]
     fn test_code_block_multiline_preserves_structure() {
-        let result = convert("```python\ndef hello():\n    print('world')\n\nhello()\n```");
+        let result = to_html("```python\ndef hello():\n    print('world')\n\nhello()\n```");
         // Count <br> occurrences - should have at least 3 (one per newline in code)
         let br_count = result.matches("<br>").count();
         assert!(
@@ -552,7 +1616,7 @@ $$"#,
 
     #[test]
     fn test_code_block_no_trailing_br() {
-        let result = convert("```rust\nfn main() {}\n```");
+        let result = to_html("```rust\nfn main() {}\n```");
         // Should NOT have trailing <br> before </span></code>
         assert!(
             !result.contains("<br></span></code>"),
@@ -586,9 +1650,29 @@ $$"#,
         assert_eq!(result, "<ul><li>hello world</li></ul>");
     }
 
+    #[test]
+    fn test_normalize_whitespace_skips_preformatted() {
+        let html = "<div>\n<PRE class=\"x\">a\n  b</PRE>\n<p>c\nd</p><textarea>e\n\nf</textarea> <code>g\nh";
+        assert_eq!(
+            normalize_whitespace(html),
+            "<div><PRE class=\"x\">a\n  b</PRE><p>c d</p><textarea>e\n\nf</textarea> <code>g\nh"
+        );
+        // Tags merely starting like one
+        assert_eq!(
+            normalize_whitespace("<codex>a\nb</codex>"),
+            "<codex>a b</codex>"
+        );
+    }
+
+    #[test]
+    fn test_raw_pre_block_keeps_newlines() {
+        let result = to_html("Text\n\n<pre>line1\nline2</pre>\n\nMore");
+        assert!(result.contains("<pre>line1\nline2</pre>"), "{result}");
+    }
+
     #[test]
     fn test_list_no_extra_whitespace_between_items() {
-        let result = convert("- item1\n- item2\n- item3");
+        let result = to_html("- item1\n- item2\n- item3");
         // Should not have whitespace between </li> and <li>
         assert!(!result.contains("</li> <li>"));
         assert!(!result.contains("</li>\n<li>"));
@@ -598,7 +1682,7 @@ $$"#,
 
     #[test]
     fn test_nested_list_no_extra_whitespace() {
-        let result = convert("- parent\n  - child1\n  - child2");
+        let result = to_html("- parent\n  - child1\n  - child2");
         // Should not have whitespace between list tags
         assert!(!result.contains("</li> <li>"));
         assert!(!result.contains("<ul> <li>"));
@@ -608,14 +1692,261 @@ $$"#,
     #[test]
     fn test_inline_html_spacing_preserved() {
         // HTML tags split across lines should preserve spacing
-        let result = convert("- <u>underline</u>, <span>orange</span>,\n  <strong>strong</strong>");
+        let result = to_html("- <u>underline</u>, <span>orange</span>,\n  <strong>strong</strong>");
         // Should have space before <strong> (from the newline in content)
         assert!(result.contains("</span>, <strong>"));
     }
 
+    #[test]
+    fn test_space_between_inline_tags_preserved() {
+        let result = to_html("*one* **two**\nthree");
+        assert!(result.contains("<em>one</em> <strong>two</strong> three"));
+    }
+
+    #[test]
+    fn test_math_source_is_not_html_escaped_twice() {
+        let result = convert(
+            "$a < b \\& c$",
+            &Target::Slack.profile(),
+            None,
+            &mut Report::default(),
+        );
+        assert!(result.contains("<code>$a &lt; b \\&amp; c$</code>"));
+    }
+
+    #[test]
+    fn test_math_error_recorded_in_report() {
+        let mut report = Report::default();
+        let result = convert(
+            "$y$ and broken $x^$.",
+            &Profile::default(),
+            None,
+            &mut report,
+        );
+        assert!(result.contains("math-error"));
+        assert_eq!(report.math.rendered, 1);
+        assert_eq!(report.math.failures.len(), 1);
+        assert_eq!(report.math.failures[0].source, "x^");
+    }
+
+    #[test]
+    fn test_failures_record_source_position() {
+        let mut report = Report::default();
+        let markdown = "# Title\n\nSome $y$ and $x^$.\n\n> ```dot\n> digraph {\n> ```\n";
+        convert(markdown, &Profile::default(), None, &mut report);
+        let math = &report.math.failures[0];
+        assert_eq!((math.line, math.column), (3, 14));
+        let graphviz = &report.graphviz.failures[0];
+        assert_eq!((graphviz.line, graphviz.column), (5, 3));
+    }
+
+    #[test]
+    fn test_math_rendered_from_cache() {
+        let dir = std::env::temp_dir().join(format!("md2cb-parser-cache-{}", std::process::id()));
+        let cache = Cache::new(&dir, crate::cache::DEFAULT_MAX_BYTES);
+        let key = math_cache_key("x", false, MathStyle::Png, 1.0, "");
+        cache
+            .get_or_render(&key, || Ok("<b>cached</b>".to_string()))
+            .unwrap();
+
+        let mut report = Report::default();
+        let result = convert("$x$", &Profile::default(), Some(&cache), &mut report);
+        assert!(result.contains(r#"<span class="math math-inline"><b>cached</b></span>"#));
+        assert_eq!(report.math.rendered, 1);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_document_macros() {
+        // KaTeX fails on undefined macros, where MathJax renders their name
+        let mut profile = Profile {
+            math: MathStyle::Katex,
+            ..Profile::default()
+        };
+        profile
+            .macros
+            .insert("RR".to_string(), r"\mathbb{R}".to_string());
+        profile
+            .macros
+            .insert("vv".to_string(), r"\undefined".to_string());
+        let markdown = "---\nmacros:\n  vv: \\vec{#1}\n---\n\n\
+                        $$\\newcommand{\\norm}[1]{\\lVert #1 \\rVert}$$\n\n\
+                        Let $\\vv{x} \\in \\RR$ with $\\norm{x} = 1$.";
+        let mut report = Report::default();
+        let result = convert(markdown, &profile, None, &mut report);
+
+        assert!(result.starts_with("<p>Let "), "{result}");
+        assert!(!result.contains("macros:"));
+        assert_eq!(report.math.rendered, 2);
+        assert!(report.math.failures.is_empty());
+
+        // Without the definitions, the same expressions fail
+        let mut report = Report::default();
+        profile.macros.clear();
+        convert("$\\norm{x}$", &profile, None, &mut report);
+        assert_eq!(report.math.failures.len(), 1);
+    }
+
+    #[test]
+    fn test_definitions_in_expressions_apply_in_order() {
+        let profile = Profile {
+            math: MathStyle::Katex,
+            ..Profile::default()
+        };
+        // KaTeX has its own `\R`, which `\newcommand` alone would refuse to
+        // replace. Definitions apply from where they are on, whichever worker
+        // renders each expression.
+        let markdown = "$\\RR$, $\\newcommand{\\R}{\\mathbf{R}}\\R^n$ and $\\R$.\n\n\
+                        $$\\renewcommand{\\R}{\\mathcal{R}}$$\n\n\
+                        $\\R$ and $\\newcommand{\\RR}{x}\\RR$.\n\n\
+                        $\\def\\one{1}$";
+        let mut report = Report::default();
+        let result = convert(markdown, &profile, None, &mut report);
+        assert_eq!(report.math.rendered, 4, "{:?}", report.math.failures);
+        // Used before its definition
+        assert_eq!(report.math.failures.len(), 1);
+        assert_eq!(report.math.failures[0].source, r"\RR");
+        assert!(!result.contains("newcommand"));
+
+        let paragraphs: Vec<_> = result.split("</p>").collect();
+        assert_eq!(paragraphs[0].matches("mathbf").count(), 2, "{result}");
+        assert!(!paragraphs[0].contains("mathcal"));
+        assert!(paragraphs[1].contains("mathcal"));
+        assert!(!paragraphs[1].contains("mathbf"));
+        // The definition alone renders to nothing
+        assert_eq!(paragraphs.len(), 3, "{result}");
+    }
+
+    #[test]
+    fn test_equation_numbers_and_refs() {
+        let profile = Profile {
+            math: MathStyle::Source,
+            numbering: EquationNumbering::Ams,
+            ..Profile::default()
+        };
+        let markdown = "By \\eqref{eq:b}, $x = \\ref{eq:a}$.\n\n\
+                        $$\\begin{equation} a \\label{eq:a} \\end{equation}$$\n\n\
+                        $$c$$\n\n\
+                        $$\\begin{align} b \\label{eq:b} \\end{align}$$\n\n\
+                        See $\\eqref{eq:a}$ and \\eqref{nope}.";
+        let mut report = Report::default();
+        let result = convert(markdown, &profile, None, &mut report);
+
+        assert!(
+            result.contains(r##"By <a href="#eq-eq:b">(2)</a>, "##),
+            "{result}"
+        );
+        assert!(result.contains(r"<code>$x = \text{1}$</code>"));
+        assert!(result.contains(concat!(
+            r#"<div class="math math-display" id="eq-eq:a">"#,
+            r#"<span class="math-tag" style="float:right;">(1)</span>"#,
+            r"<code>$$\begin{equation} a  \end{equation}$$</code></div>"
+        )));
+        assert!(result.contains(r#"<div class="math math-display"><code>$$c$$"#));
+        assert!(
+            result.contains(r##"See <a href="#eq-eq:a">(1)</a> and <a href="#eq-nope">(??)</a>."##)
+        );
+        assert_eq!(report.math.failures.len(), 1);
+        assert_eq!(report.math.failures[0].source, r"\eqref{nope}");
+
+        // Each row of an align is numbered and linked on its own
+        let markdown = "$$\\begin{align} a \\label{x} \\\\ b \\nonumber \\\\ c \\label{y} \\end{align}$$\n\n\
+                        \\eqref{y}";
+        let result = convert(markdown, &profile, None, &mut Report::default());
+        assert!(result.contains(concat!(
+            r#"<div class="math math-display"><span class="math-tag" style="float:right;">"#,
+            r#"<span id="eq-x">(1)</span><br>&nbsp;<br><span id="eq-y">(2)</span></span>"#,
+        )));
+        assert!(result.contains(r##"<a href="#eq-y">(2)</a>"##), "{result}");
+    }
+
+    #[test]
+    fn test_word_profile_keeps_preformatted_code() {
+        let profile = Target::Word.profile();
+        let result = convert(
+            "```python\na = 1\nb = 2\n```",
+            &profile,
+            None,
+            &mut Report::default(),
+        );
+        assert!(!result.contains("<br>"));
+        assert!(result.contains('\n'));
+    }
+
+    #[test]
+    fn test_word_profile_ballot_box_checkboxes() {
+        let result = convert(
+            "- [ ] todo\n- [x] done",
+            &Target::Word.profile(),
+            None,
+            &mut Report::default(),
+        );
+        assert!(result.contains("☐"));
+        assert!(result.contains("☑"));
+        assert!(!result.contains("✅"));
+    }
+
+    #[test]
+    fn test_slack_profile_math_as_source() {
+        let result = convert(
+            "Inline $a < b$ here.",
+            &Target::Slack.profile(),
+            None,
+            &mut Report::default(),
+        );
+        assert!(result.contains("<code>$a &lt; b$</code>"));
+        assert!(!result.contains("<img"));
+    }
+
+    #[test]
+    fn test_word_profile_math_as_mathml() {
+        let mut report = Report::default();
+        let result = convert(
+            "Inline $a < b$ and $$x^2$$ here.",
+            &Target::Word.profile(),
+            None,
+            &mut report,
+        );
+        assert!(result.contains(r#"<span class="math math-inline"><math xmlns="#));
+        assert!(result.contains(r#"alttext="a &lt; b""#));
+        assert!(result.contains(r#"display="block""#));
+        assert!(!result.contains("<img"));
+        assert_eq!(report.math.rendered, 2);
+    }
+
+    #[test]
+    fn test_katex_math_as_html() {
+        let profile = Profile {
+            math: MathStyle::Katex,
+            ..Profile::default()
+        };
+        let mut report = Report::default();
+        let result = convert("Inline $a < b$ and $x^$.", &profile, None, &mut report);
+        assert!(result.contains(r#"<span class="math math-inline"><span class="katex">"#));
+        assert!(!result.contains("<img"));
+        assert_eq!(report.math.rendered, 1);
+        assert!(
+            report.math.failures[0]
+                .error
+                .starts_with("KaTeX parse error")
+        );
+    }
+
+    #[test]
+    fn test_slack_profile_skips_pre_style() {
+        let result = convert(
+            "```rust\nfn main() {}\n```",
+            &Target::Slack.profile(),
+            None,
+            &mut Report::default(),
+        );
+        assert!(!result.contains("background-color:#f6f8fa"));
+    }
+
     #[test]
     fn test_paragraph_structure_preserved() {
-        let result = convert("First paragraph.\n\nSecond paragraph.");
+        let result = to_html("First paragraph.\n\nSecond paragraph.");
         // Should have separate <p> tags (semantic structure preserved)
         assert!(result.contains("<p>First paragraph.</p>"));
         assert!(result.contains("<p>Second paragraph.</p>"));
@@ -0,0 +1,289 @@
+//! Plain-text rendering of Markdown, used as the text/plain clipboard flavor.
+//!
+//! Walks the same comrak AST that `parser::convert` renders to HTML, so apps
+//! that reject rich content (terminals, plain textareas) still get readable text.
+
+use comrak::Arena;
+use comrak::nodes::{AstNode, ListDelimType, ListType, NodeList, NodeValue, TableAlignment};
+use regex::Regex;
+use std::sync::LazyLock;
+
+use crate::parser::{self, Extensions};
+
+static HTML_TAG_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^>]*>").unwrap());
+
+/// Converts GitHub Flavored Markdown to readable plain text.
+pub fn convert(markdown: &str, extensions: &Extensions) -> String {
+    let arena = Arena::new();
+    let root = parser::parse(&arena, markdown, extensions);
+    let mut text = render_blocks(root, "\n\n");
+    text.push('\n');
+    text
+}
+
+/// Renders the block children of a node, separated by `separator`.
+fn render_blocks<'a>(node: &'a AstNode<'a>, separator: &str) -> String {
+    node.children()
+        .map(render_block)
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+fn render_block<'a>(node: &'a AstNode<'a>) -> String {
+    match &node.data.borrow().value {
+        NodeValue::Paragraph | NodeValue::Heading(_) | NodeValue::DescriptionTerm => {
+            render_inlines(node)
+        }
+        // Code blocks (including ```math and ```mermaid) are kept verbatim
+        NodeValue::CodeBlock(block) => block.literal.trim_end_matches('\n').to_string(),
+        NodeValue::BlockQuote | NodeValue::MultilineBlockQuote(_) => {
+            prefix_lines(&render_blocks(node, "\n\n"), "> ", "> ")
+        }
+        NodeValue::List(list) => render_list(node, list),
+        NodeValue::Table(table) => render_table(node, &table.alignments),
+        NodeValue::ThematicBreak => "---".to_string(),
+        NodeValue::HtmlBlock(block) => HTML_TAG_RE
+            .replace_all(&block.literal, "")
+            .trim()
+            .to_string(),
+        NodeValue::FootnoteDefinition(def) => {
+            format!("[^{}]: {}", def.name, render_blocks(node, "\n\n"))
+        }
+        NodeValue::DescriptionDetails => prefix_lines(&render_blocks(node, "\n\n"), "    ", "    "),
+        NodeValue::DescriptionList | NodeValue::DescriptionItem(_) => render_blocks(node, "\n"),
+        NodeValue::FrontMatter(_) => String::new(),
+        _ => render_blocks(node, "\n\n"),
+    }
+}
+
+/// Renders a list with `-` bullets or `1.` numbers, indenting nested content
+/// under the marker.
+fn render_list<'a>(node: &'a AstNode<'a>, list: &NodeList) -> String {
+    let separator = if list.tight { "\n" } else { "\n\n" };
+    node.children()
+        .enumerate()
+        .map(|(i, item)| {
+            let mut marker = match list.list_type {
+                ListType::Bullet => "- ".to_string(),
+                ListType::Ordered => {
+                    let delim = match list.delimiter {
+                        ListDelimType::Period => '.',
+                        ListDelimType::Paren => ')',
+                    };
+                    format!("{}{delim} ", list.start + i)
+                }
+            };
+            let indent = " ".repeat(marker.len());
+            if let NodeValue::TaskItem(checked) = item.data.borrow().value {
+                marker.push_str(if checked.is_some() { "[x] " } else { "[ ] " });
+            }
+            prefix_lines(&render_blocks(item, separator), &marker, &indent)
+        })
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Renders a table with space-padded columns and a dashed rule under the header.
+fn render_table<'a>(node: &'a AstNode<'a>, alignments: &[TableAlignment]) -> String {
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut has_header = false;
+    for row in node.children() {
+        if let NodeValue::TableRow(header) = row.data.borrow().value {
+            has_header |= header;
+        }
+        rows.push(row.children().map(render_inlines).collect());
+    }
+
+    let mut widths = vec![0; alignments.len()];
+    for row in &rows {
+        for (i, cell) in row.iter().enumerate() {
+            if i < widths.len() {
+                widths[i] = widths[i].max(cell.chars().count());
+            }
+        }
+    }
+
+    let mut lines = Vec::new();
+    for (row_index, row) in rows.iter().enumerate() {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(alignments.iter().zip(&widths))
+            .map(|(cell, (align, &width))| pad_cell(cell, *align, width))
+            .collect();
+        lines.push(cells.join("  ").trim_end().to_string());
+        if row_index == 0 && has_header {
+            let rule: Vec<String> = widths.iter().map(|&w| "-".repeat(w)).collect();
+            lines.push(rule.join("  "));
+        }
+    }
+    lines.join("\n")
+}
+
+fn pad_cell(cell: &str, align: TableAlignment, width: usize) -> String {
+    let padding = width.saturating_sub(cell.chars().count());
+    match align {
+        TableAlignment::Right => format!("{}{cell}", " ".repeat(padding)),
+        TableAlignment::Center => {
+            let left = padding / 2;
+            format!("{}{cell}{}", " ".repeat(left), " ".repeat(padding - left))
+        }
+        TableAlignment::Left | TableAlignment::None => format!("{cell}{}", " ".repeat(padding)),
+    }
+}
+
+/// Prefixes the first line with `first` and every following non-empty line with `rest`.
+fn prefix_lines(text: &str, first: &str, rest: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for (i, line) in text.split('\n').enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        if i == 0 {
+            out.push_str(first);
+        } else if !line.is_empty() {
+            out.push_str(rest);
+        } else {
+            // Keep blank lines inside blockquotes quoted
+            out.push_str(rest.trim_end());
+        }
+        out.push_str(line);
+    }
+    out
+}
+
+/// Renders the inline children of a node into a single string.
+fn render_inlines<'a>(node: &'a AstNode<'a>) -> String {
+    let mut out = String::new();
+    for child in node.children() {
+        render_inline(child, &mut out);
+    }
+    out
+}
+
+fn render_inline<'a>(node: &'a AstNode<'a>, out: &mut String) {
+    match &node.data.borrow().value {
+        NodeValue::Text(text) => out.push_str(text),
+        NodeValue::Code(code) => out.push_str(&code.literal),
+        NodeValue::SoftBreak | NodeValue::LineBreak => out.push('\n'),
+        NodeValue::Math(math) => {
+            let delim = if math.display_math { "$$" } else { "$" };
+            out.push_str(delim);
+            out.push_str(&math.literal);
+            out.push_str(delim);
+        }
+        NodeValue::Link(link) => {
+            let text = render_inlines(node);
+            let url = link.url.as_str();
+            // Autolinks already show their target
+            if text.is_empty() {
+                out.push_str(url);
+            } else if text == url || url.strip_prefix("mailto:") == Some(text.as_str()) {
+                out.push_str(&text);
+            } else {
+                out.push_str(&format!("{text} ({url})"));
+            }
+        }
+        // Images fall back to their alt text
+        NodeValue::Image(_) => out.push_str(&render_inlines(node)),
+        NodeValue::FootnoteReference(reference) => {
+            out.push_str(&format!("[^{}]", reference.name));
+        }
+        NodeValue::HtmlInline(_) => {}
+        _ => {
+            for child in node.children() {
+                render_inline(child, out);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(markdown: &str) -> String {
+        super::convert(markdown, &Extensions::default())
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        assert_eq!(
+            convert("Hello **bold** and *em*.\n\nSecond."),
+            "Hello bold and em.\n\nSecond.\n"
+        );
+    }
+
+    #[test]
+    fn test_heading() {
+        assert_eq!(convert("# Title\n\nBody"), "Title\n\nBody\n");
+    }
+
+    #[test]
+    fn test_bullet_list() {
+        assert_eq!(
+            convert("- one\n- two\n  - nested"),
+            "- one\n- two\n  - nested\n"
+        );
+    }
+
+    #[test]
+    fn test_ordered_list() {
+        assert_eq!(convert("3. three\n4. four"), "3. three\n4. four\n");
+    }
+
+    #[test]
+    fn test_task_list() {
+        assert_eq!(
+            convert("- [ ] todo\n- [x] done"),
+            "- [ ] todo\n- [x] done\n"
+        );
+    }
+
+    #[test]
+    fn test_table_columns_aligned() {
+        let result = convert("| Name | Qty |\n|------|----:|\n| apple | 3 |\n| kiwi | 12 |");
+        assert_eq!(result, "Name   Qty\n-----  ---\napple    3\nkiwi    12\n");
+    }
+
+    #[test]
+    fn test_code_block_verbatim() {
+        let result = convert("```rust\nfn main() {\n    println!(\"hi\");\n}\n```");
+        assert_eq!(result, "fn main() {\n    println!(\"hi\");\n}\n");
+    }
+
+    #[test]
+    fn test_math_as_latex() {
+        assert_eq!(convert("Energy $E = mc^2$."), "Energy $E = mc^2$.\n");
+        assert_eq!(convert("$$x^2$$"), "$$x^2$$\n");
+        assert_eq!(convert("```math\nx^2\n```"), "x^2\n");
+        assert_eq!(convert(r"Energy \(E = mc^2\)."), "Energy $E = mc^2$.\n");
+    }
+
+    #[test]
+    fn test_links() {
+        assert_eq!(
+            convert("[docs](https://example.com)"),
+            "docs (https://example.com)\n"
+        );
+        assert_eq!(
+            convert("Visit https://example.com now"),
+            "Visit https://example.com now\n"
+        );
+    }
+
+    #[test]
+    fn test_image_alt_text() {
+        assert_eq!(convert("![a cat](cat.png)"), "a cat\n");
+    }
+
+    #[test]
+    fn test_blockquote() {
+        assert_eq!(convert("> quoted\n>\n> more"), "> quoted\n>\n> more\n");
+    }
+
+    #[test]
+    fn test_inline_html_dropped() {
+        assert_eq!(convert("a <u>b</u> c"), "a b c\n");
+    }
+}
@@ -0,0 +1,161 @@
+//! User plugins rendering custom fenced blocks.
+//!
+//! A plugin is a JavaScript file in the `plugins` directory next to the user
+//! config (see [`settings::plugins_dir`](crate::settings::plugins_dir)) that
+//! registers renderers for info-string languages:
+//!
+//! ```js
+//! md2cb.register("status", (body, attributes) =>
+//!     `<p><b>${attributes.team ?? "status"}</b>: ${body.trim()}</p>`);
+//! ```
+//!
+//! A renderer gets the content of a block and the `key=value` attributes
+//! following the language in its info string, such as
+//! ```` ```status team=infra ````, and returns HTML. SVG is rendered to a PNG
+//! image like Mermaid diagrams.
+//!
+//! Plugins run in a QuickJS runtime of their own, apart from the math
+//! libraries, with the same memory limit and time budget. They only get the
+//! language itself: there is no filesystem, network or module loading.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::js_runtime;
+
+/// Source of one plugin file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Script {
+    /// File name, used in error messages.
+    pub name: String,
+    pub source: String,
+}
+
+/// Loaded plugins and the languages they render.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Plugins {
+    scripts: Arc<[Script]>,
+    languages: Vec<String>,
+}
+
+impl Plugins {
+    /// Loads every `.js` file in `dir`, in name order. A missing directory
+    /// has no plugins.
+    pub fn load(dir: &Path) -> Result<Self, String> {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return Ok(Self::default());
+        };
+        let mut paths: Vec<_> = entries
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "js"))
+            .collect();
+        paths.sort();
+
+        let scripts = paths
+            .iter()
+            .map(|path| {
+                let source = fs::read_to_string(path)
+                    .map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+                let name = path.file_name().unwrap_or_default().to_string_lossy();
+                Ok(Script {
+                    name: name.into_owned(),
+                    source,
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        Self::from_scripts(scripts)
+    }
+
+    /// Evaluates `scripts` to find out which languages they render.
+    pub fn from_scripts(scripts: Vec<Script>) -> Result<Self, String> {
+        if scripts.is_empty() {
+            return Ok(Self::default());
+        }
+        let scripts: Arc<[Script]> = scripts.into();
+        let languages = js_runtime::plugin_languages(&scripts)?;
+        Ok(Self { scripts, languages })
+    }
+
+    /// Whether a plugin renders blocks of `language`.
+    pub fn renders(&self, language: &str) -> bool {
+        self.languages.iter().any(|l| l == language)
+    }
+
+    pub fn languages(&self) -> &[String] {
+        &self.languages
+    }
+
+    /// Renders a block with the plugin registered for `language`, returning
+    /// its HTML or SVG.
+    pub fn render(
+        &self,
+        language: &str,
+        body: &str,
+        attributes: &BTreeMap<String, String>,
+    ) -> Result<String, String> {
+        js_runtime::render_plugin(&self.scripts, language, body, attributes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn script(name: &str, source: &str) -> Script {
+        Script {
+            name: name.to_string(),
+            source: source.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_plugins_render_blocks() {
+        let plugins = Plugins::from_scripts(vec![
+            script(
+                "status.js",
+                "md2cb.register('status', (body, attrs) => `<b>${attrs.team}</b> ${body.trim()}`);",
+            ),
+            script(
+                "sandbox.js",
+                "md2cb.register('sandbox', () => [typeof require, typeof fetch, typeof std, typeof os].join());",
+            ),
+        ])
+        .unwrap();
+        assert_eq!(plugins.languages(), ["status", "sandbox"]);
+        assert!(plugins.renders("status"));
+        assert!(!plugins.renders("mermaid"));
+
+        let attributes = BTreeMap::from([("team".to_string(), "infra".to_string())]);
+        assert_eq!(
+            plugins.render("status", "all good\n", &attributes).unwrap(),
+            "<b>infra</b> all good"
+        );
+        assert_eq!(
+            plugins.render("sandbox", "", &BTreeMap::new()).unwrap(),
+            "undefined,undefined,undefined,undefined"
+        );
+    }
+
+    #[test]
+    fn test_plugin_errors() {
+        let err = Plugins::from_scripts(vec![script("broken.js", "md2cb.register(")]).unwrap_err();
+        assert!(err.starts_with("broken.js: "), "{err}");
+
+        let plugins = Plugins::from_scripts(vec![script(
+            "throws.js",
+            "md2cb.register('boom', () => { throw new Error('no data'); });",
+        )])
+        .unwrap();
+        let err = plugins.render("boom", "", &BTreeMap::new()).unwrap_err();
+        assert!(
+            err.starts_with("throws.js: ") && err.contains("no data"),
+            "{err}"
+        );
+
+        // Loading nothing from a missing directory
+        let plugins = Plugins::load(Path::new("/nonexistent/md2cb/plugins")).unwrap();
+        assert!(plugins.languages().is_empty());
+    }
+}
@@ -0,0 +1,189 @@
+//! Bounded worker pool for rendering math, diagrams and images concurrently.
+//!
+//! Workers are started on first use and live for the rest of the process.
+//! Each keeps its own MathJax runtime in thread-local storage (see
+//! `js_runtime`), so a runtime is initialized at most once per worker, also
+//! across `--watch` rebuilds, unless a conversion times out. A new document
+//! preamble replaces the macros without reloading the library.
+
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread;
+
+/// Upper bound on workers; each one may hold a MathJax runtime in memory.
+const MAX_WORKERS: usize = 4;
+
+/// Stack size of a worker. MathJax recurses deeply on complex expressions and
+/// QuickJS is allowed 8 MB of it, well past the default 2 MB thread stack.
+const WORKER_STACK_SIZE: usize = 16 * 1024 * 1024;
+
+type Task = Box<dyn FnOnce() + Send>;
+
+static POOL: OnceLock<Sender<Task>> = OnceLock::new();
+
+// Panicking jobs are caught and reported, which aborting would defeat
+#[cfg(not(panic = "unwind"))]
+compile_error!("md2cb must be built with panic = \"unwind\"");
+
+thread_local! {
+    /// Whether panics on this thread are caught by [`catch_silently`], to be
+    /// kept off stderr by [`silence_caught_panics`]
+    static SILENCED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs every job on the pool and returns their results in the order given.
+///
+/// A job that panics yields an error instead of taking the pool down.
+pub fn run_all<T, F>(jobs: Vec<F>) -> Vec<Result<T, String>>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    if jobs.is_empty() {
+        return Vec::new();
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    let count = jobs.len();
+    for (index, job) in jobs.into_iter().enumerate() {
+        let sender = sender.clone();
+        execute(Box::new(move || {
+            let _ = sender.send((index, job()));
+        }));
+    }
+    drop(sender);
+
+    let mut results: Vec<Result<T, String>> = (0..count)
+        .map(|_| Err("render worker panicked".to_string()))
+        .collect();
+    // Ends once every job has run (or panicked) and dropped its sender
+    for (index, result) in receiver {
+        results[index] = Ok(result);
+    }
+    results
+}
+
+/// Queues `task` on the pool, or runs it right away if no worker is running.
+fn execute(task: Task) {
+    let sender = POOL.get_or_init(start_workers);
+    if let Err(mpsc::SendError(task)) = sender.send(task) {
+        task();
+    }
+}
+
+fn start_workers() -> Sender<Task> {
+    let (sender, receiver) = mpsc::channel::<Task>();
+    let receiver = Arc::new(Mutex::new(receiver));
+    let workers = thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(MAX_WORKERS);
+    for index in 0..workers {
+        let receiver = Arc::clone(&receiver);
+        // Without any worker the receiver is dropped and tasks run inline
+        let _ = thread::Builder::new()
+            .name(format!("md2cb-render-{index}"))
+            .stack_size(WORKER_STACK_SIZE)
+            .spawn(move || work(&receiver));
+    }
+    sender
+}
+
+/// Runs `f`, turning a panic into an error with its message. Meant for
+/// libraries that panic on bad input.
+///
+/// Tasks may run inline on the calling thread when the pool has no worker,
+/// so this doesn't rely on the pool catching panics.
+pub fn catch_silently<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    let silenced = SILENCED.with(|silenced| silenced.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    SILENCED.with(|s| s.set(silenced));
+    result.map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "panicked".to_string())
+    })
+}
+
+/// Wraps the panic hook so that it skips panics caught by
+/// [`catch_silently`]. Those of other code are reported as usual.
+///
+/// The hook is process-wide, so this is left to the application to call once.
+pub fn silence_caught_panics() {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if !SILENCED.with(Cell::get) {
+            hook(info);
+        }
+    }));
+}
+
+/// Runs tasks until the pool's sender goes away.
+fn work(receiver: &Mutex<Receiver<Task>>) {
+    loop {
+        let task = match receiver.lock() {
+            Ok(receiver) => receiver.recv(),
+            Err(_) => return,
+        };
+        let Ok(task) = task else {
+            return;
+        };
+        let _ = panic::catch_unwind(AssertUnwindSafe(task));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_results_in_job_order() {
+        let jobs: Vec<_> = (0..20u64)
+            .map(|i| {
+                move || {
+                    // Finish out of order
+                    thread::sleep(std::time::Duration::from_millis(20 - i));
+                    i * 2
+                }
+            })
+            .collect();
+        let results: Vec<_> = run_all(jobs).into_iter().map(Result::unwrap).collect();
+        assert_eq!(results, (0..20).map(|i| i * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_panicking_job_reports_error() {
+        let jobs: Vec<Box<dyn FnOnce() -> u8 + Send>> =
+            vec![Box::new(|| 1), Box::new(|| panic!("boom")), Box::new(|| 3)];
+        let results = run_all(jobs);
+        assert_eq!(results[0], Ok(1));
+        assert!(results[1].is_err());
+        assert_eq!(results[2], Ok(3));
+    }
+
+    #[test]
+    fn test_catch_silently() {
+        assert_eq!(catch_silently(|| 1), Ok(1));
+        assert_eq!(
+            catch_silently(|| panic!("bad {}", "graph")),
+            Err::<(), _>("bad graph".to_string())
+        );
+        assert_eq!(
+            catch_silently(|| panic!("boom")),
+            Err::<(), _>("boom".to_string())
+        );
+        assert!(!SILENCED.with(Cell::get));
+    }
+
+    #[test]
+    fn test_release_profile_unwinds() {
+        // Tests always unwind, whatever the profile of the shipped binary
+        let manifest: toml::Table = include_str!("../Cargo.toml").parse().unwrap();
+        let panic = manifest["profile"]["release"].get("panic");
+        assert!(panic.is_none_or(|panic| panic.as_str() == Some("unwind")));
+    }
+}
@@ -0,0 +1,200 @@
+//! Conversion report, collected while the pipeline runs.
+//!
+//! Each stage records what it rendered and what fell back to source, so the
+//! CLI can print warnings, fail in `--strict` mode, or emit the whole thing
+//! as JSON with `--report json`.
+
+use comrak::nodes::LineColumn;
+use serde::Serialize;
+use std::str::FromStr;
+
+/// Output format for `--report`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Json,
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(ReportFormat::Json),
+            _ => Err(format!("unknown report format '{s}' (expected: json)")),
+        }
+    }
+}
+
+/// What happened during one conversion.
+#[derive(Debug, Default, Serialize)]
+pub struct Report {
+    /// Math expressions, rendered in the `parser` stage.
+    pub math: StageItems,
+    /// Mermaid diagrams, rendered in the `parser` stage.
+    pub mermaid: StageItems,
+    /// Graphviz diagrams, rendered in the `parser` stage.
+    pub graphviz: StageItems,
+    /// Fenced blocks rendered by user plugins, in the `parser` stage.
+    pub plugins: StageItems,
+    /// Images seen by the `images` stage, once per distinct `src`.
+    pub images: Vec<ImageReport>,
+    /// Size of the final HTML in bytes.
+    pub payload_bytes: usize,
+    pub timings_ms: Timings,
+}
+
+/// Rendered count and failures for one kind of generated content.
+#[derive(Debug, Default, Serialize)]
+pub struct StageItems {
+    pub rendered: usize,
+    pub failures: Vec<Failure>,
+}
+
+/// Content that couldn't be rendered and was left as source.
+#[derive(Debug, Serialize)]
+pub struct Failure {
+    pub source: String,
+    /// Where the content starts in the Markdown, counting from 1.
+    pub line: usize,
+    pub column: usize,
+    pub error: String,
+}
+
+/// One image referenced by the document.
+#[derive(Debug, Default, Serialize)]
+pub struct ImageReport {
+    /// `src` as written in the document.
+    pub src: String,
+    /// URL or file path the image was read from.
+    pub resolved: Option<String>,
+    pub mime: Option<String>,
+    pub bytes: Option<usize>,
+    /// Whether the image was inlined as a data URI.
+    pub inlined: bool,
+    pub error: Option<String>,
+}
+
+/// Wall-clock time spent in each stage, in milliseconds.
+#[derive(Debug, Default, Serialize)]
+pub struct Timings {
+    pub parser: f64,
+    pub plain_text: f64,
+    pub css: f64,
+    pub images: f64,
+    pub total: f64,
+}
+
+impl StageItems {
+    pub fn record(&mut self, source: &str, start: LineColumn, result: Result<(), String>) {
+        match result {
+            Ok(()) => self.rendered += 1,
+            Err(error) => self.failures.push(Failure {
+                source: source.to_string(),
+                line: start.line,
+                column: start.column,
+                error,
+            }),
+        }
+    }
+}
+
+impl Failure {
+    /// `line:column`, as editors take it.
+    fn at(&self) -> String {
+        format!("{}:{}", self.line, self.column)
+    }
+}
+
+impl Report {
+    /// One line per piece of content that fell back to source.
+    pub fn fallbacks(&self) -> Vec<String> {
+        let math = self
+            .math
+            .failures
+            .iter()
+            .map(|f| format!("math `{}` at {}: {}", f.source, f.at(), f.error));
+        let mermaid = self
+            .mermaid
+            .failures
+            .iter()
+            .map(|f| format!("mermaid diagram at {}: {}", f.at(), f.error));
+        let graphviz = self
+            .graphviz
+            .failures
+            .iter()
+            .map(|f| format!("graphviz diagram at {}: {}", f.at(), f.error));
+        let plugins = self
+            .plugins
+            .failures
+            .iter()
+            .map(|f| format!("plugin block at {}: {}", f.at(), f.error));
+        let images = self
+            .images
+            .iter()
+            .filter_map(|i| Some(format!("image {}: {}", i.src, i.error.as_ref()?)));
+        math.chain(mermaid)
+            .chain(graphviz)
+            .chain(plugins)
+            .chain(images)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fallbacks_lists_every_failure() {
+        let mut report = Report::default();
+        let at = |line, column| LineColumn { line, column };
+        report.math.record("x^2", at(1, 1), Ok(()));
+        report
+            .math
+            .record("x^", at(1, 8), Err("Missing argument".to_string()));
+        report
+            .mermaid
+            .record("graph", at(3, 1), Err("parse error".to_string()));
+        report
+            .plugins
+            .record("ok", at(7, 1), Err("status.js: no data".to_string()));
+        report.images.push(ImageReport {
+            src: "cat.png".to_string(),
+            error: Some("not found".to_string()),
+            ..ImageReport::default()
+        });
+
+        assert_eq!(report.math.rendered, 1);
+        assert_eq!(
+            report.fallbacks(),
+            [
+                "math `x^` at 1:8: Missing argument",
+                "mermaid diagram at 3:1: parse error",
+                "plugin block at 7:1: status.js: no data",
+                "image cat.png: not found",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_serializes_to_json() {
+        let mut report = Report::default();
+        let start = LineColumn { line: 2, column: 5 };
+        report
+            .math
+            .record("x^", start, Err("Missing argument".to_string()));
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["math"]["rendered"], 0);
+        assert_eq!(
+            json["math"]["failures"][0],
+            serde_json::json!({
+                "source": "x^",
+                "line": 2,
+                "column": 5,
+                "error": "Missing argument",
+            })
+        );
+        assert!(json["images"].as_array().unwrap().is_empty());
+        assert!(json["timings_ms"]["parser"].is_number());
+    }
+}
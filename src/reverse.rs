@@ -0,0 +1,648 @@
+//! Reverse conversion - rich HTML (e.g. copied from Teams or Google Docs) back to GFM.
+//!
+//! Math images produced by md2cb are turned back into `$...$` / `$$...$$`
+//! using their `alt` text, which holds the original LaTeX.
+
+use kuchikiki::traits::*;
+use kuchikiki::{ElementData, NodeRef};
+
+/// Elements rendered as Markdown blocks; everything else is inline.
+const BLOCK_TAGS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "body",
+    "dd",
+    "details",
+    "div",
+    "dl",
+    "dt",
+    "figcaption",
+    "figure",
+    "footer",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "html",
+    "li",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "summary",
+    "table",
+    "ul",
+];
+
+/// Elements whose content is never rendered.
+const SKIPPED_TAGS: &[&str] = &["head", "meta", "script", "style", "template", "title"];
+
+/// Alt text md2cb gives rendered diagrams, which must not become math.
+const DIAGRAM_ALTS: [&str; 2] = ["Mermaid diagram", "Graphviz diagram"];
+
+/// Converts an HTML document or fragment to GitHub Flavored Markdown.
+pub fn html_to_markdown(html: &str) -> String {
+    let document = kuchikiki::parse_html().one(html);
+    let mut markdown = render_blocks(&document).join("\n\n");
+    markdown.push('\n');
+    markdown
+}
+
+fn tag_name(element: &ElementData) -> &str {
+    &element.name.local
+}
+
+fn attr(node: &NodeRef, name: &str) -> Option<String> {
+    node.as_element()?
+        .attributes
+        .borrow()
+        .get(name)
+        .map(str::to_string)
+}
+
+fn has_class(node: &NodeRef, class: &str) -> bool {
+    attr(node, "class").is_some_and(|c| c.split_whitespace().any(|c| c == class))
+}
+
+/// Returns the normalized inline style, e.g. `font-weight:700;color:red`.
+fn style(node: &NodeRef) -> String {
+    attr(node, "style")
+        .unwrap_or_default()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_ascii_lowercase()
+}
+
+fn is_block(node: &NodeRef) -> bool {
+    node.as_element()
+        .is_some_and(|e| BLOCK_TAGS.contains(&tag_name(e)))
+}
+
+/// Renders the children of `node`, grouping runs of inline content into paragraphs.
+fn render_blocks(node: &NodeRef) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut inline = String::new();
+
+    for child in node.children() {
+        if is_block(&child) {
+            push_paragraph(&mut blocks, &mut inline);
+            let block = render_block(&child);
+            if !block.trim().is_empty() {
+                blocks.push(block);
+            }
+        } else if child.descendants().any(|n| is_block(&n)) {
+            // Inline wrappers around blocks, like the <b> Google Docs puts
+            // around the whole document, are treated as transparent
+            push_paragraph(&mut blocks, &mut inline);
+            blocks.extend(render_blocks(&child));
+        } else {
+            render_inline(&child, &mut inline);
+        }
+    }
+    push_paragraph(&mut blocks, &mut inline);
+    blocks
+}
+
+fn push_paragraph(blocks: &mut Vec<String>, inline: &mut String) {
+    let paragraph = tidy_inline(inline);
+    if !paragraph.is_empty() {
+        blocks.push(paragraph);
+    }
+    inline.clear();
+}
+
+fn render_block(node: &NodeRef) -> String {
+    let Some(element) = node.as_element() else {
+        return String::new();
+    };
+    match tag_name(element) {
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+            let level = usize::from(tag_name(element).as_bytes()[1] - b'0');
+            format!("{} {}", "#".repeat(level), inline_content(node))
+        }
+        "p" | "dt" | "summary" | "figcaption" => render_blocks(node).join("\n\n"),
+        "ul" => render_list(node, false),
+        "ol" => render_list(node, true),
+        "pre" => render_code_block(node),
+        "blockquote" => prefix_lines(&render_blocks(node).join("\n\n"), "> ", "> "),
+        "table" => render_table(node),
+        "hr" => "---".to_string(),
+        "dd" => prefix_lines(&render_blocks(node).join("\n\n"), ": ", "  "),
+        "li" => render_blocks(node).join("\n"),
+        _ if has_class(node, "math-display") => match math_image(node) {
+            Some((latex, _)) => format!("$${latex}$$"),
+            None => render_blocks(node).join("\n\n"),
+        },
+        _ => render_blocks(node).join("\n\n"),
+    }
+}
+
+fn render_list(node: &NodeRef, ordered: bool) -> String {
+    let start: usize = attr(node, "start")
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(1);
+
+    let items: Vec<String> = node
+        .children()
+        .filter(|c| c.as_element().is_some_and(|e| tag_name(e) == "li"))
+        .enumerate()
+        .map(|(i, item)| {
+            let marker = if ordered {
+                format!("{}. ", start + i)
+            } else {
+                "- ".to_string()
+            };
+            let indent = " ".repeat(marker.len());
+            let content = task_marker(&render_blocks(&item).join("\n"));
+            prefix_lines(&content, &marker, &indent)
+        })
+        .collect();
+    items.join("\n")
+}
+
+/// Turns md2cb's Unicode checkboxes back into GFM task list markers.
+fn task_marker(content: &str) -> String {
+    for (symbol, marker) in [("✅", "[x]"), ("☑", "[x]"), ("⬜", "[ ]"), ("☐", "[ ]")] {
+        if let Some(rest) = content.strip_prefix(symbol) {
+            return format!("{marker} {}", rest.trim_start());
+        }
+    }
+    content.to_string()
+}
+
+fn render_code_block(node: &NodeRef) -> String {
+    let language = node
+        .inclusive_descendants()
+        .filter_map(|n| attr(&n, "class"))
+        .find_map(|class| {
+            class
+                .split_whitespace()
+                .find_map(|c| c.strip_prefix("language-").map(str::to_string))
+        })
+        .unwrap_or_default();
+
+    let mut code = String::new();
+    collect_code_text(node, &mut code);
+    let code = code.trim_end_matches('\n');
+
+    let longest_run = code.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest_run.max(2) + 1);
+    format!("{fence}{language}\n{code}\n{fence}")
+}
+
+/// Collects text verbatim, turning `<br>` (used for Teams) back into newlines.
+fn collect_code_text(node: &NodeRef, out: &mut String) {
+    for child in node.children() {
+        if let Some(text) = child.as_text() {
+            out.push_str(&text.borrow());
+        } else if child.as_element().is_some_and(|e| tag_name(e) == "br") {
+            out.push('\n');
+        } else {
+            collect_code_text(&child, out);
+        }
+    }
+}
+
+fn render_table(node: &NodeRef) -> String {
+    let rows: Vec<NodeRef> = node
+        .descendants()
+        .filter(|n| n.as_element().is_some_and(|e| tag_name(e) == "tr"))
+        .collect();
+    let Some(header) = rows.first() else {
+        return String::new();
+    };
+
+    let cells = |row: &NodeRef| -> Vec<NodeRef> {
+        row.children()
+            .filter(|c| {
+                c.as_element()
+                    .is_some_and(|e| matches!(tag_name(e), "th" | "td"))
+            })
+            .collect()
+    };
+    let columns = rows.iter().map(|r| cells(r).len()).max().unwrap_or(0);
+    if columns == 0 {
+        return String::new();
+    }
+
+    let render_row = |row: &NodeRef| -> String {
+        let mut texts: Vec<String> = cells(row)
+            .iter()
+            .map(|cell| inline_content(cell).replace('|', "\\|").replace('\n', " "))
+            .collect();
+        texts.resize(columns, String::new());
+        format!("| {} |", texts.join(" | "))
+    };
+
+    let mut alignments: Vec<&str> = cells(header)
+        .iter()
+        .map(|cell| {
+            let align = attr(cell, "align").unwrap_or_default();
+            let style = style(cell);
+            if align == "center" || style.contains("text-align:center") {
+                ":---:"
+            } else if align == "right" || style.contains("text-align:right") {
+                "---:"
+            } else if align == "left" || style.contains("text-align:left") {
+                ":---"
+            } else {
+                "---"
+            }
+        })
+        .collect();
+    alignments.resize(columns, "---");
+
+    let mut lines = vec![
+        render_row(header),
+        format!("| {} |", alignments.join(" | ")),
+    ];
+    lines.extend(rows.iter().skip(1).map(render_row));
+    lines.join("\n")
+}
+
+/// Renders the inline content of an element as a single tidied string.
+fn inline_content(node: &NodeRef) -> String {
+    let mut out = String::new();
+    for child in node.children() {
+        render_inline(&child, &mut out);
+    }
+    tidy_inline(&out)
+}
+
+fn render_inline(node: &NodeRef, out: &mut String) {
+    if let Some(text) = node.as_text() {
+        push_text(out, &text.borrow());
+        return;
+    }
+    let Some(element) = node.as_element() else {
+        return;
+    };
+
+    let tag = tag_name(element);
+    if SKIPPED_TAGS.contains(&tag) {
+        return;
+    }
+
+    let style = style(node);
+    match tag {
+        "br" => out.push_str("\\\n"),
+        "img" => match math_image(node) {
+            Some((latex, true)) => out.push_str(&format!("$${latex}$$")),
+            Some((latex, false)) => out.push_str(&format!("${latex}$")),
+            None => {
+                let alt = attr(node, "alt").unwrap_or_default();
+                let src = attr(node, "src").unwrap_or_default();
+                out.push_str(&format!("![{}]({src})", escape_text(&alt)));
+            }
+        },
+        // MathML from md2cb keeps the TeX source in alttext
+        "math" => match attr(node, "alttext") {
+            Some(latex) if attr(node, "display").as_deref() == Some("block") => {
+                out.push_str(&format!("$${}$$", latex.trim()))
+            }
+            Some(latex) => out.push_str(&format!("${}$", latex.trim())),
+            None => push_text(out, &node.text_contents()),
+        },
+        "input" if attr(node, "type").as_deref() == Some("checkbox") => {
+            let checked = attr(node, "checked").is_some();
+            out.push_str(if checked { "[x] " } else { "[ ] " });
+        }
+        "code" | "kbd" | "samp" | "tt" => out.push_str(&code_span(&node.text_contents())),
+        "a" => render_link(node, out),
+        // Failed math and diagrams already hold their source
+        _ if has_class(node, "math-error") => out.push_str(&node.text_contents()),
+        "strong" | "b" if !is_normal_weight(&style) => wrap_inline(node, out, "**"),
+        "em" | "i" if !style.contains("font-style:normal") => wrap_inline(node, out, "*"),
+        "del" | "s" | "strike" => wrap_inline(node, out, "~~"),
+        "sup" => wrap_inline(node, out, "^"),
+        // Google Docs expresses formatting through span styles
+        "span" if style.contains("font-weight:700") || style.contains("font-weight:bold") => {
+            wrap_inline(node, out, "**")
+        }
+        "span" if style.contains("font-style:italic") => wrap_inline(node, out, "*"),
+        "span" if style.contains("text-decoration:line-through") => wrap_inline(node, out, "~~"),
+        _ => {
+            for child in node.children() {
+                render_inline(&child, out);
+            }
+        }
+    }
+}
+
+fn is_normal_weight(style: &str) -> bool {
+    style.contains("font-weight:normal") || style.contains("font-weight:400")
+}
+
+/// Wraps the element's content in `marker`, keeping surrounding whitespace
+/// outside the markers so the emphasis still parses.
+fn wrap_inline(node: &NodeRef, out: &mut String, marker: &str) {
+    let mut inner = String::new();
+    for child in node.children() {
+        render_inline(&child, &mut inner);
+    }
+    let trimmed = inner.trim();
+    if trimmed.is_empty() {
+        out.push_str(&inner);
+        return;
+    }
+    if inner.starts_with(char::is_whitespace) {
+        push_text(out, " ");
+    }
+    out.push_str(&format!("{marker}{trimmed}{marker}"));
+    if inner.ends_with(char::is_whitespace) {
+        out.push(' ');
+    }
+}
+
+fn render_link(node: &NodeRef, out: &mut String) {
+    let href = attr(node, "href").unwrap_or_default();
+    let mut text = String::new();
+    for child in node.children() {
+        render_inline(&child, &mut text);
+    }
+    let text = text.trim();
+    if href.is_empty() {
+        out.push_str(text);
+    } else if text.is_empty() || text == href {
+        out.push_str(&format!("<{href}>"));
+    } else {
+        out.push_str(&format!("[{text}]({href})"));
+    }
+}
+
+/// Returns the LaTeX source and display mode if `node` is (or wraps) one of
+/// md2cb's rendered math images.
+///
+/// Class names are checked first; editors that strip classes keep the inline
+/// styles md2cb puts on the image, which are used as a fallback.
+fn math_image(node: &NodeRef) -> Option<(String, bool)> {
+    let img = node
+        .inclusive_descendants()
+        .find(|n| n.as_element().is_some_and(|e| tag_name(e) == "img"))?;
+    let alt = attr(&img, "alt")
+        .filter(|a| !a.trim().is_empty() && !DIAGRAM_ALTS.contains(&a.as_str()))?;
+
+    let display = img
+        .inclusive_ancestors()
+        .take(3)
+        .find_map(|n| {
+            if has_class(&n, "math-display") {
+                Some(true)
+            } else if has_class(&n, "math-inline") {
+                Some(false)
+            } else {
+                None
+            }
+        })
+        .or_else(|| {
+            let src = attr(&img, "src").unwrap_or_default();
+            let style = style(&img);
+            if !src.starts_with("data:image/png") {
+                None
+            } else if style.contains("margin:0.5emauto") {
+                Some(true)
+            } else if style.contains("vertical-align:") {
+                Some(false)
+            } else {
+                None
+            }
+        })?;
+
+    Some((alt.trim().to_string(), display))
+}
+
+/// Appends HTML text, collapsing whitespace runs and escaping Markdown syntax.
+fn push_text(out: &mut String, text: &str) {
+    let mut last_space = out.is_empty() || out.ends_with([' ', '\n']);
+    let mut collapsed = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_whitespace() && c != '\u{a0}' {
+            if !last_space {
+                collapsed.push(' ');
+            }
+            last_space = true;
+        } else {
+            collapsed.push(c);
+            last_space = false;
+        }
+    }
+    out.push_str(&escape_text(&collapsed));
+}
+
+/// Escapes characters that would otherwise be read as Markdown (or math) syntax.
+fn escape_text(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    for (i, &c) in chars.iter().enumerate() {
+        let escape = match c {
+            '\\' | '`' | '*' | '[' | ']' | '$' => true,
+            // Intraword underscores (snake_case) never start emphasis in GFM
+            '_' => {
+                let before = i.checked_sub(1).map(|j| chars[j]);
+                let after = chars.get(i + 1).copied();
+                !(before.is_some_and(char::is_alphanumeric)
+                    && after.is_some_and(char::is_alphanumeric))
+            }
+            _ => false,
+        };
+        if escape {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Wraps code in enough backticks to contain any backtick run inside it.
+fn code_span(code: &str) -> String {
+    let longest_run = code.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat(longest_run + 1);
+    let pad = if code.starts_with('`') || code.ends_with('`') {
+        " "
+    } else {
+        ""
+    };
+    format!("{fence}{pad}{code}{pad}{fence}")
+}
+
+/// Trims each line of rendered inline content.
+fn tidy_inline(inline: &str) -> String {
+    inline
+        .trim()
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Prefixes the first line with `first` and every following non-empty line with `rest`.
+fn prefix_lines(text: &str, first: &str, rest: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for (i, line) in text.split('\n').enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        if i == 0 {
+            out.push_str(first);
+        } else if !line.is_empty() {
+            out.push_str(rest);
+        } else {
+            out.push_str(rest.trim_end());
+        }
+        out.push_str(line);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_headings_and_paragraphs() {
+        assert_eq!(
+            html_to_markdown("<h1>Title</h1><p>Hello\n  world</p><h3>Sub</h3>"),
+            "# Title\n\nHello world\n\n### Sub\n"
+        );
+    }
+
+    #[test]
+    fn test_inline_formatting() {
+        assert_eq!(
+            html_to_markdown(
+                "<p><strong>bold</strong>, <em>it</em>, <del>gone</del>, <code>x</code></p>"
+            ),
+            "**bold**, *it*, ~~gone~~, `x`\n"
+        );
+    }
+
+    #[test]
+    fn test_google_docs_spans() {
+        let html = r#"<b style="font-weight:normal;" id="docs-internal-guid-1"><p><span style="font-weight:700">bold</span> <span style="font-style:italic">it</span></p></b>"#;
+        assert_eq!(html_to_markdown(html), "**bold** *it*\n");
+    }
+
+    #[test]
+    fn test_links_and_images() {
+        assert_eq!(
+            html_to_markdown(
+                r#"<p><a href="https://x.dev">docs</a> <a href="https://y.dev">https://y.dev</a> <img src="a.png" alt="cat"></p>"#
+            ),
+            "[docs](https://x.dev) <https://y.dev> ![cat](a.png)\n"
+        );
+    }
+
+    #[test]
+    fn test_nested_lists() {
+        let html = "<ul><li>one<ul><li>nested</li></ul></li><li>two</li></ul><ol start=\"3\"><li>three</li><li>four</li></ol>";
+        assert_eq!(
+            html_to_markdown(html),
+            "- one\n  - nested\n- two\n\n3. three\n4. four\n"
+        );
+    }
+
+    #[test]
+    fn test_task_list_checkboxes() {
+        let html = "<ul><li>✅ done</li><li>⬜  todo</li><li><input type=\"checkbox\" checked> html</li></ul>";
+        assert_eq!(
+            html_to_markdown(html),
+            "- [x] done\n- [ ] todo\n- [x] html\n"
+        );
+    }
+
+    #[test]
+    fn test_code_block_with_br() {
+        let html = r#"<pre style="background-color:#f6f8fa;"><code class="language-rust"><span>fn main() {</span><br><span>    a &lt; b;</span><br><span>}</span></code></pre>"#;
+        assert_eq!(
+            html_to_markdown(html),
+            "```rust\nfn main() {\n    a < b;\n}\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_table() {
+        let html = r#"<table><thead><tr><th>Name</th><th align="right">Qty</th></tr></thead><tbody><tr><td>a|b</td><td>1</td></tr></tbody></table>"#;
+        assert_eq!(
+            html_to_markdown(html),
+            "| Name | Qty |\n| --- | ---: |\n| a\\|b | 1 |\n"
+        );
+    }
+
+    #[test]
+    fn test_blockquote() {
+        assert_eq!(
+            html_to_markdown("<blockquote><p>one</p><p>two</p></blockquote>"),
+            "> one\n>\n> two\n"
+        );
+    }
+
+    #[test]
+    fn test_md2cb_math_images() {
+        let html = r#"<p>Inline <span class="math math-inline"><img src="data:image/png;base64,AA" alt="E = mc^2" style="vertical-align:middle;"></span>.</p><div class="math math-display"><img src="data:image/png;base64,AA" alt="\int_0^1 x\,dx" style="display:block;margin:0.5em auto;"></div>"#;
+        assert_eq!(
+            html_to_markdown(html),
+            "Inline $E = mc^2$.\n\n$$\\int_0^1 x\\,dx$$\n"
+        );
+    }
+
+    #[test]
+    fn test_md2cb_mathml() {
+        let html = r#"<p>Inline <span class="math math-inline"><math xmlns="http://www.w3.org/1998/Math/MathML" alttext="a &lt; b"><mi>a</mi><mo>&lt;</mo><mi>b</mi></math></span>.</p><div class="math math-display"><math xmlns="http://www.w3.org/1998/Math/MathML" alttext="x^2" display="block"><msup><mi>x</mi><mn>2</mn></msup></math></div>"#;
+        assert_eq!(html_to_markdown(html), "Inline $a < b$.\n\n$$x^2$$\n");
+    }
+
+    #[test]
+    fn test_math_images_without_classes() {
+        // Teams strips classes but keeps inline styles
+        let html = r#"<p>Sum <img src="data:image/png;base64,AA" alt="a+b" style="vertical-align: middle;"></p>"#;
+        assert_eq!(html_to_markdown(html), "Sum $a+b$\n");
+        let html = r#"<p>Sum <img src="data:image/png;base64,AA" alt="a_i" style="vertical-align:-3.19px;"></p>"#;
+        assert_eq!(html_to_markdown(html), "Sum $a_i$\n");
+    }
+
+    #[test]
+    fn test_mermaid_image_stays_image() {
+        let html = r#"<div class="mermaid-diagram"><img src="data:image/png;base64,AA" alt="Mermaid diagram" style="display:block;margin:0.5em auto;"></div>"#;
+        assert_eq!(
+            html_to_markdown(html),
+            "![Mermaid diagram](data:image/png;base64,AA)\n"
+        );
+    }
+
+    #[test]
+    fn test_graphviz_image_stays_image() {
+        let html = r#"<div class="graphviz-diagram"><img src="data:image/png;base64,AA" alt="Graphviz diagram" style="display:block;margin:0.5em auto;"></div>"#;
+        assert_eq!(
+            html_to_markdown(html),
+            "![Graphviz diagram](data:image/png;base64,AA)\n"
+        );
+    }
+
+    #[test]
+    fn test_escaping() {
+        assert_eq!(
+            html_to_markdown("<p>costs $5 *now* snake_case _x_</p>"),
+            "costs \\$5 \\*now\\* snake_case \\_x\\_\n"
+        );
+    }
+
+    #[test]
+    fn test_line_break() {
+        assert_eq!(html_to_markdown("<p>one<br>two</p>"), "one\\\ntwo\n");
+    }
+
+    #[test]
+    fn test_skips_head_and_style() {
+        let html = "<html><head><style>p{color:red}</style><title>t</title></head><body><p>x</p></body></html>";
+        assert_eq!(html_to_markdown(html), "x\n");
+    }
+}
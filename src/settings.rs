@@ -0,0 +1,348 @@
+//! Persistent defaults loaded from TOML config files.
+//!
+//! Settings come from the user config at `$XDG_CONFIG_HOME/md2cb/config.toml`
+//! (falling back to `~/.config`), overridden key by key by the nearest
+//! `.md2cb.toml` found walking up from the input file. Command-line flags
+//! take precedence over both.
+//!
+//! ```toml
+//! target = "teams"
+//! theme = "base16-ocean.light"
+//!
+//! [extensions]
+//! superscript = false
+//! latex_fences = true
+//!
+//! [images]
+//! max_bytes = 2097152
+//! formats = ["image/png", "image/jpeg"]
+//!
+//! [math]
+//! style = "source"
+//! scale = 1.1
+//! numbering = "ams"
+//!
+//! [math.macros]
+//! R = '\mathbb{R}'
+//! norm = '\left\lVert #1 \right\rVert'
+//!
+//! [cache]
+//! max_bytes = 16777216
+//! ```
+
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::cache::{self, Cache};
+use crate::parser::{self, Extensions};
+use crate::target::{EquationNumbering, MathStyle, Profile, Target};
+
+/// File name of the per-project config.
+const PROJECT_FILE: &str = ".md2cb.toml";
+
+/// Settings read from the config files.
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Settings {
+    /// Target used when `--target` isn't given.
+    pub target: Option<Target>,
+    /// Syntax highlighting theme for code blocks.
+    pub theme: Option<String>,
+    pub extensions: Option<Extensions>,
+    pub images: ImageSettings,
+    pub math: MathSettings,
+    pub cache: CacheSettings,
+}
+
+/// Overrides for the target's image limits.
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ImageSettings {
+    pub max_bytes: Option<usize>,
+    pub formats: Option<Vec<String>>,
+}
+
+/// Overrides for how math is rendered.
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MathSettings {
+    pub style: Option<MathStyle>,
+    /// Size of math images relative to 16px text.
+    pub scale: Option<f32>,
+    pub numbering: Option<EquationNumbering>,
+    /// LaTeX macros by name, `#1`.. standing for their arguments.
+    pub macros: BTreeMap<String, String>,
+}
+
+/// Render cache settings.
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CacheSettings {
+    /// Set to `false` to render everything on every run.
+    pub enabled: Option<bool>,
+    /// Cap on the total size of cached renders.
+    pub max_bytes: Option<u64>,
+}
+
+impl Settings {
+    /// Applies these settings on top of a target profile.
+    pub fn apply(&self, profile: &mut Profile) {
+        if let Some(theme) = self.theme.as_deref() {
+            // Names are checked in `load`, so this always finds a match
+            if let Some(known) = parser::THEMES.iter().find(|t| **t == theme) {
+                profile.theme = Cow::Borrowed(known);
+            }
+        }
+        if let Some(extensions) = self.extensions {
+            profile.extensions = extensions;
+        }
+        if let Some(max_bytes) = self.images.max_bytes {
+            profile.images.max_bytes = max_bytes;
+        }
+        if let Some(formats) = &self.images.formats {
+            profile.images.formats = formats.clone();
+        }
+        if let Some(style) = self.math.style {
+            profile.math = style;
+        }
+        if let Some(scale) = self.math.scale {
+            profile.math_scale = scale;
+        }
+        if let Some(numbering) = self.math.numbering {
+            profile.numbering = numbering;
+        }
+        profile.macros.extend(self.math.macros.clone());
+    }
+
+    /// The render cache in its default location, unless disabled.
+    pub fn cache(&self) -> Option<Cache> {
+        if self.cache.enabled == Some(false) {
+            return None;
+        }
+        let max_bytes = self.cache.max_bytes.unwrap_or(cache::DEFAULT_MAX_BYTES);
+        Cache::default_dir().map(|dir| Cache::new(dir, max_bytes))
+    }
+}
+
+/// Loads the user config merged with the project config for `input_file`.
+///
+/// Without an input file the project config is looked up from the current
+/// directory. Missing files are not an error.
+pub fn load(input_file: Option<&Path>) -> Result<Settings, String> {
+    let start_dir = match input_file {
+        Some(path) => path
+            .canonicalize()
+            .ok()
+            .and_then(|p| p.parent().map(Path::to_path_buf)),
+        None => env::current_dir().ok(),
+    };
+
+    let paths = [
+        user_config_path(),
+        start_dir.and_then(|dir| find_project_config(&dir)),
+    ];
+
+    let mut merged = toml::Table::new();
+    for path in paths.into_iter().flatten() {
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(format!("{}: {e}", path.display())),
+        };
+        let table: toml::Table = content
+            .parse()
+            .map_err(|e| format!("{}: {e}", path.display()))?;
+        merge_tables(&mut merged, table);
+    }
+
+    parse_table(merged)
+}
+
+fn parse_table(table: toml::Table) -> Result<Settings, String> {
+    let settings: Settings = table.try_into().map_err(|e| format!("{e}"))?;
+    if let Some(scale) = settings.math.scale.filter(|s| !s.is_finite() || *s <= 0.0) {
+        return Err(format!("math scale must be a positive number, got {scale}"));
+    }
+    match settings.theme.as_deref() {
+        Some(theme) if !parser::THEMES.contains(&theme) => Err(format!(
+            "unknown theme '{theme}' (expected one of: {})",
+            parser::THEMES.join(", ")
+        )),
+        _ => Ok(settings),
+    }
+}
+
+/// Merges `overrides` into `base`, key by key inside nested tables.
+fn merge_tables(base: &mut toml::Table, overrides: toml::Table) {
+    for (key, value) in overrides {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(existing)), toml::Value::Table(nested)) => {
+                merge_tables(existing, nested);
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Directory of the user's JavaScript plugins, next to the user config.
+pub fn plugins_dir() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("plugins"))
+}
+
+fn user_config_path() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("config.toml"))
+}
+
+fn config_dir() -> Option<PathBuf> {
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_dir.join("md2cb"))
+}
+
+/// Finds the nearest `.md2cb.toml` in `dir` or one of its ancestors.
+fn find_project_config(dir: &Path) -> Option<PathBuf> {
+    dir.ancestors()
+        .map(|d| d.join(PROJECT_FILE))
+        .find(|path| path.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(content: &str) -> Result<Settings, String> {
+        parse_table(content.parse().map_err(|e| format!("{e}"))?)
+    }
+
+    #[test]
+    fn test_parse_full_config() {
+        let settings = parse(
+            r#"
+            target = "teams"
+            theme = "base16-ocean.light"
+
+            [extensions]
+            superscript = false
+            latex_fences = true
+
+            [images]
+            max_bytes = 1024
+            formats = ["image/png"]
+
+            [math]
+            style = "source"
+            scale = 1.25
+            numbering = "all"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(settings.target, Some(Target::Teams));
+        assert_eq!(settings.theme.as_deref(), Some("base16-ocean.light"));
+        let extensions = settings.extensions.unwrap();
+        assert!(!extensions.superscript);
+        assert!(extensions.table);
+        assert!(extensions.latex_fences);
+        assert_eq!(settings.images.max_bytes, Some(1024));
+        assert_eq!(settings.math.style, Some(MathStyle::Source));
+        assert_eq!(settings.math.scale, Some(1.25));
+        assert_eq!(settings.math.numbering, Some(EquationNumbering::All));
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_values() {
+        assert!(
+            parse(r#"target = "notion""#)
+                .unwrap_err()
+                .contains("notion")
+        );
+        assert!(
+            parse(r#"theme = "dracula""#)
+                .unwrap_err()
+                .contains("unknown theme")
+        );
+        assert!(
+            parse("[math]\nscale = 0")
+                .unwrap_err()
+                .contains("math scale")
+        );
+        assert!(parse("colour = true").is_err());
+    }
+
+    #[test]
+    fn test_project_config_overrides_user_config() {
+        let mut merged: toml::Table = "target = \"word\"\n[images]\nmax_bytes = 10\nformats = []"
+            .parse()
+            .unwrap();
+        merge_tables(&mut merged, "[images]\nmax_bytes = 20".parse().unwrap());
+
+        let settings = parse_table(merged).unwrap();
+        assert_eq!(settings.target, Some(Target::Word));
+        assert_eq!(settings.images.max_bytes, Some(20));
+        assert_eq!(settings.images.formats, Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_apply_overrides_profile() {
+        let settings = parse("theme = \"Solarized (dark)\"\n[math]\nstyle = \"source\"").unwrap();
+        let mut profile = Target::Generic.profile();
+        settings.apply(&mut profile);
+        assert_eq!(profile.theme, "Solarized (dark)");
+        assert_eq!(profile.math, MathStyle::Source);
+        assert_eq!(profile.images, Target::Generic.profile().images);
+    }
+
+    #[test]
+    fn test_math_macros_merged_by_name() {
+        let mut merged: toml::Table = "[math.macros]\nR = '\\mathbb{R}'\nN = '\\mathbb{N}'"
+            .parse()
+            .unwrap();
+        merge_tables(&mut merged, "[math.macros]\nR = '\\Re'".parse().unwrap());
+
+        let mut profile = Target::Generic.profile();
+        parse_table(merged).unwrap().apply(&mut profile);
+        assert_eq!(profile.macros["R"], r"\Re");
+        assert_eq!(profile.macros["N"], r"\mathbb{N}");
+    }
+
+    #[test]
+    fn test_cache_settings() {
+        assert!(parse("[cache]\nenabled = false").unwrap().cache().is_none());
+        let settings = parse("[cache]\nmax_bytes = 1024").unwrap();
+        assert_eq!(settings.cache.max_bytes, Some(1024));
+    }
+
+    #[test]
+    fn test_find_project_config_walks_up() {
+        let root = env::temp_dir().join(format!("md2cb-settings-{}", std::process::id()));
+        let nested = root.join("docs").join("notes");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(root.join(PROJECT_FILE), "target = \"slack\"").unwrap();
+
+        assert_eq!(find_project_config(&nested), Some(root.join(PROJECT_FILE)));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_unreadable_config_is_an_error() {
+        let root = env::temp_dir().join(format!("md2cb-unreadable-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join(PROJECT_FILE), b"target = \"\xff\"").unwrap();
+        fs::write(root.join("doc.md"), "# Doc").unwrap();
+
+        let err = load(Some(&root.join("doc.md"))).unwrap_err();
+        assert!(err.contains(PROJECT_FILE), "{err}");
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
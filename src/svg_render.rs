@@ -2,6 +2,11 @@
 //!
 //! Converts SVG strings to PNG images with high-resolution rendering
 //! for crisp output when displayed at smaller sizes.
+//!
+//! MathJax sizes its SVG in ex, which resvg would resolve against a default
+//! 12px font. Math is instead sized from the box MathJax reports, for text at
+//! [`MATH_FONT_SIZE`] times the target's math scale, and keeps MathJax's depth
+//! below the baseline so it lines up with the text.
 
 use base64::{Engine, engine::general_purpose::STANDARD};
 use resvg::tiny_skia::{Pixmap, Transform};
@@ -9,7 +14,24 @@ use resvg::usvg::{Options, Tree, fontdb};
 use std::sync::{Arc, OnceLock};
 
 /// Render scale factor for crisp output (4x like the original Node.js implementation)
-const RENDER_SCALE: f32 = 4.0;
+pub const RENDER_SCALE: f32 = 4.0;
+
+/// Text size in pixels math is rendered for at a math scale of 1, the body
+/// text of the GitHub stylesheet.
+pub const MATH_FONT_SIZE: f32 = 16.0;
+
+/// Height of MathJax's ex, in em: the x-height of its TeX fonts.
+const MATHJAX_EX: f32 = 0.442;
+
+/// Size of rendered math and its depth below the baseline, in ex, as MathJax
+/// reports them.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct MathBox {
+    pub width: f32,
+    pub height: f32,
+    /// CSS `vertical-align`, negative when the math reaches below the baseline.
+    pub vertical_align: f32,
+}
 
 /// Global font database - loaded once and reused
 static FONT_DB: OnceLock<Arc<fontdb::Database>> = OnceLock::new();
@@ -33,6 +55,9 @@ pub struct SvgRenderResult {
     pub display_width: u32,
     /// Display height in pixels
     pub display_height: u32,
+    /// CSS `vertical-align` in pixels that puts the image's baseline on the
+    /// text's, negative when it reaches below. Zero for anything but math.
+    pub vertical_align: f32,
 }
 
 /// Renders an SVG string to PNG and returns base64-encoded result.
@@ -40,18 +65,39 @@ pub struct SvgRenderResult {
 /// The SVG is rendered at 4x resolution for crispness, but the returned
 /// display dimensions are the original size.
 pub fn render_svg_to_png(svg_content: &str) -> Result<SvgRenderResult, String> {
-    // Parse SVG with font database for text rendering
+    let tree = parse(svg_content)?;
+    let size = tree.size();
+    render(&tree, size.width(), size.height())
+}
+
+/// Renders an SVG produced by MathJax to PNG, sized for text `scale` times
+/// [`MATH_FONT_SIZE`], along with the `vertical-align` matching its depth.
+pub fn render_math_svg_to_png(
+    svg_content: &str,
+    size: MathBox,
+    scale: f32,
+) -> Result<SvgRenderResult, String> {
+    if size.width <= 0.0 || size.height <= 0.0 {
+        return Err("SVG has no MathJax dimensions".to_string());
+    }
+    let ex = MATHJAX_EX * MATH_FONT_SIZE * scale;
+    let tree = parse(svg_content)?;
+    let mut result = render(&tree, size.width * ex, size.height * ex)?;
+    result.vertical_align = size.vertical_align * ex;
+    Ok(result)
+}
+
+/// Parses an SVG, with the font database for text rendering
+fn parse(svg_content: &str) -> Result<Tree, String> {
     let opts = Options {
         fontdb: get_font_db(),
         ..Default::default()
     };
-    let tree = Tree::from_str(svg_content, &opts).map_err(|e| format!("SVG parse error: {e}"))?;
-
-    // Get original size from the SVG
-    let size = tree.size();
-    let base_width = size.width();
-    let base_height = size.height();
+    Tree::from_str(svg_content, &opts).map_err(|e| format!("SVG parse error: {e}"))
+}
 
+/// Rasterizes an SVG at [`RENDER_SCALE`] times the given display size.
+fn render(tree: &Tree, base_width: f32, base_height: f32) -> Result<SvgRenderResult, String> {
     // Calculate render dimensions (scaled up for crispness)
     let render_width = (base_width * RENDER_SCALE).ceil() as u32;
     let render_height = (base_height * RENDER_SCALE).ceil() as u32;
@@ -75,9 +121,13 @@ pub fn render_svg_to_png(svg_content: &str) -> Result<SvgRenderResult, String> {
     // Fill with white background (matching the Node.js implementation)
     pixmap.fill(resvg::tiny_skia::Color::WHITE);
 
-    // Render with scale transform
-    let transform = Transform::from_scale(RENDER_SCALE, RENDER_SCALE);
-    resvg::render(&tree, transform, &mut pixmap.as_mut());
+    // Render with scale transform, stretching the SVG to the display size
+    let size = tree.size();
+    let transform = Transform::from_scale(
+        RENDER_SCALE * base_width / size.width(),
+        RENDER_SCALE * base_height / size.height(),
+    );
+    resvg::render(tree, transform, &mut pixmap.as_mut());
 
     // Encode to PNG
     let png_data = pixmap
@@ -91,6 +141,7 @@ pub fn render_svg_to_png(svg_content: &str) -> Result<SvgRenderResult, String> {
         png_base64,
         display_width,
         display_height,
+        vertical_align: 0.0,
     })
 }
 
@@ -118,6 +169,31 @@ mod tests {
         assert!(!result.png_base64.is_empty());
     }
 
+    #[test]
+    fn test_math_svg_size_and_depth() {
+        // MathJax's `x`: 0.442em tall, 0.011em of it below the baseline
+        let svg = r#"<svg style="vertical-align: -0.025ex;" xmlns="http://www.w3.org/2000/svg" width="1.294ex" height="1.025ex" viewBox="0 -442 572 453">
+            <rect y="-442" width="572" height="453"/>
+        </svg>"#;
+        let size = MathBox {
+            width: 1.294,
+            height: 1.025,
+            vertical_align: -0.025,
+        };
+        let result = render_math_svg_to_png(svg, size, 1.0).unwrap();
+        // 0.572em and 0.453em of 16px
+        assert_eq!(result.display_width, 10);
+        assert_eq!(result.display_height, 8);
+        assert!((result.vertical_align + 0.177).abs() < 0.01);
+
+        let result = render_math_svg_to_png(svg, size, 2.0).unwrap();
+        assert_eq!(result.display_width, 19);
+        assert!((result.vertical_align + 0.354).abs() < 0.01);
+
+        assert!(render_math_svg_to_png(svg, MathBox::default(), 1.0).is_err());
+        assert_eq!(render_svg_to_png(svg).unwrap().vertical_align, 0.0);
+    }
+
     #[test]
     fn test_invalid_svg() {
         let svg = "not valid svg";
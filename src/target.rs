@@ -0,0 +1,461 @@
+//! Target application profiles.
+//!
+//! Each editor we paste into has its own quirks. A [`Profile`] bundles the
+//! output choices for one of them: which post-processing passes run, which
+//! stylesheet is embedded, and how checkboxes, code blocks, math and images
+//! are emitted.
+
+use regex::Regex;
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use crate::parser::{DEFAULT_THEME, Extensions};
+use crate::plugins::Plugins;
+
+/// Markdown stylesheet embedded in the full HTML document.
+pub const GITHUB_CSS: &str = include_str!("../assets/github-markdown.css");
+
+/// Stylesheet for math rendered with [`MathStyle::Katex`].
+///
+/// KaTeX's fonts aren't bundled: its `@font-face` rules point at files next
+/// to the stylesheet, which a pasted document doesn't have, so they use the
+/// fonts installed where the document is viewed instead. Without them, math
+/// is set in the default fonts.
+pub static KATEX_CSS: LazyLock<String> = LazyLock::new(|| {
+    let font = Regex::new(r"src:url\(fonts/([\w-]+)\.woff2\)[^;}]*").unwrap();
+    font.replace_all(
+        include_str!("../assets/katex.min.css"),
+        r#"src:local("$1")"#,
+    )
+    .into_owned()
+});
+
+/// Application the converted HTML is meant to be pasted into.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub enum Target {
+    /// Compromise output that works reasonably everywhere.
+    #[default]
+    Generic,
+    Teams,
+    GoogleDocs,
+    Word,
+    Outlook,
+    Slack,
+}
+
+/// How task list checkboxes are emitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckboxStyle {
+    /// ✅ / ⬜ emoji.
+    Emoji,
+    /// ☑ / ☐ ballot boxes, which render as text in desktop Office fonts.
+    BallotBox,
+}
+
+/// How newlines inside code blocks are emitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodeBlockStyle {
+    /// Newlines become `<br>`, for editors that strip plain newlines (Teams).
+    LineBreaks,
+    /// Newlines are kept as-is inside `<pre>`.
+    Preformatted,
+}
+
+/// How math expressions are emitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MathStyle {
+    /// PNG images rendered with MathJax.
+    Png,
+    /// Inline MathML, which Word and Outlook turn into editable equations.
+    /// Falls back to a PNG if MathJax can't produce MathML.
+    ///
+    /// No OMML, Office's own equation markup, is emitted alongside: apps
+    /// that only read OMML get the MathML as text or drop it.
+    #[serde(rename = "mathml")]
+    MathMl,
+    /// HTML styled by the KaTeX stylesheet, so the math stays selectable and
+    /// searchable text in web editors such as Confluence.
+    Katex,
+    /// The LaTeX source in `<code>`, for targets that drop pasted images.
+    Source,
+}
+
+/// Which display equations get a number, like MathJax's `tags` option.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EquationNumbering {
+    /// Only equations with an explicit `\tag`.
+    #[default]
+    None,
+    /// Unstarred environments amsmath numbers, such as `equation` and `align`.
+    Ams,
+    /// Every display equation.
+    All,
+}
+
+/// Limits on which images get inlined as data URIs.
+///
+/// Images outside these limits keep their original `src`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageLimits {
+    /// Maximum size of a single image in bytes.
+    pub max_bytes: usize,
+    /// Accepted MIME types; an empty list disables inlining.
+    pub formats: Vec<String>,
+}
+
+impl Default for ImageLimits {
+    fn default() -> Self {
+        Self {
+            max_bytes: 10 * 1024 * 1024,
+            formats: mime_types(&[
+                "image/png",
+                "image/jpeg",
+                "image/gif",
+                "image/webp",
+                "image/svg+xml",
+                "image/x-icon",
+                "image/bmp",
+            ]),
+        }
+    }
+}
+
+impl ImageLimits {
+    /// Whether an image with the given MIME type and size may be inlined.
+    pub fn allows(&self, mime: &str, len: usize) -> bool {
+        len <= self.max_bytes && self.formats.iter().any(|f| mime.starts_with(f.as_str()))
+    }
+}
+
+fn mime_types(types: &[&str]) -> Vec<String> {
+    types.iter().map(|t| t.to_string()).collect()
+}
+
+/// Output settings for a [`Target`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Profile {
+    pub checkboxes: CheckboxStyle,
+    pub code_blocks: CodeBlockStyle,
+    pub math: MathStyle,
+    /// Size of math images relative to 16px text, to match the target's body
+    /// text.
+    pub math_scale: f32,
+    /// Which display equations are numbered, one number per equation.
+    pub numbering: EquationNumbering,
+    /// Render ```` ```mermaid ```` blocks as PNG diagrams.
+    pub mermaid: bool,
+    /// Render ```` ```dot ```` and ```` ```graphviz ```` blocks as PNG diagrams.
+    pub graphviz: bool,
+    /// Replace syntect's `<pre>` style with GitHub's inline code block style.
+    pub inline_code_style: bool,
+    /// Drop whitespace between tags and turn soft breaks into spaces.
+    pub collapse_whitespace: bool,
+    /// Resolve the stylesheet into per-element `style` attributes, for
+    /// targets that drop `<style>` blocks and classes.
+    pub inline_styles: bool,
+    /// Stylesheet embedded in the full document, if the target keeps
+    /// `<style>`. It's also the one `inline_styles` resolves, GitHub's if
+    /// there is none, in which case only the rules that can't be inlined,
+    /// such as `:hover`, are embedded.
+    pub stylesheet: Option<Cow<'static, str>>,
+    pub images: ImageLimits,
+    /// Syntax highlighting theme for code blocks, one of
+    /// [`THEMES`](crate::parser::THEMES).
+    pub theme: Cow<'static, str>,
+    /// Markdown extensions enabled when parsing.
+    pub extensions: Extensions,
+    /// LaTeX macros available to every math expression, by name.
+    pub macros: BTreeMap<String, String>,
+    /// User plugins rendering custom fenced blocks.
+    pub plugins: Plugins,
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Target::default().profile()
+    }
+}
+
+impl Target {
+    pub const ALL: [Target; 6] = [
+        Target::Generic,
+        Target::Teams,
+        Target::GoogleDocs,
+        Target::Word,
+        Target::Outlook,
+        Target::Slack,
+    ];
+
+    /// Name used on the command line.
+    pub fn name(self) -> &'static str {
+        match self {
+            Target::Generic => "generic",
+            Target::Teams => "teams",
+            Target::GoogleDocs => "gdocs",
+            Target::Word => "word",
+            Target::Outlook => "outlook",
+            Target::Slack => "slack",
+        }
+    }
+
+    /// Returns the output profile for this target.
+    pub fn profile(self) -> Profile {
+        let generic = Profile {
+            checkboxes: CheckboxStyle::Emoji,
+            code_blocks: CodeBlockStyle::LineBreaks,
+            math: MathStyle::Png,
+            math_scale: 1.0,
+            numbering: EquationNumbering::None,
+            mermaid: true,
+            graphviz: true,
+            inline_code_style: true,
+            collapse_whitespace: true,
+            inline_styles: true,
+            stylesheet: Some(Cow::Borrowed(GITHUB_CSS)),
+            images: ImageLimits::default(),
+            theme: Cow::Borrowed(DEFAULT_THEME),
+            extensions: Extensions::default(),
+            macros: BTreeMap::new(),
+            plugins: Plugins::default(),
+        };
+        let web_formats = || mime_types(&["image/png", "image/jpeg", "image/gif"]);
+
+        match self {
+            Target::Generic => generic,
+            // Teams and Google Docs drop <style> blocks on paste.
+            // Teams messages are set in 14px, Google Docs in 11pt.
+            Target::Teams => Profile {
+                math_scale: 0.875,
+                stylesheet: None,
+                images: ImageLimits {
+                    max_bytes: 4 * 1024 * 1024,
+                    formats: web_formats(),
+                },
+                ..generic
+            },
+            Target::GoogleDocs => Profile {
+                math_scale: 0.92,
+                stylesheet: None,
+                images: ImageLimits {
+                    max_bytes: 50 * 1024 * 1024,
+                    formats: web_formats(),
+                },
+                ..generic
+            },
+            // Word keeps <pre> newlines and the stylesheet, but emoji look out of place.
+            // Pasted MathML becomes native equations, images sit in 11pt text.
+            Target::Word => Profile {
+                checkboxes: CheckboxStyle::BallotBox,
+                math: MathStyle::MathMl,
+                math_scale: 0.92,
+                code_blocks: CodeBlockStyle::Preformatted,
+                collapse_whitespace: false,
+                images: ImageLimits {
+                    formats: mime_types(&["image/png", "image/jpeg", "image/gif", "image/bmp"]),
+                    ..ImageLimits::default()
+                },
+                ..generic
+            },
+            // Outlook uses Word's HTML engine but drops <style> blocks
+            Target::Outlook => Profile {
+                checkboxes: CheckboxStyle::BallotBox,
+                math: MathStyle::MathMl,
+                math_scale: 0.92,
+                code_blocks: CodeBlockStyle::Preformatted,
+                collapse_whitespace: false,
+                stylesheet: None,
+                images: ImageLimits {
+                    max_bytes: 1024 * 1024,
+                    formats: web_formats(),
+                },
+                ..generic
+            },
+            // Slack ignores styles and drops pasted images, diagrams included
+            Target::Slack => Profile {
+                math: MathStyle::Source,
+                mermaid: false,
+                graphviz: false,
+                inline_code_style: false,
+                inline_styles: false,
+                stylesheet: None,
+                images: ImageLimits {
+                    max_bytes: 0,
+                    formats: Vec::new(),
+                },
+                ..generic
+            },
+        }
+    }
+}
+
+impl MathStyle {
+    pub const ALL: [MathStyle; 4] = [
+        MathStyle::Png,
+        MathStyle::MathMl,
+        MathStyle::Katex,
+        MathStyle::Source,
+    ];
+
+    /// Name used on the command line and in the config file.
+    pub fn name(self) -> &'static str {
+        match self {
+            MathStyle::Png => "png",
+            MathStyle::MathMl => "mathml",
+            MathStyle::Katex => "katex",
+            MathStyle::Source => "source",
+        }
+    }
+}
+
+impl FromStr for MathStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MathStyle::ALL
+            .into_iter()
+            .find(|m| m.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                let names: Vec<_> = MathStyle::ALL.iter().map(|m| m.name()).collect();
+                format!(
+                    "unknown math style '{s}' (expected one of: {})",
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+impl EquationNumbering {
+    pub const ALL: [EquationNumbering; 3] = [
+        EquationNumbering::None,
+        EquationNumbering::Ams,
+        EquationNumbering::All,
+    ];
+
+    /// Name used on the command line and in the config file.
+    pub fn name(self) -> &'static str {
+        match self {
+            EquationNumbering::None => "none",
+            EquationNumbering::Ams => "ams",
+            EquationNumbering::All => "all",
+        }
+    }
+}
+
+impl FromStr for EquationNumbering {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EquationNumbering::ALL
+            .into_iter()
+            .find(|n| n.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                let names: Vec<_> = EquationNumbering::ALL.iter().map(|n| n.name()).collect();
+                format!(
+                    "unknown equation numbering '{s}' (expected one of: {})",
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl TryFrom<String> for Target {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl FromStr for Target {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("google-docs") {
+            return Ok(Target::GoogleDocs);
+        }
+        Target::ALL
+            .into_iter()
+            .find(|t| t.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                let names: Vec<_> = Target::ALL.iter().map(|t| t.name()).collect();
+                format!(
+                    "unknown target '{s}' (expected one of: {})",
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_target_names() {
+        for target in Target::ALL {
+            assert_eq!(target.name().parse::<Target>(), Ok(target));
+        }
+        assert_eq!("Teams".parse::<Target>(), Ok(Target::Teams));
+        assert_eq!("google-docs".parse::<Target>(), Ok(Target::GoogleDocs));
+    }
+
+    #[test]
+    fn test_parse_unknown_target() {
+        let err = "notion".parse::<Target>().unwrap_err();
+        assert!(err.contains("unknown target 'notion'"));
+        assert!(err.contains("teams"));
+    }
+
+    #[test]
+    fn test_parse_math_styles() {
+        for style in MathStyle::ALL {
+            assert_eq!(style.name().parse::<MathStyle>(), Ok(style));
+        }
+        let err = "svg".parse::<MathStyle>().unwrap_err();
+        assert!(err.contains("unknown math style 'svg'"));
+    }
+
+    #[test]
+    fn test_parse_equation_numbering() {
+        for numbering in EquationNumbering::ALL {
+            assert_eq!(numbering.name().parse(), Ok(numbering));
+        }
+        assert!(
+            "auto"
+                .parse::<EquationNumbering>()
+                .unwrap_err()
+                .contains("unknown equation numbering 'auto'")
+        );
+    }
+
+    #[test]
+    fn test_default_profile_is_generic() {
+        assert_eq!(Profile::default(), Target::Generic.profile());
+        assert!(Profile::default().stylesheet.is_some());
+    }
+
+    #[test]
+    fn test_image_limits() {
+        let limits = Target::Teams.profile().images;
+        assert!(limits.allows("image/png", 1024));
+        assert!(!limits.allows("image/svg+xml", 1024));
+        assert!(!limits.allows("image/png", 5 * 1024 * 1024));
+        assert!(!Target::Slack.profile().images.allows("image/png", 1));
+        // Diagrams would be dropped as images, so they stay code
+        let slack = Target::Slack.profile();
+        assert!(!slack.mermaid && !slack.graphviz);
+    }
+}